@@ -57,18 +57,24 @@ bind! {
     php_error_docref,
     php_info_print_table_end,
     php_info_print_table_header,
+    php_info_print_table_colspan_header,
     php_info_print_table_row,
     php_info_print_table_start,
     std_object_handlers,
     zend_array_destroy,
     zend_array_dup,
+    zend_call_function,
     zend_call_known_function,
+    zend_fcall_info,
+    zend_fcall_info_cache,
+    zend_release_fcall_info_cache,
     zend_fetch_function_str,
     zend_hash_str_find_ptr_lc,
     zend_ce_argument_count_error,
     zend_ce_arithmetic_error,
     zend_ce_compile_error,
     zend_ce_division_by_zero_error,
+    zend_ce_error,
     zend_ce_error_exception,
     zend_ce_exception,
     zend_ce_parse_error,
@@ -86,6 +92,7 @@ bind! {
     zend_class_entry,
     zend_declare_class_constant,
     zend_declare_property,
+    zend_declare_typed_property,
     zend_do_implement_interface,
     zend_enum_add_case,
     zend_enum_get_case,
@@ -104,6 +111,7 @@ bind! {
     zend_hash_str_update,
     zend_internal_arg_info,
     zend_is_callable,
+    zend_is_callable_ex,
     zend_is_identical,
     zend_is_iterable,
     zend_known_strings,
@@ -117,9 +125,14 @@ bind! {
     zend_register_bool_constant,
     zend_register_double_constant,
     zend_register_ini_entries,
+    zend_unregister_ini_entries,
+    display_ini_entries,
     zend_register_internal_enum,
     zend_ini_entry_def,
     zend_register_internal_class_ex,
+    zend_register_internal_interface,
+    zend_read_static_property_ex,
+    zend_update_static_property_ex,
     zend_register_long_constant,
     zend_register_string_constant,
     zend_resource,
@@ -128,6 +141,7 @@ bind! {
     zend_throw_exception_ex,
     zend_throw_exception_object,
     zend_type,
+    zend_type_list,
     zend_value,
     zend_wrong_parameters_count_error,
     zval,
@@ -150,6 +164,8 @@ bind! {
     E_RECOVERABLE_ERROR,
     E_DEPRECATED,
     E_USER_DEPRECATED,
+    GC_FLAGS_SHIFT,
+    GC_NOT_COLLECTABLE,
     HT_MIN_SIZE,
     IS_ARRAY,
     IS_ARRAY_EX,
@@ -178,6 +194,8 @@ bind! {
     IS_VOID,
     IS_PTR,
     IS_ITERABLE,
+    IS_STATIC,
+    IS_NEVER,
     MAY_BE_ANY,
     MAY_BE_BOOL,
     PHP_INI_USER,
@@ -217,6 +235,7 @@ bind! {
     ZEND_ACC_PROPERTY_TYPES_RESOLVED,
     ZEND_ACC_PROTECTED,
     ZEND_ACC_PUBLIC,
+    ZEND_ACC_READONLY,
     ZEND_ACC_RESOLVED_INTERFACES,
     ZEND_ACC_RESOLVED_PARENT,
     ZEND_ACC_RETURN_REFERENCE,
@@ -230,12 +249,16 @@ bind! {
     ZEND_ACC_USES_THIS,
     ZEND_ACC_USE_GUARDS,
     ZEND_ACC_VARIADIC,
+    ZEND_CALL_HAS_EXTRA_NAMED_PARAMS,
     ZEND_DEBUG,
     ZEND_HAS_STATIC_IN_METHODS,
     ZEND_ISEMPTY,
     // ZEND_MM_ALIGNMENT,
     // ZEND_MM_ALIGNMENT_MASK,
     ZEND_MODULE_API_NO,
+    MODULE_DEP_REQUIRED,
+    MODULE_DEP_CONFLICTS,
+    MODULE_DEP_OPTIONAL,
     ZEND_PROPERTY_EXISTS,
     ZEND_PROPERTY_ISSET,
     Z_TYPE_FLAGS_SHIFT,
@@ -246,10 +269,31 @@ bind! {
     ts_rsrc_id,
     _ZEND_TYPE_NAME_BIT,
     _ZEND_TYPE_LITERAL_NAME_BIT,
+    _ZEND_TYPE_LIST_BIT,
+    _ZEND_TYPE_INTERSECTION_BIT,
     ZEND_INTERNAL_FUNCTION,
     ZEND_USER_FUNCTION,
     ZEND_EVAL_CODE,
+    ZEND_ADD,
+    ZEND_SUB,
+    ZEND_MUL,
+    ZEND_DIV,
+    ZEND_MOD,
+    ZEND_SL,
+    ZEND_SR,
+    ZEND_CONCAT,
+    ZEND_BW_OR,
+    ZEND_BW_AND,
+    ZEND_BW_XOR,
+    ZEND_POW,
+    ZEND_BW_NOT,
+    BP_VAR_IS,
+    BP_VAR_W,
+    BP_VAR_RW,
+    BP_VAR_UNSET,
     zval_ptr_dtor,
+    zend_iterator_init,
+    zend_create_internal_iterator_zval,
     zend_refcounted_h,
     zend_is_true,
     zend_object_std_dtor,
@@ -257,6 +301,10 @@ bind! {
     zend_std_write_property,
     zend_std_get_properties,
     zend_std_has_property,
+    zend_std_get_property_ptr_ptr,
+    zend_std_unset_property,
+    zend_std_compare_objects,
+    zend_ref_del_type_source,
     zend_objects_new,
     zend_standard_class_def,
     zend_class_serialize_deny,
@@ -272,6 +320,14 @@ bind! {
     zend_hash_move_backwards_ex,
     zend_array_count,
     gc_possible_root,
+    zend_get_gc_buffer_create,
+    zend_get_gc_buffer_grow,
+    rsrc_dtor_func_t,
+    zend_register_list_destructors_ex,
+    zend_register_resource,
+    zend_rsrc_list_get_rsrc_type,
+    zend_list_close,
+    zend_list_delete,
     ZEND_ACC_NOT_SERIALIZABLE,
     executor_globals,
     compiler_globals,
@@ -0,0 +1,57 @@
+//! Benchmarks calling PHP callables from Rust, comparing
+//! `ZendCallable::try_call` with a `PreparedCall`.
+//!
+//! Run with `cargo bench --features embed`.
+#![cfg_attr(windows, feature(abi_vectorcall))]
+#![allow(missing_docs, clippy::cast_precision_loss)]
+
+use std::{hint::black_box, time::Instant};
+
+use ext_php_rs::{embed::Embed, types::ZendCallable};
+
+const ITERATIONS: i64 = 1_000_000;
+const WARMUP_ITERATIONS: i64 = 10_000;
+
+/// Runs the function for each iteration and prints the average time per call.
+fn bench(name: &str, mut f: impl FnMut(i64)) {
+    for i in 0..WARMUP_ITERATIONS {
+        f(i);
+    }
+    let start = Instant::now();
+    for i in 0..ITERATIONS {
+        f(i);
+    }
+    let elapsed = start.elapsed();
+    println!(
+        "{name:<24} {:>8.1} ns/call",
+        elapsed.as_nanos() as f64 / ITERATIONS as f64
+    );
+}
+
+fn main() {
+    Embed::run(|| {
+        let callables = [
+            ("function", "'abs'"),
+            ("closure", "fn ($x) => $x * 2"),
+            (
+                "method",
+                "[new class { public function double($x) { return $x * 2; } }, 'double']",
+            ),
+        ];
+        for (name, code) in callables {
+            let callback = Embed::eval(code).expect("Failed to create callable");
+
+            let callable = ZendCallable::new(&callback).expect("Value is not callable");
+            bench(&format!("{name}/try_call"), |i| {
+                black_box(callable.try_call(vec![&i]).expect("Call failed"));
+            });
+
+            let mut prepared = ZendCallable::new(&callback)
+                .and_then(ZendCallable::prepare)
+                .expect("Failed to prepare callable");
+            bench(&format!("{name}/prepared"), |i| {
+                black_box(prepared.call(&[&i]).expect("Call failed"));
+            });
+        }
+    });
+}
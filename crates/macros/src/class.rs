@@ -2,9 +2,9 @@ use darling::util::Flag;
 use darling::{FromAttributes, FromMeta, ToTokens};
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::{Attribute, Expr, Fields, ItemStruct};
+use syn::{Attribute, Expr, ExprLit, ExprUnary, Fields, ItemStruct, Lit, UnOp};
 
-use crate::helpers::get_docs;
+use crate::helpers::{get_docs, module_item};
 use crate::parsing::{PhpRename, RenameRule};
 use crate::prelude::*;
 
@@ -20,6 +20,9 @@ pub struct StructAttributes {
     modifier: Option<syn::Ident>,
     /// An expression of `ClassFlags` to be applied to the class.
     flags: Option<syn::Expr>,
+    /// Marks the class as abstract.
+    #[darling(rename = "abstract")]
+    abstract_: Flag,
     extends: Option<ClassEntryAttribute>,
     #[darling(multiple)]
     implements: Vec<ClassEntryAttribute>,
@@ -32,6 +35,14 @@ pub struct ClassEntryAttribute {
     stub: String,
 }
 
+impl ToTokens for ClassEntryAttribute {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let ce = &self.ce;
+        let stub = &self.stub;
+        quote! { (#ce, #stub) }.to_tokens(tokens);
+    }
+}
+
 pub fn parser(mut input: ItemStruct) -> Result<TokenStream> {
     let attr = StructAttributes::from_attributes(&input.attrs)?;
     let ident = &input.ident;
@@ -52,12 +63,16 @@ pub fn parser(mut input: ItemStruct) -> Result<TokenStream> {
         &attr.implements,
         &fields,
         attr.flags.as_ref(),
+        attr.abstract_.is_present(),
         &docs,
     );
 
+    let module_item = module_item(&name, "Class", &quote! { .class::<#ident>() }, Some(ident));
+
     Ok(quote! {
         #input
         #class_impl
+        #module_item
 
         ::ext_php_rs::class_derives!(#ident);
     })
@@ -70,9 +85,64 @@ struct PropAttributes {
     #[darling(flatten)]
     rename: PhpRename,
     flags: Option<Expr>,
+    readonly: Flag,
+    default: Option<PropDefault>,
+    stub: Option<String>,
     attrs: Vec<Attribute>,
 }
 
+/// Default value of a property. Unlike a plain [`Expr`], string literals are
+/// kept as literals instead of being parsed as expressions.
+#[derive(Debug)]
+struct PropDefault(Expr);
+
+impl FromMeta for PropDefault {
+    fn from_expr(expr: &Expr) -> darling::Result<Self> {
+        Ok(Self(expr.clone()))
+    }
+}
+
+impl PropDefault {
+    /// Returns the PHP representation of the default, used in stubs. Only
+    /// literals and `None` can be represented, other expressions require an
+    /// explicit `stub`.
+    fn stub(&self) -> Option<String> {
+        match &self.0 {
+            Expr::Lit(ExprLit { lit, .. }) => lit_stub(lit),
+            Expr::Unary(ExprUnary {
+                op: UnOp::Neg(_),
+                expr,
+                ..
+            }) => match &**expr {
+                Expr::Lit(ExprLit {
+                    lit: lit @ (Lit::Int(_) | Lit::Float(_)),
+                    ..
+                }) => lit_stub(lit).map(|lit| format!("-{lit}")),
+                _ => None,
+            },
+            Expr::Path(path) if path.path.is_ident("None") => Some("null".into()),
+            _ => None,
+        }
+    }
+}
+
+/// Returns the PHP representation of a literal.
+fn lit_stub(lit: &Lit) -> Option<String> {
+    Some(match lit {
+        Lit::Str(lit) => php_string(&lit.value()),
+        Lit::Char(lit) => php_string(&lit.value().to_string()),
+        Lit::Int(lit) => lit.base10_digits().into(),
+        Lit::Float(lit) => lit.base10_digits().into(),
+        Lit::Bool(lit) => lit.value.to_string(),
+        _ => return None,
+    })
+}
+
+/// Returns `value` as a single quoted PHP string.
+fn php_string(value: &str) -> String {
+    format!("'{}'", value.replace('\\', "\\\\").replace('\'', "\\'"))
+}
+
 fn parse_fields<'a>(fields: impl Iterator<Item = &'a mut syn::Field>) -> Result<Vec<Property<'a>>> {
     let mut result = vec![];
     for field in fields {
@@ -82,10 +152,27 @@ fn parse_fields<'a>(fields: impl Iterator<Item = &'a mut syn::Field>) -> Result<
                 .ident
                 .as_ref()
                 .ok_or_else(|| err!("Only named fields can be properties."))?;
+            if attr.readonly.is_present() && attr.default.is_some() {
+                bail!(field => "Readonly properties cannot have a default value.");
+            }
+            let default_stub = match (&attr.default, &attr.stub) {
+                (Some(_), Some(stub)) => Some(stub.clone()),
+                (Some(default), None) => Some(default.stub().ok_or_else(|| {
+                    err!(default.0 => "The default value is not a literal and cannot be represented in stubs. Add its PHP representation with `stub`, e.g. `#[php(prop, default = 60 * 60, stub = \"3600\")]`.")
+                })?),
+                (None, Some(_)) => bail!(field => "`stub` can only be used together with `default`."),
+                (None, None) => None,
+            };
             let docs = get_docs(&attr.attrs)?;
             field.attrs.retain(|attr| !attr.path().is_ident("php"));
 
-            result.push(Property { ident, attr, docs });
+            result.push(Property {
+                ident,
+                ty: &field.ty,
+                attr,
+                default_stub,
+                docs,
+            });
         }
     }
 
@@ -95,7 +182,9 @@ fn parse_fields<'a>(fields: impl Iterator<Item = &'a mut syn::Field>) -> Result<
 #[derive(Debug)]
 struct Property<'a> {
     pub ident: &'a syn::Ident,
+    pub ty: &'a syn::Type,
     pub attr: PropAttributes,
+    pub default_stub: Option<String>,
     pub docs: Vec<String>,
 }
 
@@ -117,6 +206,7 @@ fn generate_registered_class_impl(
     implements: &[ClassEntryAttribute],
     fields: &[Property],
     flags: Option<&syn::Expr>,
+    is_abstract: bool,
     docs: &[String],
 ) -> TokenStream {
     let modifier = modifier.option_tokens();
@@ -130,44 +220,65 @@ fn generate_registered_class_impl(
             .as_ref()
             .map(ToTokens::to_token_stream)
             .unwrap_or(quote! { ::ext_php_rs::flags::PropertyFlags::Public });
+        let flags = if prop.attr.readonly.is_present() {
+            quote! { ::ext_php_rs::readonly_property_flag!().union(#flags) }
+        } else {
+            flags
+        };
+        let ty = prop.ty;
+        let default = prop
+            .attr
+            .default
+            .as_ref()
+            .zip(prop.default_stub.as_ref())
+            .map_or_else(
+                || quote! { ::std::option::Option::None },
+                |(PropDefault(default), stub)| {
+                    quote! {
+                        ::std::option::Option::Some((
+                            || <#ty as ::ext_php_rs::convert::IntoZval>::into_zval(
+                                ::std::convert::Into::<#ty>::into(#default),
+                                true,
+                            ),
+                            #stub,
+                        ))
+                    }
+                },
+            );
         let docs = &prop.docs;
 
         quote! {
             (#name, ::ext_php_rs::internal::property::PropertyInfo {
                 prop: ::ext_php_rs::props::Property::field(|this: &mut Self| &mut this.#ident),
                 flags: #flags,
+                ty: ::std::option::Option::Some(<#ty as ::ext_php_rs::convert::IntoZval>::TYPE),
+                nullable: <#ty as ::ext_php_rs::convert::IntoZval>::NULLABLE,
+                default: #default,
                 docs: &[#(#docs,)*]
             })
         }
     });
 
-    let flags = match flags {
+    let mut flags = match flags {
         Some(flags) => flags.to_token_stream(),
         None => quote! { ::ext_php_rs::flags::ClassFlags::empty() }.to_token_stream(),
     };
+    if is_abstract {
+        flags = quote! { ::ext_php_rs::flags::ClassFlags::Abstract.union(#flags) };
+    }
 
     let docs = quote! {
         #(#docs,)*
     };
 
     let extends = if let Some(extends) = extends {
-        let ce = &extends.ce;
-        let stub = &extends.stub;
         quote! {
-            Some((#ce, #stub))
+            Some(#extends)
         }
     } else {
         quote! { None }
     };
 
-    let implements = implements.iter().map(|imp| {
-        let ce = &imp.ce;
-        let stub = &imp.stub;
-        quote! {
-            (#ce, #stub)
-        }
-    });
-
     quote! {
         impl ::ext_php_rs::class::RegisteredClass for #ident {
             const CLASS_NAME: &'static str = #class_name;
@@ -220,6 +331,58 @@ fn generate_registered_class_impl(
                 use ::ext_php_rs::internal::class::PhpClassImpl;
                 ::ext_php_rs::internal::class::PhpClassImplCollector::<Self>::default().get_constants()
             }
+
+            #[inline]
+            fn static_properties() -> &'static [(&'static str, ::ext_php_rs::flags::PropertyFlags, &'static dyn ::ext_php_rs::convert::IntoZvalDyn, &'static [&'static str])] {
+                use ::ext_php_rs::internal::class::PhpClassImpl;
+                ::ext_php_rs::internal::class::PhpClassImplCollector::<Self>::default().get_static_properties()
+            }
+
+            #[inline]
+            fn modify_builder(
+                builder: ::ext_php_rs::builders::ClassBuilder,
+            ) -> ::ext_php_rs::builders::ClassBuilder {
+                use ::ext_php_rs::internal::class::PhpIterableHandler;
+                ::ext_php_rs::internal::class::PhpClassImplCollector::<Self>::default().install_iterable(builder)
+            }
+
+            #[inline]
+            fn modify_handlers(handlers: &mut ::ext_php_rs::zend::ZendObjectHandlers) {
+                use ::ext_php_rs::internal::class::{
+                    PhpArrayAccessHandler, PhpCastHandler, PhpCloneHandler, PhpCompareHandler,
+                    PhpCountableHandler, PhpGcHandler, PhpOperatorsHandler,
+                };
+                ::ext_php_rs::internal::class::PhpClassImplCollector::<Self>::default().install_compare(handlers);
+                ::ext_php_rs::internal::class::PhpClassImplCollector::<Self>::default().install_cast(handlers);
+                ::ext_php_rs::internal::class::PhpClassImplCollector::<Self>::default().install_operators(handlers);
+                ::ext_php_rs::internal::class::PhpClassImplCollector::<Self>::default().install_gc(handlers);
+                ::ext_php_rs::internal::class::PhpClassImplCollector::<Self>::default().install_clone(handlers);
+                ::ext_php_rs::internal::class::PhpClassImplCollector::<Self>::default().install_countable(handlers);
+                ::ext_php_rs::internal::class::PhpClassImplCollector::<Self>::default().install_array_access(handlers);
+            }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::PropDefault;
+
+    fn stub(expr: &str) -> Option<String> {
+        PropDefault(syn::parse_str(expr).expect("Failed to parse expression")).stub()
+    }
+
+    #[test]
+    fn prop_default_stub() {
+        assert_eq!(stub("10").as_deref(), Some("10"));
+        assert_eq!(stub("10i64").as_deref(), Some("10"));
+        assert_eq!(stub("-1").as_deref(), Some("-1"));
+        assert_eq!(stub("-1.5").as_deref(), Some("-1.5"));
+        assert_eq!(stub("true").as_deref(), Some("true"));
+        assert_eq!(stub("\"it's\"").as_deref(), Some("'it\\'s'"));
+        assert_eq!(stub("None").as_deref(), Some("null"));
+        assert_eq!(stub("vec![]"), None);
+        assert_eq!(stub("String::new()"), None);
+        assert_eq!(stub("-x"), None);
+    }
+}
@@ -7,7 +7,7 @@ use quote::{quote, ToTokens};
 use syn::{Fields, Ident, ItemEnum, Lit};
 
 use crate::{
-    helpers::get_docs,
+    helpers::{get_docs, module_item},
     parsing::{PhpRename, RenameRule, Visibility},
     prelude::*,
 };
@@ -110,11 +110,20 @@ pub fn parser(mut input: ItemEnum) -> Result<TokenStream> {
         discriminant_type,
     );
 
+    let ident = &input.ident;
+    let module_item = module_item(
+        &enum_props.name,
+        "Class",
+        &quote! { .enumeration::<#ident>() },
+        Some(ident),
+    );
+
     Ok(quote! {
         #[allow(dead_code)]
         #input
 
         #enum_props
+        #module_item
     })
 }
 
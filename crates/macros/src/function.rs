@@ -1,13 +1,16 @@
 use std::collections::HashMap;
 
+use darling::util::Flag;
 use darling::{FromAttributes, ToTokens};
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::{format_ident, quote};
 use syn::spanned::Spanned as _;
-use syn::{Expr, FnArg, GenericArgument, ItemFn, PatType, PathArguments, Type, TypePath};
+use syn::{
+    Expr, FnArg, GenericArgument, ItemFn, LitStr, PatType, PathArguments, Signature, Type, TypePath,
+};
 
-use crate::helpers::get_docs;
-use crate::parsing::{PhpRename, RenameRule, Visibility};
+use crate::helpers::{get_docs, module_item};
+use crate::parsing::{PhpRename, PhpType, RenameRule, Visibility};
 use crate::prelude::*;
 use crate::syn_ext::DropLifetimes;
 
@@ -29,22 +32,55 @@ struct PhpFunctionAttribute {
     rename: PhpRename,
     defaults: HashMap<Ident, Expr>,
     optional: Option<Ident>,
+    types: HashMap<Ident, LitStr>,
+    returns: Option<LitStr>,
     vis: Option<Visibility>,
     attrs: Vec<syn::Attribute>,
 }
 
+#[derive(FromAttributes, Default, Debug)]
+#[darling(default, attributes(php))]
+struct PhpArgAttribute {
+    named_rest: Flag,
+}
+
+/// Removes the `#[php]` attributes from the arguments of a function, returning
+/// the argument marked with `#[php(named_rest)]`.
+pub fn take_named_rest(sig: &mut Signature) -> Result<Option<Ident>> {
+    let mut named_rest = None;
+    for arg in &mut sig.inputs {
+        let FnArg::Typed(PatType { attrs, pat, .. }) = arg else {
+            continue;
+        };
+        let php_attr = PhpArgAttribute::from_attributes(attrs)?;
+        attrs.retain(|attr| !attr.path().is_ident("php"));
+        if !php_attr.named_rest.is_present() {
+            continue;
+        }
+        let syn::Pat::Ident(syn::PatIdent { ident, .. }) = &**pat else {
+            bail!(pat => "Unsupported argument.");
+        };
+        if named_rest.replace(ident.clone()).is_some() {
+            bail!(ident => "Only one argument can be marked with `#[php(named_rest)]`.");
+        }
+    }
+    Ok(named_rest)
+}
+
 pub fn parser(mut input: ItemFn) -> Result<TokenStream> {
     let php_attr = PhpFunctionAttribute::from_attributes(&input.attrs)?;
     input.attrs.retain(|attr| !attr.path().is_ident("php"));
+    let named_rest = take_named_rest(&mut input.sig)?;
 
-    let args = Args::parse_from_fnargs(input.sig.inputs.iter(), php_attr.defaults)?;
+    let mut args = Args::parse_from_fnargs(input.sig.inputs.iter(), php_attr.defaults)?;
+    args.named_rest(named_rest)?;
     if let Some(ReceiverArg { span, .. }) = args.receiver {
         bail!(span => "Receiver arguments are invalid on PHP functions. See `#[php_impl]`.");
     }
 
     let docs = get_docs(&php_attr.attrs)?;
 
-    let func = Function::new(
+    let mut func = Function::new(
         &input.sig,
         php_attr
             .rename
@@ -53,11 +89,22 @@ pub fn parser(mut input: ItemFn) -> Result<TokenStream> {
         php_attr.optional,
         docs,
     );
+    func.apply_types(php_attr.types, php_attr.returns.as_ref())?;
     let function_impl = func.php_function_impl();
+    let internal_ident = func.internal_ident();
+    let module_item = module_item(
+        &func.name,
+        "Function",
+        &quote! {
+            .function(<#internal_ident as ::ext_php_rs::internal::function::PhpFunction>::FUNCTION_ENTRY())
+        },
+        None,
+    );
 
     Ok(quote! {
         #input
         #function_impl
+        #module_item
     })
 }
 
@@ -75,6 +122,8 @@ pub struct Function<'a> {
     pub optional: Option<Ident>,
     /// Doc comments for the function.
     pub docs: Vec<String>,
+    /// PHP return type overriding the type derived from the output.
+    pub returns: Option<PhpType>,
 }
 
 #[derive(Debug)]
@@ -123,9 +172,32 @@ impl<'a> Function<'a> {
             },
             optional,
             docs,
+            returns: None,
         }
     }
 
+    /// Overrides the PHP types of the arguments and return value of the
+    /// function.
+    ///
+    /// # Parameters
+    ///
+    /// * `types` - PHP types of the arguments, e.g. `int|string`.
+    /// * `returns` - PHP return type.
+    pub fn apply_types(
+        &mut self,
+        types: HashMap<Ident, LitStr>,
+        returns: Option<&LitStr>,
+    ) -> Result<()> {
+        for (name, ty) in types {
+            let Some(arg) = self.args.typed.iter_mut().find(|arg| *arg.name == name) else {
+                bail!(name => "Unknown argument `{}` in `types`.", name);
+            };
+            arg.php_type = Some(PhpType::parse(&ty)?);
+        }
+        self.returns = returns.map(PhpType::parse).transpose()?;
+        Ok(())
+    }
+
     /// Generates an internal identifier for the function.
     pub fn internal_ident(&self) -> Ident {
         format_ident!("_internal_{}", &self.ident)
@@ -156,14 +228,7 @@ impl<'a> Function<'a> {
 
         let returns = self.build_returns();
         let result = self.build_result(call_type, required, not_required);
-        let docs = if self.docs.is_empty() {
-            quote! {}
-        } else {
-            let docs = &self.docs;
-            quote! {
-                .docs(&[#(#docs),*])
-            }
-        };
+        let docs = self.build_docs();
 
         quote! {
             ::ext_php_rs::builders::FunctionBuilder::new(#name, {
@@ -195,17 +260,68 @@ impl<'a> Function<'a> {
         }
     }
 
+    /// Generates the function builder for an abstract method, which has no
+    /// handler and is implemented by classes extending or implementing the
+    /// declaring class.
+    pub fn abstract_function_builder(&self) -> TokenStream {
+        let name = &self.name;
+        let (required, not_required) = self.args.split_args(self.optional.as_ref());
+        let required_args = required
+            .iter()
+            .map(TypedArg::arg_builder)
+            .collect::<Vec<_>>();
+        let not_required_args = not_required
+            .iter()
+            .map(TypedArg::arg_builder)
+            .collect::<Vec<_>>();
+        let returns = self.build_returns();
+        let docs = self.build_docs();
+
+        quote! {
+            ::ext_php_rs::builders::FunctionBuilder::new_abstract(#name)
+            #(.arg(#required_args))*
+            .not_required()
+            #(.arg(#not_required_args))*
+            #returns
+            #docs
+        }
+    }
+
+    fn build_docs(&self) -> TokenStream {
+        if self.docs.is_empty() {
+            quote! {}
+        } else {
+            let docs = &self.docs;
+            quote! {
+                .docs(&[#(#docs),*])
+            }
+        }
+    }
+
     fn build_returns(&self) -> Option<TokenStream> {
-        self.output.cloned().map(|mut output| {
+        let output = self.output.cloned().map(|mut output| {
             output.drop_lifetimes();
-            quote! {
+            output
+        });
+        match (&self.returns, output) {
+            (Some(PhpType { ty, nullable }), output) => {
+                let nullable = output.map_or_else(
+                    || quote! { #nullable },
+                    |output| quote! { #nullable || <#output as ::ext_php_rs::convert::IntoZval>::NULLABLE },
+                );
+                Some(quote! {
+                    .returns(#ty, false, #nullable)
+                })
+            }
+            (None, Some(output)) => Some(quote! {
                 .returns(
                     <#output as ::ext_php_rs::convert::IntoZval>::TYPE,
                     false,
                     <#output as ::ext_php_rs::convert::IntoZval>::NULLABLE,
                 )
-            }
-        })
+            }),
+            (None, None) => None,
+        }
     }
 
     fn build_result(
@@ -333,11 +449,6 @@ impl<'a> Function<'a> {
                 |e| quote! { return ::ext_php_rs::class::ConstructorResult::Exception(#e); },
             )
         });
-        let variadic = self.args.typed.iter().any(|arg| arg.variadic).then(|| {
-            quote! {
-                .variadic()
-            }
-        });
         let docs = &self.docs;
 
         quote! {
@@ -349,7 +460,6 @@ impl<'a> Function<'a> {
                             #(.arg(&mut #required_arg_names))*
                             .not_required()
                             #(.arg(&mut #not_required_arg_names))*
-                            #variadic
                             .parse();
                         if parse.is_err() {
                             return ::ext_php_rs::class::ConstructorResult::ArgError;
@@ -365,7 +475,6 @@ impl<'a> Function<'a> {
                             #(.arg(#required_args))*
                             .not_required()
                             #(.arg(#not_required_args))*
-                            #variadic
                     }
                     inner
                 }
@@ -388,6 +497,11 @@ pub struct TypedArg<'a> {
     pub default: Option<Expr>,
     pub as_ref: bool,
     pub variadic: bool,
+    /// Whether the argument receives the named arguments which do not match
+    /// another argument.
+    pub named_rest: bool,
+    /// PHP type overriding the type derived from the Rust type.
+    pub php_type: Option<PhpType>,
 }
 
 #[derive(Debug)]
@@ -434,6 +548,8 @@ impl<'a> Args<'a> {
                         default,
                         as_ref,
                         variadic,
+                        named_rest: false,
+                        php_type: None,
                     });
                 }
             }
@@ -441,6 +557,27 @@ impl<'a> Args<'a> {
         Ok(result)
     }
 
+    /// Marks the argument receiving the unknown named arguments, which must be
+    /// the last argument.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - The argument marked with `#[php(named_rest)]`.
+    pub fn named_rest(&mut self, name: Option<Ident>) -> Result<()> {
+        let Some(name) = name else {
+            return Ok(());
+        };
+        match self.typed.last_mut() {
+            Some(arg) if *arg.name == name && !arg.variadic => {
+                arg.named_rest = true;
+                Ok(())
+            }
+            _ => {
+                bail!(name => "The `#[php(named_rest)]` argument must be the last argument and cannot be variadic.")
+            }
+        }
+    }
+
     fn parse_typed(ty: &Type) -> (bool, bool, Type) {
         match ty {
             Type::Reference(ref_) => {
@@ -509,9 +646,9 @@ impl<'a> Args<'a> {
                 if optional == arg.name {
                     mid.replace(i);
                 }
-            } else if mid.is_none() && arg.nullable {
+            } else if mid.is_none() && (arg.nullable || arg.named_rest) {
                 mid.replace(i);
-            } else if !arg.nullable {
+            } else if !arg.nullable && !arg.named_rest {
                 mid.take();
             }
         }
@@ -541,6 +678,29 @@ impl TypedArg<'_> {
             }
         }
 
+        // Named rest arguments are passed as maps, so we need to extract the
+        // type of the values.
+        if self.named_rest {
+            let value = match &ty {
+                Type::Path(TypePath { path, .. }) => path.segments.last().and_then(|seg| {
+                    let PathArguments::AngleBracketed(args) = &seg.arguments else {
+                        return None;
+                    };
+                    args.args
+                        .iter()
+                        .filter_map(|arg| match arg {
+                            GenericArgument::Type(ty) => Some(ty),
+                            _ => None,
+                        })
+                        .last()
+                }),
+                _ => None,
+            };
+            if let Some(value) = value {
+                return value.clone();
+            }
+        }
+
         ty
     }
 
@@ -558,8 +718,14 @@ impl TypedArg<'_> {
     /// `ext-php-rs`.
     fn arg_builder(&self) -> TokenStream {
         let name = self.name.to_string();
-        let ty = self.clean_ty();
-        let null = if self.nullable {
+        let ty = match &self.php_type {
+            Some(php_type) => php_type.ty.clone(),
+            None => {
+                let ty = self.clean_ty();
+                quote! { <#ty as ::ext_php_rs::convert::FromZvalMut>::TYPE }
+            }
+        };
+        let null = if self.nullable || self.php_type.as_ref().is_some_and(|ty| ty.nullable) {
             Some(quote! { .allow_null() })
         } else {
             None
@@ -575,9 +741,13 @@ impl TypedArg<'_> {
         } else {
             None
         };
-        let variadic = self.variadic.then(|| quote! { .is_variadic() });
+        let variadic = if self.named_rest {
+            Some(quote! { .is_named_rest() })
+        } else {
+            self.variadic.then(|| quote! { .is_variadic() })
+        };
         quote! {
-            ::ext_php_rs::args::Arg::new(#name, <#ty as ::ext_php_rs::convert::FromZvalMut>::TYPE)
+            ::ext_php_rs::args::Arg::new(#name, #ty)
                 #null
                 #default
                 #as_ref
@@ -596,6 +766,22 @@ impl TypedArg<'_> {
             quote! {
                 &#name.variadic_vals()
             }
+        } else if self.named_rest {
+            let bail = bail_fn(quote! {
+                ::ext_php_rs::exception::PhpException::new(
+                    e.to_string(),
+                    0,
+                    ::ext_php_rs::zend::ce::type_error(),
+                )
+            });
+            quote! {
+                match #name.named_rest_vals() {
+                    Ok(vals) => vals,
+                    Err(e) => {
+                        #bail;
+                    }
+                }
+            }
         } else if self.nullable {
             // Originally I thought we could just use the below case for `null` options, as
             // `val()` will return `Option<Option<T>>`, however, this isn't the case when
@@ -0,0 +1,94 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{
+    punctuated::Punctuated, token::Where, Data, DeriveInput, Fields, GenericParam, WhereClause,
+};
+
+use crate::prelude::*;
+
+pub fn parser(input: DeriveInput) -> Result<TokenStream> {
+    let DeriveInput {
+        ident,
+        generics,
+        data,
+        ..
+    } = input;
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let mut where_clause = where_clause.cloned().unwrap_or_else(|| WhereClause {
+        where_token: Where {
+            span: Span::call_site(),
+        },
+        predicates: Punctuated::default(),
+    });
+    for generic in &generics.params {
+        if let GenericParam::Type(ty) = generic {
+            let ident = &ty.ident;
+            where_clause.predicates.push(
+                syn::parse2(quote! {
+                    #ident: ::ext_php_rs::gc::GcTraverse
+                })
+                .expect("couldn't parse where predicate"),
+            );
+        }
+    }
+
+    let body = match data {
+        Data::Struct(data) => {
+            let (pattern, bindings) = destructure(&data.fields);
+            quote! {
+                let Self #pattern = self;
+                #(::ext_php_rs::gc::GcTraverse::gc_traverse(#bindings, buffer);)*
+            }
+        }
+        Data::Enum(data) => {
+            let arms = data.variants.iter().map(|variant| {
+                let variant_ident = &variant.ident;
+                let (pattern, bindings) = destructure(&variant.fields);
+                quote! {
+                    Self::#variant_ident #pattern => {
+                        #(::ext_php_rs::gc::GcTraverse::gc_traverse(#bindings, buffer);)*
+                    }
+                }
+            });
+            quote! {
+                match self {
+                    #(#arms)*
+                }
+            }
+        }
+        Data::Union(_) => {
+            bail!(ident => "Only structs and enums are supported by the `#[derive(GcTraverse)]` macro.");
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::ext_php_rs::gc::GcTraverse for #ident #ty_generics #where_clause {
+            #[allow(unused_variables)]
+            fn gc_traverse(&self, buffer: &mut ::ext_php_rs::gc::GcBuffer) {
+                #body
+            }
+        }
+    })
+}
+
+/// Returns the pattern binding the given fields, and the bound identifiers.
+fn destructure(fields: &Fields) -> (TokenStream, Vec<syn::Ident>) {
+    match fields {
+        Fields::Named(fields) => {
+            let idents = fields
+                .named
+                .iter()
+                .filter_map(|field| field.ident.clone())
+                .collect::<Vec<_>>();
+            (quote! { { #(#idents),* } }, idents)
+        }
+        Fields::Unnamed(fields) => {
+            let idents = (0..fields.unnamed.len())
+                .map(|i| format_ident!("field_{i}"))
+                .collect::<Vec<_>>();
+            (quote! { ( #(#idents),* ) }, idents)
+        }
+        Fields::Unit => (TokenStream::new(), vec![]),
+    }
+}
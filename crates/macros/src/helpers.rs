@@ -1,5 +1,7 @@
 use crate::prelude::*;
-use syn::{Attribute, Expr, Lit, Meta};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Attribute, Expr, Ident, Lit, Meta};
 
 /// Takes a list of attributes and returns a list of doc comments retrieved from
 /// the attributes.
@@ -24,3 +26,41 @@ pub fn get_docs(attrs: &[Attribute]) -> Result<Vec<String>> {
         })
         .collect::<Result<Vec<_>>>()
 }
+
+/// Submits an item to be added to the module by
+/// `ModuleBuilder::auto_register`. Returns an empty token stream if the
+/// `inventory` feature is disabled.
+///
+/// # Parameters
+///
+/// * `name` - The name of the item in PHP.
+/// * `kind` - The variant of `ModuleItemKind` of the item.
+/// * `register` - The method call adding the item to a `ModuleBuilder`.
+/// * `class` - The type implementing `RegisteredClass` for classes, interfaces
+///   and enums, which are registered after their parent and interfaces.
+pub fn module_item(
+    name: &str,
+    kind: &str,
+    register: &TokenStream,
+    class: Option<&Ident>,
+) -> TokenStream {
+    if !cfg!(feature = "inventory") {
+        return TokenStream::new();
+    }
+
+    let kind = format_ident!("{kind}");
+    let dependencies = match class {
+        Some(class) => quote! { ::ext_php_rs::internal::registry::class_dependencies::<#class> },
+        None => quote! { ::std::vec::Vec::new },
+    };
+    quote! {
+        ::ext_php_rs::internal::registry::inventory::submit! {
+            ::ext_php_rs::internal::registry::ModuleItem {
+                name: #name,
+                kind: ::ext_php_rs::internal::registry::ModuleItemKind::#kind,
+                register: |builder| builder #register,
+                dependencies: #dependencies,
+            }
+        }
+    }
+}
@@ -3,10 +3,10 @@ use darling::FromAttributes;
 use proc_macro2::TokenStream;
 use quote::quote;
 use std::collections::{HashMap, HashSet};
-use syn::{Expr, Ident, ItemImpl};
+use syn::parse::{Parse, ParseStream};
+use syn::{Attribute, Expr, Ident, ItemImpl, LitStr, Signature, Token};
 
-use crate::constant::PhpConstAttribute;
-use crate::function::{Args, CallType, Function, MethodReceiver};
+use crate::function::{take_named_rest, Args, CallType, Function, MethodReceiver};
 use crate::helpers::get_docs;
 use crate::parsing::{PhpRename, RenameRule, Visibility};
 use crate::prelude::*;
@@ -33,6 +33,8 @@ pub struct PhpImpl {
     change_method_case: Option<RenameRule>,
     /// Rename constants to match the given rule.
     change_constant_case: Option<RenameRule>,
+    /// Rename static properties to match the given rule.
+    change_static_prop_case: Option<RenameRule>,
 }
 
 pub fn parser(mut input: ItemImpl) -> Result<TokenStream> {
@@ -50,6 +52,7 @@ pub fn parser(mut input: ItemImpl) -> Result<TokenStream> {
         args.change_method_case.unwrap_or(RenameRule::Camel),
         args.change_constant_case
             .unwrap_or(RenameRule::ScreamingSnake),
+        args.change_static_prop_case.unwrap_or(RenameRule::Camel),
     );
     parsed.parse(input.items.iter_mut())?;
 
@@ -69,12 +72,28 @@ struct MethodArgs {
     optional: Option<Ident>,
     /// Default values for optional arguments.
     defaults: HashMap<Ident, Expr>,
+    /// PHP types of the arguments.
+    types: HashMap<Ident, LitStr>,
+    /// PHP return type.
+    returns: Option<LitStr>,
     /// Visibility of the method (public, protected, private).
     vis: Visibility,
     /// Method type.
     ty: MethodTy,
 }
 
+#[derive(FromAttributes, Default, Debug)]
+#[darling(default, attributes(php), forward_attrs(doc))]
+struct PhpConstImplAttribute {
+    #[darling(flatten)]
+    rename: PhpRename,
+    /// Exports the constant as a static property, using its value as the
+    /// initial value of the property.
+    static_prop: Flag,
+    vis: Option<Visibility>,
+    attrs: Vec<syn::Attribute>,
+}
+
 #[derive(FromAttributes, Default, Debug)]
 #[darling(default, attributes(php), forward_attrs(doc))]
 pub struct PhpFunctionImplAttribute {
@@ -82,11 +101,15 @@ pub struct PhpFunctionImplAttribute {
     rename: PhpRename,
     defaults: HashMap<Ident, Expr>,
     optional: Option<Ident>,
+    types: HashMap<Ident, LitStr>,
+    returns: Option<LitStr>,
     vis: Option<Visibility>,
     attrs: Vec<syn::Attribute>,
     getter: Flag,
     setter: Flag,
     constructor: Flag,
+    #[darling(rename = "abstract")]
+    abstract_: Flag,
     abstract_method: Flag,
 }
 
@@ -98,7 +121,7 @@ impl MethodArgs {
             MethodTy::Getter
         } else if attr.setter.is_present() {
             MethodTy::Setter
-        } else if attr.abstract_method.is_present() {
+        } else if attr.abstract_.is_present() || attr.abstract_method.is_present() {
             MethodTy::Abstract
         } else {
             MethodTy::Normal
@@ -108,20 +131,40 @@ impl MethodArgs {
             name,
             optional: attr.optional,
             defaults: attr.defaults,
+            types: attr.types,
+            returns: attr.returns,
             vis: attr.vis.unwrap_or(Visibility::Public),
             ty,
         }
     }
 }
 
+/// A method signature without a body, e.g. `pub fn area(&self) -> f64;`.
+struct BodylessMethod {
+    attrs: Vec<Attribute>,
+    sig: Signature,
+}
+
+impl Parse for BodylessMethod {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let attrs = input.call(Attribute::parse_outer)?;
+        input.parse::<syn::Visibility>()?;
+        let sig = input.parse()?;
+        input.parse::<Token![;]>()?;
+        Ok(Self { attrs, sig })
+    }
+}
+
 #[derive(Debug)]
 struct ParsedImpl<'a> {
     path: &'a syn::Path,
     change_method_case: RenameRule,
     change_constant_case: RenameRule,
+    change_static_prop_case: RenameRule,
     functions: Vec<FnBuilder>,
     constructor: Option<Function<'a>>,
     constants: Vec<Constant<'a>>,
+    static_props: Vec<StaticProperty<'a>>,
 }
 
 #[derive(Debug, Eq, Hash, PartialEq)]
@@ -160,6 +203,18 @@ struct Constant<'a> {
     docs: Vec<String>,
 }
 
+#[derive(Debug)]
+struct StaticProperty<'a> {
+    /// Name of the property in PHP land.
+    name: String,
+    /// Identifier of the constant holding the initial value in Rust land.
+    ident: &'a syn::Ident,
+    /// The visibility of the property.
+    vis: Visibility,
+    /// Documentation for the property.
+    docs: Vec<String>,
+}
+
 impl<'a> ParsedImpl<'a> {
     /// Create a new, empty parsed impl block.
     ///
@@ -168,14 +223,22 @@ impl<'a> ParsedImpl<'a> {
     /// * `path` - Path of the type the `impl` block is for.
     /// * `rename_methods` - Rule to rename methods with.
     /// * `rename_constants` - Rule to rename constants with.
-    fn new(path: &'a syn::Path, rename_methods: RenameRule, rename_constants: RenameRule) -> Self {
+    /// * `rename_static_props` - Rule to rename static properties with.
+    fn new(
+        path: &'a syn::Path,
+        rename_methods: RenameRule,
+        rename_constants: RenameRule,
+        rename_static_props: RenameRule,
+    ) -> Self {
         Self {
             path,
             change_method_case: rename_methods,
             change_constant_case: rename_constants,
+            change_static_prop_case: rename_static_props,
             functions: Vec::default(),
             constructor: Option::default(),
             constants: Vec::default(),
+            static_props: Vec::default(),
         }
     }
 
@@ -184,13 +247,29 @@ impl<'a> ParsedImpl<'a> {
         for items in items {
             match items {
                 syn::ImplItem::Const(c) => {
-                    let attr = PhpConstAttribute::from_attributes(&c.attrs)?;
-                    let name = attr
-                        .rename
-                        .rename(c.ident.to_string(), self.change_constant_case);
+                    let attr = PhpConstImplAttribute::from_attributes(&c.attrs)?;
                     let docs = get_docs(&attr.attrs)?;
                     c.attrs.retain(|attr| !attr.path().is_ident("php"));
 
+                    if attr.static_prop.is_present() {
+                        let name = attr
+                            .rename
+                            .rename(c.ident.to_string(), self.change_static_prop_case);
+                        self.static_props.push(StaticProperty {
+                            name,
+                            ident: &c.ident,
+                            vis: attr.vis.unwrap_or(Visibility::Public),
+                            docs,
+                        });
+                        continue;
+                    }
+                    if attr.vis.is_some() {
+                        bail!(c => "Visibility is only supported on constants exported as static properties.");
+                    }
+
+                    let name = attr
+                        .rename
+                        .rename(c.ident.to_string(), self.change_constant_case);
                     self.constants.push(Constant {
                         name,
                         ident: &c.ident,
@@ -206,8 +285,17 @@ impl<'a> ParsedImpl<'a> {
                     method.attrs.retain(|attr| !attr.path().is_ident("php"));
 
                     let opts = MethodArgs::new(name, attr);
-                    let args = Args::parse_from_fnargs(method.sig.inputs.iter(), opts.defaults)?;
+                    if matches!(opts.ty, MethodTy::Abstract) {
+                        self.functions
+                            .push(Self::abstract_method(&mut method.sig, opts, docs)?);
+                        continue;
+                    }
+                    let named_rest = take_named_rest(&mut method.sig)?;
+                    let mut args =
+                        Args::parse_from_fnargs(method.sig.inputs.iter(), opts.defaults)?;
+                    args.named_rest(named_rest)?;
                     let mut func = Function::new(&method.sig, opts.name, args, opts.optional, docs);
+                    func.apply_types(opts.types, opts.returns.as_ref())?;
 
                     let mut modifiers: HashSet<MethodModifier> = HashSet::new();
 
@@ -237,9 +325,6 @@ impl<'a> ParsedImpl<'a> {
                                 MethodReceiver::Static
                             },
                         };
-                        if matches!(opts.ty, MethodTy::Abstract) {
-                            modifiers.insert(MethodModifier::Abstract);
-                        }
 
                         let builder = func.function_builder(call_type);
 
@@ -250,12 +335,69 @@ impl<'a> ParsedImpl<'a> {
                         });
                     }
                 }
+                syn::ImplItem::Verbatim(tokens) => {
+                    // Methods without a body are not valid Rust, so `syn` leaves them as
+                    // verbatim tokens. They are only allowed as abstract methods and are
+                    // removed from the `impl` block.
+                    let Ok(mut method) = syn::parse2::<BodylessMethod>(tokens.clone()) else {
+                        continue;
+                    };
+                    let attr = PhpFunctionImplAttribute::from_attributes(&method.attrs)?;
+                    if !attr.abstract_.is_present() && !attr.abstract_method.is_present() {
+                        bail!(method.sig => "Methods without a body must be marked with `#[php(abstract)]`.");
+                    }
+                    let name = attr
+                        .rename
+                        .rename_method(method.sig.ident.to_string(), self.change_method_case);
+                    let docs = get_docs(&attr.attrs)?;
+                    let opts = MethodArgs::new(name, attr);
+
+                    self.functions
+                        .push(Self::abstract_method(&mut method.sig, opts, docs)?);
+                    *tokens = TokenStream::new();
+                }
                 _ => {}
             }
         }
         Ok(())
     }
 
+    /// Builds an abstract method, which has no handler and must be implemented
+    /// by PHP classes extending the class.
+    fn abstract_method(
+        sig: &mut Signature,
+        opts: MethodArgs,
+        docs: Vec<String>,
+    ) -> Result<FnBuilder> {
+        if !matches!(opts.ty, MethodTy::Abstract) {
+            bail!(sig => "Constructors, getters and setters cannot be abstract.");
+        }
+        if matches!(opts.vis, Visibility::Private) {
+            bail!(sig => "Abstract methods cannot be private.");
+        }
+
+        let named_rest = take_named_rest(sig)?;
+        let sig = &*sig;
+        let mut args = Args::parse_from_fnargs(sig.inputs.iter(), opts.defaults)?;
+        args.named_rest(named_rest)?;
+        if args.receiver.is_none() {
+            if args.typed.first().is_some_and(|arg| arg.name == "self_") {
+                // `self_: &[mut] ZendClassObject<Self>`
+                args.typed.remove(0);
+            } else {
+                bail!(sig => "Static methods cannot be abstract.");
+            }
+        }
+        let mut func = Function::new(sig, opts.name, args, opts.optional, docs);
+        func.apply_types(opts.types, opts.returns.as_ref())?;
+
+        Ok(FnBuilder {
+            builder: func.abstract_function_builder(),
+            vis: opts.vis,
+            modifiers: HashSet::from([MethodModifier::Abstract]),
+        })
+    }
+
     /// Generates an `impl PhpClassImpl<Self> for PhpClassImplCollector<Self>`
     /// block.
     fn generate_php_class_impl(&self) -> TokenStream {
@@ -274,6 +416,19 @@ impl<'a> ParsedImpl<'a> {
                 (#name, &#path::#ident, &[#(#docs),*])
             }
         });
+        let static_props = self.static_props.iter().map(|prop| {
+            let name = &prop.name;
+            let ident = prop.ident;
+            let flags = match prop.vis {
+                Visibility::Public => quote! { ::ext_php_rs::flags::PropertyFlags::Public },
+                Visibility::Protected => quote! { ::ext_php_rs::flags::PropertyFlags::Protected },
+                Visibility::Private => quote! { ::ext_php_rs::flags::PropertyFlags::Private },
+            };
+            let docs = &prop.docs;
+            quote! {
+                (#name, #flags, &#path::#ident, &[#(#docs),*])
+            }
+        });
 
         quote! {
             impl ::ext_php_rs::internal::class::PhpClassImpl<#path>
@@ -296,6 +451,10 @@ impl<'a> ParsedImpl<'a> {
                 fn get_constants(self) -> &'static [(&'static str, &'static dyn ::ext_php_rs::convert::IntoZvalDyn, &'static [&'static str])] {
                     &[#(#constants),*]
                 }
+
+                fn get_static_properties(self) -> &'static [(&'static str, ::ext_php_rs::flags::PropertyFlags, &'static dyn ::ext_php_rs::convert::IntoZvalDyn, &'static [&'static str])] {
+                    &[#(#static_props),*]
+                }
             }
         }
     }
@@ -0,0 +1,203 @@
+use std::collections::HashMap;
+
+use darling::FromAttributes;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Attribute, Expr, Ident, ItemTrait, LitStr, TraitItem};
+
+use crate::class::ClassEntryAttribute;
+use crate::function::{take_named_rest, Args, Function};
+use crate::helpers::{get_docs, module_item};
+use crate::parsing::{PhpRename, RenameRule};
+use crate::prelude::*;
+
+#[derive(FromAttributes, Debug, Default)]
+#[darling(attributes(php), forward_attrs(doc), default)]
+struct TraitAttributes {
+    /// The name of the PHP interface. Defaults to the same name as the trait.
+    #[darling(flatten)]
+    rename: PhpRename,
+    /// Rename methods to match the given rule.
+    change_method_case: Option<RenameRule>,
+    /// Rename constants to match the given rule.
+    change_constant_case: Option<RenameRule>,
+    /// Interfaces extended by the interface.
+    #[darling(multiple)]
+    extends: Vec<ClassEntryAttribute>,
+    attrs: Vec<Attribute>,
+}
+
+#[derive(FromAttributes, Debug, Default)]
+#[darling(attributes(php), forward_attrs(doc), default)]
+struct MethodAttributes {
+    #[darling(flatten)]
+    rename: PhpRename,
+    defaults: HashMap<Ident, Expr>,
+    optional: Option<Ident>,
+    types: HashMap<Ident, LitStr>,
+    returns: Option<LitStr>,
+    attrs: Vec<Attribute>,
+}
+
+#[derive(FromAttributes, Debug, Default)]
+#[darling(attributes(php), forward_attrs(doc), default)]
+struct ConstAttributes {
+    #[darling(flatten)]
+    rename: PhpRename,
+    attrs: Vec<Attribute>,
+}
+
+pub fn parser(mut input: ItemTrait) -> Result<TokenStream> {
+    let attr = TraitAttributes::from_attributes(&input.attrs)?;
+    input.attrs.retain(|attr| !attr.path().is_ident("php"));
+
+    let ident = &input.ident;
+    let vis = &input.vis;
+    let struct_ident = format_ident!("PhpInterface{ident}");
+    let name = attr.rename.rename(ident.to_string(), RenameRule::Pascal);
+    let docs = get_docs(&attr.attrs)?;
+    let method_case = attr.change_method_case.unwrap_or(RenameRule::Camel);
+    let constant_case = attr
+        .change_constant_case
+        .unwrap_or(RenameRule::ScreamingSnake);
+
+    let mut methods = vec![];
+    let mut constants = vec![];
+    let mut constant_defs = vec![];
+
+    for item in &mut input.items {
+        match item {
+            TraitItem::Fn(method) => {
+                let method_attr = MethodAttributes::from_attributes(&method.attrs)?;
+                method.attrs.retain(|attr| !attr.path().is_ident("php"));
+
+                let name = method_attr
+                    .rename
+                    .rename_method(method.sig.ident.to_string(), method_case);
+                let docs = get_docs(&method_attr.attrs)?;
+                let named_rest = take_named_rest(&mut method.sig)?;
+                let mut args =
+                    Args::parse_from_fnargs(method.sig.inputs.iter(), method_attr.defaults)?;
+                args.named_rest(named_rest)?;
+                let static_flag = args
+                    .receiver
+                    .is_none()
+                    .then(|| quote! { | ::ext_php_rs::flags::MethodFlags::Static });
+                let mut func = Function::new(&method.sig, name, args, method_attr.optional, docs);
+                func.apply_types(method_attr.types, method_attr.returns.as_ref())?;
+                let builder = func.abstract_function_builder();
+
+                methods.push(quote! {
+                    (
+                        #builder,
+                        ::ext_php_rs::flags::MethodFlags::Public
+                            | ::ext_php_rs::flags::MethodFlags::Abstract
+                            #static_flag
+                    )
+                });
+            }
+            TraitItem::Const(constant) => {
+                let const_attr = ConstAttributes::from_attributes(&constant.attrs)?;
+                constant.attrs.retain(|attr| !attr.path().is_ident("php"));
+
+                let Some((_, default)) = &constant.default else {
+                    bail!(constant => "Interface constants must have a value.");
+                };
+                let const_ident = &constant.ident;
+                let ty = &constant.ty;
+                let name = const_attr
+                    .rename
+                    .rename(const_ident.to_string(), constant_case);
+                let docs = get_docs(&const_attr.attrs)?;
+
+                constant_defs.push(quote! {
+                    const #const_ident: #ty = #default;
+                });
+                constants.push(quote! {
+                    (#name, &#struct_ident::#const_ident, &[#(#docs),*])
+                });
+            }
+            _ => {}
+        }
+    }
+
+    let extends = &attr.extends;
+    let struct_docs = format!(" PHP interface `{name}` declared by the [`{ident}`] trait.");
+
+    let module_item = module_item(
+        &name,
+        "Class",
+        &quote! { .interface::<#struct_ident>() },
+        Some(&struct_ident),
+    );
+
+    Ok(quote! {
+        #input
+        #module_item
+
+        #[doc = #struct_docs]
+        #vis struct #struct_ident;
+
+        impl #struct_ident {
+            #(#constant_defs)*
+
+            /// Returns the class entry of the interface. Can be used with
+            /// `#[php(implements(ce = ...))]` on classes implementing it.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the interface has not been registered yet.
+            #vis fn ce() -> &'static ::ext_php_rs::zend::ClassEntry {
+                <Self as ::ext_php_rs::class::RegisteredClass>::get_metadata().ce()
+            }
+        }
+
+        impl ::ext_php_rs::class::RegisteredClass for #struct_ident {
+            const CLASS_NAME: &'static str = #name;
+            const BUILDER_MODIFIER: ::std::option::Option<
+                fn(::ext_php_rs::builders::ClassBuilder) -> ::ext_php_rs::builders::ClassBuilder
+            > = None;
+            const EXTENDS: ::std::option::Option<
+                ::ext_php_rs::class::ClassEntryInfo
+            > = None;
+            const IMPLEMENTS: &'static [::ext_php_rs::class::ClassEntryInfo] = &[
+                #(#extends,)*
+            ];
+            const FLAGS: ::ext_php_rs::flags::ClassFlags = ::ext_php_rs::flags::ClassFlags::Interface;
+            const DOC_COMMENTS: &'static [&'static str] = &[
+                #(#docs,)*
+            ];
+
+            #[inline]
+            fn get_metadata() -> &'static ::ext_php_rs::class::ClassMetadata<Self> {
+                static METADATA: ::ext_php_rs::class::ClassMetadata<#struct_ident> =
+                    ::ext_php_rs::class::ClassMetadata::new();
+                &METADATA
+            }
+
+            #[inline]
+            fn get_properties<'a>() -> ::std::collections::HashMap<
+                &'static str, ::ext_php_rs::internal::property::PropertyInfo<'a, Self>
+            > {
+                ::std::collections::HashMap::new()
+            }
+
+            #[inline]
+            fn method_builders() -> ::std::vec::Vec<
+                (::ext_php_rs::builders::FunctionBuilder<'static>, ::ext_php_rs::flags::MethodFlags)
+            > {
+                vec![#(#methods),*]
+            }
+
+            #[inline]
+            fn constructor() -> ::std::option::Option<::ext_php_rs::class::ConstructorMeta<Self>> {
+                None
+            }
+
+            #[inline]
+            fn constants() -> &'static [(&'static str, &'static dyn ::ext_php_rs::convert::IntoZvalDyn, &'static [&'static str])] {
+                &[#(#constants),*]
+            }
+        }
+    })
+}
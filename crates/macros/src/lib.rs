@@ -5,8 +5,10 @@ mod enum_;
 mod extern_;
 mod fastcall;
 mod function;
+mod gc;
 mod helpers;
 mod impl_;
+mod interface;
 mod module;
 mod parsing;
 mod syn_ext;
@@ -14,7 +16,9 @@ mod zval;
 
 use proc_macro::TokenStream;
 use proc_macro2::TokenStream as TokenStream2;
-use syn::{DeriveInput, ItemConst, ItemEnum, ItemFn, ItemForeignMod, ItemImpl, ItemStruct};
+use syn::{
+    DeriveInput, ItemConst, ItemEnum, ItemFn, ItemForeignMod, ItemImpl, ItemStruct, ItemTrait,
+};
 
 extern crate proc_macro;
 
@@ -41,6 +45,8 @@ extern crate proc_macro;
 /// - `#[php(implements(ce = ce_fn, stub = "InterfaceName"))]` - Implements the
 ///   given interface on the class. Can be used multiple times. `ce_fn` must be
 ///   a valid function with the signature `fn() -> &'static ClassEntry`.
+/// - `#[php(abstract)]` - Marks the class as abstract. Abstract classes cannot
+///   be instantiated from PHP, but can be extended by PHP classes.
 ///
 /// You may also use the `#[php(prop)]` attribute on a struct field to use the
 /// field as a PHP property. By default, the field will be accessible from PHP
@@ -54,6 +60,40 @@ extern crate proc_macro;
 /// - `change_case` - Allows you to rename the property using rename rules, e.g.
 ///   `#[php(change_case = PascalCase)]`
 ///
+/// Properties are declared with the PHP type of the field, derived from its
+/// `IntoZval` implementation, e.g. an `i64` field is declared as `public int
+/// $id` and an `Option<String>` field as `public ?string $name`. Types which
+/// PHP does not allow on properties are left untyped.
+///
+/// The following options change how the property is declared:
+///
+/// - `flags` - Sets the property flags, e.g. `#[php(flags =
+///   PropertyFlags::Protected)]`
+/// - `readonly` - Declares the property as `readonly` (PHP 8.1+), e.g.
+///   `#[php(prop, readonly)]`. As in PHP, the property can be initialized once
+///   from the scope of the declaring class and further writes from PHP throw an
+///   `Error`. Rust code can still modify the field. Building against PHP 8.0
+///   fails with a compile error.
+/// - `default` - Sets the default value of the property in the class
+///   declaration, e.g. `#[php(prop, default = 10)]`. The value is converted
+///   into the type of the field. Readonly properties cannot have a default.
+/// - `stub` - Sets the PHP representation of the default used in stubs, e.g.
+///   `#[php(prop, default = 60 * 60, stub = "3600")]`. Required when the
+///   default is not a literal or `None`.
+///
+/// The value of a property is always read from the Rust struct. The default is
+/// visible through reflection and the generated stubs, and is used by objects
+/// of PHP subclasses which override the constructor without calling
+/// `parent::__construct()`, as those objects have no Rust value.
+///
+/// Properties can be modified indirectly, e.g. `$obj->items[] = 1`,
+/// `$obj->count++` or `$obj->object->name = 'x'`. The value is copied from the
+/// Rust struct into the declared property slot, modified there by PHP and
+/// written back into the struct the next time the object is accessed. PHP
+/// references to a property, e.g. `$ref = &$obj->items`, are detached when the
+/// value is written back. Unsetting a property throws an `Error`, as the field
+/// always holds a value.
+///
 /// ## Restrictions
 ///
 /// ### No lifetime parameters
@@ -85,6 +125,29 @@ extern crate proc_macro;
 /// where there needs to be a single compiled implementation of `Foo` which is
 /// integrated with the PHP interpreter.
 ///
+/// ## Abstract classes
+///
+/// Classes marked with `#[php(abstract)]`, or declaring abstract methods in
+/// their [`#[php_impl]`](./impl.md#abstract-methods) block, are abstract and
+/// can be extended by PHP classes. The Rust object is created by the
+/// constructor of the Rust class, so PHP subclasses defining their own
+/// constructor must call `parent::__construct()` before using any of the
+/// inherited Rust methods:
+///
+/// ```php
+/// <?php
+///
+/// class Square extends Shape {
+///     public function __construct(private float $side) {
+///         parent::__construct();
+///     }
+///
+///     public function area(): float {
+///         return $this->side ** 2;
+///     }
+/// }
+/// ```
+///
 /// ## Example
 ///
 /// This example creates a PHP class `Human`, adding a PHP property `address`.
@@ -200,6 +263,159 @@ extern crate proc_macro;
 /// }
 /// # fn main() {}
 /// ````
+///
+/// ## Operator overloading
+///
+/// Classes can overload PHP operators the same way extensions such as GMP do,
+/// by implementing the traits in the `ext_php_rs::ops` module. The object
+/// handlers of the class are installed automatically for each implemented
+/// trait:
+///
+/// - `PhpCompare` - Comparison operators such as `==`, `<` and `<=>`. The
+///   object may be either operand, the ordering is reversed when it is the
+///   right operand.
+/// - `PhpCast` - Casts to `string`, `int`, `float` and `bool`, including
+///   implicit conversions such as string interpolation.
+/// - `PhpOperators` - Arithmetic, concatenation and bitwise operators,
+///   including compound assignments such as `+=`.
+/// - `PhpCountable` - `count($obj)`.
+/// - `PhpArrayAccess` - Array access such as `$obj[$key]`, `$obj[] = $value`,
+///   `isset($obj[$key])` and `unset($obj[$key])`.
+/// - `PhpIterable` - Iteration with `foreach` over a Rust iterator. The class
+///   implements `IteratorAggregate`, with a `getIterator()` method returning an
+///   iterator over the object. The iterator is created whenever PHP starts
+///   iterating, and must own its elements, e.g.
+///   `Ok(self.items.clone().into_iter().enumerate())`.
+///
+/// Unlike the `Countable` and `ArrayAccess` interfaces, `PhpCountable` and
+/// `PhpArrayAccess` do not go through PHP method calls. The class only
+/// implements the interfaces if they are also given with
+/// `#[php(implements(...))]`.
+///
+/// Returning `None` from any of the trait methods falls back to the default PHP
+/// behaviour, e.g. an `Unsupported operand types` error for operators, or the
+/// standard object comparison, which casts the object when compared with a
+/// scalar.
+///
+/// ```rust,no_run,ignore
+/// # #![cfg_attr(windows, feature(abi_vectorcall))]
+/// # extern crate ext_php_rs;
+/// use std::cmp::Ordering;
+///
+/// use ext_php_rs::{
+///     convert::IntoZval,
+///     flags::DataType,
+///     ops::{Operator, PhpCast, PhpCompare, PhpOperators},
+///     prelude::*,
+///     types::Zval,
+/// };
+///
+/// #[php_class]
+/// pub struct Money {
+///     cents: i64,
+/// }
+///
+/// #[php_impl]
+/// impl Money {
+///     pub fn __construct(cents: i64) -> Self {
+///         Self { cents }
+///     }
+/// }
+///
+/// fn cents(value: &Zval) -> Option<i64> {
+///     value
+///         .extract::<&Money>()
+///         .map(|money| money.cents)
+///         .or_else(|| value.long())
+/// }
+///
+/// impl PhpCompare for Money {
+///     fn compare(&self, other: &Zval) -> PhpResult<Option<Ordering>> {
+///         Ok(cents(other).map(|other| self.cents.cmp(&other)))
+///     }
+/// }
+///
+/// impl PhpCast for Money {
+///     fn cast(&self, ty: DataType) -> PhpResult<Option<Zval>> {
+///         Ok(match ty {
+///             DataType::String => Some(format!("{:.2}", self.cents as f64 / 100.0).into_zval(false)?),
+///             DataType::Long => Some(self.cents.into_zval(false)?),
+///             _ => None,
+///         })
+///     }
+/// }
+///
+/// impl PhpOperators for Money {
+///     fn operation(op: Operator, lhs: &Zval, rhs: &Zval) -> PhpResult<Option<Zval>> {
+///         let (Some(lhs), Some(rhs)) = (cents(lhs), cents(rhs)) else {
+///             return Ok(None);
+///         };
+///         let cents = match op {
+///             Operator::Add => lhs + rhs,
+///             Operator::Sub => lhs - rhs,
+///             _ => return Ok(None),
+///         };
+///         Ok(Some(Money { cents }.into_zval(false)?))
+///     }
+/// }
+///
+/// #[php_module]
+/// pub fn get_module(module: ModuleBuilder) -> ModuleBuilder {
+///     module.class::<Money>()
+/// }
+/// # fn main() {}
+/// ```
+///
+/// ```php
+/// <?php
+///
+/// $a = new Money(150);
+/// $b = new Money(250);
+///
+/// var_dump($a < $b); // bool(true)
+/// var_dump((string) ($a + $b)); // string(4) "4.00"
+/// ```
+///
+/// ## Cloning
+///
+/// Objects of classes implementing `Clone` can be cloned in PHP with `clone`.
+/// The Rust value is cloned with its `Clone` implementation and the PHP
+/// properties are copied, after which PHP calls the `__clone()` method of the
+/// new object, if the class defines one. Cloning an object of a class which
+/// does not implement `Clone` throws an `Error`.
+///
+/// ```rust,no_run,ignore
+/// # #![cfg_attr(windows, feature(abi_vectorcall))]
+/// # extern crate ext_php_rs;
+/// use ext_php_rs::prelude::*;
+///
+/// #[php_class]
+/// #[derive(Clone)]
+/// pub struct Counter {
+///     #[php(prop)]
+///     count: i64,
+/// }
+///
+/// #[php_impl]
+/// impl Counter {
+///     pub fn __construct() -> Self {
+///         Self { count: 0 }
+///     }
+///
+///     pub fn __clone(&mut self) {
+///         self.count = 0;
+///     }
+/// }
+/// # fn main() {}
+/// ```
+///
+/// ## Cycle collection
+///
+/// Classes holding PHP values, such as callbacks or objects referring back to
+/// the object, must report them to the PHP cycle collector, otherwise reference
+/// cycles through the Rust struct are never freed. Implement or derive the
+/// `GcTraverse` trait on the class, see the [`GcTraverse`](./gc_traverse.md)
+/// derive macro.
 // END DOCS FROM classes.md
 #[proc_macro_attribute]
 pub fn php_class(args: TokenStream, input: TokenStream) -> TokenStream {
@@ -339,6 +555,131 @@ fn php_enum_internal(_args: TokenStream2, input: TokenStream2) -> TokenStream2 {
     enum_::parser(input).unwrap_or_else(|e| e.to_compile_error())
 }
 
+// BEGIN DOCS FROM interface.md
+/// # `#[php_interface]` Attribute
+///
+/// Traits can be exported to PHP as interfaces with the `#[php_interface]`
+/// attribute macro. The trait itself is left untouched; the macro generates a
+/// unit struct named `PhpInterface<TraitName>` which implements
+/// `RegisteredClass` and describes the PHP interface. To register the interface
+/// use the `interface::<PhpInterfaceTraitName>()` method on the `ModuleBuilder`
+/// in the `#[php_module]` macro.
+///
+/// Interfaces are registered before classes, so classes in the same module can
+/// implement them.
+///
+/// ## Options
+///
+/// The `#[php_interface]` attribute can be configured with the following
+/// options:
+///
+/// - `#[php(name = "InterfaceName")]` or `#[php(change_case = snake_case)]` -
+///   Sets the name of the interface in PHP. The default is the `PascalCase`
+///   name of the trait.
+/// - `#[php(change_method_case = snake_case)]` - Sets the case of the methods
+///   in PHP. The default is `camelCase`.
+/// - `#[php(change_constant_case = snake_case)]` - Sets the case of the
+///   constants in PHP. The default is `UPPER_CASE`.
+/// - `#[php(extends(ce = ce_fn, stub = "ParentInterface"))]` - Extends the
+///   given interface. Can be used multiple times. `ce_fn` must be a valid
+///   function with the signature `fn() -> &'static ClassEntry`.
+///
+/// Methods of the trait are exported as public abstract methods. Methods taking
+/// `self` become instance methods, methods without a receiver become static
+/// methods. Methods support the same `name`, `change_case`, `defaults`,
+/// `optional`, `types` and `returns` options as [`#[php_impl]`](./impl.md)
+/// methods.
+///
+/// Associated constants of the trait are exported as interface constants and
+/// must have a value.
+///
+/// ## Implementing the interface
+///
+/// The generated struct provides a `ce()` function returning the class entry of
+/// the interface, which can be used to implement the interface on a class:
+///
+/// ```rust,ignore
+/// #[php_class]
+/// #[php(implements(ce = PhpInterfaceGreeter::ce, stub = "Greeter"))]
+/// pub struct Hello;
+/// ```
+///
+/// The class must then define all methods of the interface in its `#[php_impl]`
+/// block, otherwise PHP refuses to register it.
+///
+/// ## Example
+///
+/// This example creates a PHP interface `Greeter` with an instance method, a
+/// static method and a constant, and a class implementing it.
+///
+/// ```rust,no_run,ignore
+/// # #![cfg_attr(windows, feature(abi_vectorcall))]
+/// # extern crate ext_php_rs;
+/// use ext_php_rs::prelude::*;
+///
+/// #[php_interface]
+/// #[php(name = "Greeter")]
+/// pub trait Greeter {
+///     const DEFAULT_NAME: &'static str = "World";
+///
+///     fn greet(&self, name: String) -> String;
+///
+///     fn version() -> i64;
+/// }
+///
+/// #[php_class]
+/// #[php(implements(ce = PhpInterfaceGreeter::ce, stub = "Greeter"))]
+/// pub struct Hello;
+///
+/// #[php_impl]
+/// impl Hello {
+///     pub fn greet(&self, name: String) -> String {
+///         format!("Hello, {name}!")
+///     }
+///
+///     pub fn version() -> i64 {
+///         1
+///     }
+/// }
+///
+/// #[php_module]
+/// pub fn get_module(module: ModuleBuilder) -> ModuleBuilder {
+///     module
+///         .interface::<PhpInterfaceGreeter>()
+///         .class::<Hello>()
+/// }
+/// # fn main() {}
+/// ```
+///
+/// This generates the following PHP interface:
+///
+/// ```php
+/// <?php
+///
+/// interface Greeter {
+///     const DEFAULT_NAME = 'World';
+///
+///     public function greet(string $name): string;
+///
+///     public static function version(): int;
+/// }
+/// ```
+// END DOCS FROM interface.md
+#[proc_macro_attribute]
+pub fn php_interface(args: TokenStream, input: TokenStream) -> TokenStream {
+    php_interface_internal(args.into(), input.into()).into()
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn php_interface_internal(args: TokenStream2, input: TokenStream2) -> TokenStream2 {
+    let input = parse_macro_input2!(input as ItemTrait);
+    if !args.is_empty() {
+        return err!(input => "`#[php_interface(<args>)]` args are not supported. Please use `#[php(<args>)]` instead.").to_compile_error();
+    }
+
+    interface::parser(input).unwrap_or_else(|e| e.to_compile_error())
+}
+
 // BEGIN DOCS FROM function.md
 /// # `#[php_function]` Attribute
 ///
@@ -489,6 +830,91 @@ fn php_enum_internal(_args: TokenStream2, input: TokenStream2) -> TokenStream2 {
 /// # fn main() {}
 /// ```
 ///
+/// ## Named arguments
+///
+/// Functions can be called with named arguments, e.g. `greet(name: "Ferris")`,
+/// which PHP maps onto the parameters of the same name. Optional parameters of
+/// type `Option<T>` default to `null`, so they can be skipped by naming the
+/// parameters after them. Calls with a named argument which does not match a
+/// parameter throw an `Error`.
+///
+/// Unknown named arguments can be collected by marking the last argument of the
+/// function with `#[php(named_rest)]`, which is the equivalent of a PHP
+/// function using the `...$options` syntax and only accepting named arguments.
+/// The argument must be a `HashMap<String, T>`, where `T` is the type of the
+/// values. A named argument whose value cannot be converted into `T` throws a
+/// `TypeError`.
+///
+/// ```rust,no_run,ignore
+/// # #![cfg_attr(windows, feature(abi_vectorcall))]
+/// # extern crate ext_php_rs;
+/// use std::collections::HashMap;
+/// use ext_php_rs::{prelude::*, types::Zval};
+///
+/// /// This can be called from PHP as
+/// /// `connect("localhost", port: 8080, timeout: 5, persistent: true)`.
+/// #[php_function]
+/// pub fn connect(
+///     host: String,
+///     port: Option<i64>,
+///     #[php(named_rest)] options: HashMap<String, Zval>,
+/// ) -> String {
+///     format!("{host}:{} ({} options)", port.unwrap_or(80), options.len())
+/// }
+///
+/// #[php_module]
+/// pub fn get_module(module: ModuleBuilder) -> ModuleBuilder {
+///     module.function(wrap_function!(connect))
+/// }
+/// # fn main() {}
+/// ```
+///
+/// ## Type declarations
+///
+/// The PHP types of the parameters and return value are derived from the Rust
+/// types, e.g. `i64` is declared as `int` and `Option<String>` as `?string`. An
+/// enum deriving [`ZvalConvert`](./zval_convert.md) is declared as the union of
+/// the types of its variants.
+///
+/// The derived types can be overridden with the `types` and `returns` options,
+/// which accept any PHP type declaration: union types (`int|string`),
+/// intersection types (`Countable&Traversable`), nullable types (`?Foo`),
+/// `static`, `self` and `never`. The declared types are shown by reflection and
+/// in stubs, while the arguments are still converted into the Rust types.
+///
+/// ```rust,no_run,ignore
+/// # #![cfg_attr(windows, feature(abi_vectorcall))]
+/// # extern crate ext_php_rs;
+/// use ext_php_rs::{prelude::*, types::Zval};
+///
+/// /// Declared as `user_key(int|string $id): string`.
+/// #[php_function]
+/// #[php(types(id = "int|string"))]
+/// pub fn user_key(id: &Zval) -> String {
+///     id.long()
+///         .map_or_else(|| id.string().unwrap_or_default(), |id| id.to_string())
+/// }
+///
+/// /// Declared as `count_items(Countable&Traversable $items): int`.
+/// #[php_function]
+/// #[php(types(items = "Countable&Traversable"))]
+/// pub fn count_items(items: &Zval) -> i64 {
+///     items.object().map_or(0, |_| 1)
+/// }
+///
+/// #[php_module]
+/// pub fn get_module(module: ModuleBuilder) -> ModuleBuilder {
+///     module
+///         .function(wrap_function!(user_key))
+///         .function(wrap_function!(count_items))
+/// }
+/// # fn main() {}
+/// ```
+///
+/// PHP versions before 8.3 cannot declare intersection types for functions of
+/// extensions, so they are declared as `object`. `never` is declared as `void`
+/// on PHP 8.0.
+///
 /// ## Returning `Result<T, E>`
 ///
 /// You can also return a `Result` from the function. The error variant will be
@@ -645,6 +1071,167 @@ fn php_const_internal(args: TokenStream2, input: TokenStream2) -> TokenStream2 {
 /// }
 /// # fn main() {}
 /// ```
+///
+/// ## Information table
+///
+/// Instead of printing the information shown by `phpinfo()` with the
+/// `info_table_*` macros, the table can be built with `InfoTable` and set with
+/// `ModuleBuilder::info_table`. The table supports headers, rows and headers
+/// spanning multiple columns, and is rendered as text or HTML by PHP. The INI
+/// settings of the extension are shown at the position of `ini_entries`, or
+/// after the table. The closure building the table is called whenever
+/// `phpinfo()` shows the extension, and may capture values computed when the
+/// module is built.
+///
+/// ```rust,no_run,ignore
+/// # #![cfg_attr(windows, feature(abi_vectorcall))]
+/// # extern crate ext_php_rs;
+/// use ext_php_rs::{builders::InfoTable, prelude::*};
+///
+/// #[php_module]
+/// pub fn get_module(module: ModuleBuilder) -> ModuleBuilder {
+///     module.info_table(|| {
+///         InfoTable::new()
+///             .header(["my extension", "enabled"])
+///             .row(["Version", env!("CARGO_PKG_VERSION")])
+///             .colspan_header(2, "Settings")
+///             .ini_entries()
+///     })
+/// }
+/// # fn main() {}
+/// ```
+///
+/// ## Module globals
+///
+/// Extensions can store their state in module globals. The type holding the
+/// globals is set with the `#[php(globals = "...")]` attribute, or by calling
+/// `ModuleBuilder::globals` and implementing the `ModuleGlobals` trait. The
+/// type must implement `Default`, which is used to create the globals when the
+/// extension is loaded.
+///
+/// When PHP is built with thread safety (ZTS), every thread has its own
+/// globals. Otherwise, the globals are shared for the lifetime of the process.
+/// State which should only live for a single request must be reset in a request
+/// startup function.
+///
+/// The globals are accessed with `ModuleGlobals::get` and
+/// `ModuleGlobals::get_mut`, which return guards similar to the ones returned
+/// by `ExecutorGlobals::get`.
+///
+/// ```rust,no_run,ignore
+/// # #![cfg_attr(windows, feature(abi_vectorcall))]
+/// # extern crate ext_php_rs;
+/// use ext_php_rs::{prelude::*, zend::ModuleGlobals};
+///
+/// #[derive(Default)]
+/// pub struct Globals {
+///     calls: i64,
+/// }
+///
+/// #[php_function]
+/// pub fn count_calls() -> i64 {
+///     let mut globals = Globals::get_mut();
+///     globals.calls += 1;
+///     globals.calls
+/// }
+///
+/// #[php_module]
+/// #[php(globals = "Globals")]
+/// pub fn get_module(module: ModuleBuilder) -> ModuleBuilder {
+///     module.function(wrap_function!(count_calls))
+/// }
+/// # fn main() {}
+/// ```
+///
+/// ## Lifecycle hooks
+///
+/// Code which should run when the extension or a request is started or shut
+/// down can be added as closures with `ModuleBuilder::on_minit`,
+/// `on_mshutdown`, `on_rinit` and `on_rshutdown`. The closures are passed the
+/// module number, can capture state and return a `Result`, whose error is
+/// reported as a PHP warning.
+///
+/// Hooks of the same stage are called in the order they were added, so several
+/// crates contributing to one module can each add their own hooks. Startup
+/// hooks stop at the first failure, which prevents the extension or request
+/// from starting. Shutdown hooks are always all called. Panics in hooks are
+/// caught and treated as failures.
+///
+/// ```rust,no_run,ignore
+/// # #![cfg_attr(windows, feature(abi_vectorcall))]
+/// # extern crate ext_php_rs;
+/// use std::sync::atomic::{AtomicU64, Ordering};
+///
+/// use ext_php_rs::prelude::*;
+///
+/// static REQUESTS: AtomicU64 = AtomicU64::new(0);
+///
+/// #[php_module]
+/// pub fn get_module(module: ModuleBuilder) -> ModuleBuilder {
+///     module
+///         .on_minit(|_| std::env::var("HOME").map(|_| ()))
+///         .on_rinit(|_| {
+///             REQUESTS.fetch_add(1, Ordering::Relaxed);
+///             Ok::<_, String>(())
+///         })
+/// }
+/// # fn main() {}
+/// ```
+///
+/// ## Automatic registration
+///
+/// With the `inventory` feature enabled, functions, classes, interfaces and
+/// enums declared with `#[php_function]`, `#[php_class]`, `#[php_interface]`
+/// and `#[php_enum]` register themselves with the module, including items
+/// declared in other crates of the dependency graph. The `#[php_module]` macro
+/// adds them by calling `ModuleBuilder::auto_register` after the module
+/// function has run. Classes are registered after the classes and interfaces
+/// they extend or implement. Items which were added manually are not added a
+/// second time.
+///
+/// ```toml
+/// [dependencies]
+/// ext-php-rs = { version = "*", features = ["inventory"] }
+/// ```
+///
+/// ```rust,ignore
+/// use ext_php_rs::prelude::*;
+///
+/// #[php_function]
+/// pub fn hello_world() -> &'static str {
+///     "Hello, world!"
+/// }
+///
+/// // `hello_world` is registered without being listed.
+/// #[php_module]
+/// pub fn get_module(module: ModuleBuilder) -> ModuleBuilder {
+///     module
+/// }
+/// ```
+///
+/// ## Dependencies
+///
+/// Extensions which depend on other extensions declare them with the
+/// `#[php(requires = [...])]`, `#[php(conflicts = [...])]` and
+/// `#[php(optional = [...])]` attributes, or by calling
+/// `ModuleBuilder::requires`, `ModuleBuilder::conflicts` and
+/// `ModuleBuilder::optional`. PHP refuses to load the extension if a required
+/// extension is missing or a conflicting extension is loaded, and starts
+/// required and optional extensions first, so their classes and functions are
+/// available in the startup function.
+///
+/// ```rust,no_run,ignore
+/// # #![cfg_attr(windows, feature(abi_vectorcall))]
+/// # extern crate ext_php_rs;
+/// use ext_php_rs::prelude::*;
+///
+/// #[php_module]
+/// #[php(requires = ["json"], optional = ["mbstring"])]
+/// pub fn get_module(module: ModuleBuilder) -> ModuleBuilder {
+///     module
+/// }
+/// # fn main() {}
+/// ```
 // END DOCS FROM module.md
 #[proc_macro_attribute]
 pub fn php_module(args: TokenStream, input: TokenStream) -> TokenStream {
@@ -678,15 +1265,18 @@ fn php_module_internal(args: TokenStream2, input: TokenStream2) -> TokenStream2
 ///
 /// ## Options
 ///
-/// By default all constants are renamed to `UPPER_CASE` and all methods are
-/// renamed to camelCase. This can be changed by passing the
-/// `change_method_case` and `change_constant_case` as `#[php]` attributes on
-/// the `impl` block. The options are:
+/// By default all constants are renamed to `UPPER_CASE` and all methods and
+/// static properties are renamed to camelCase. This can be changed by passing
+/// the `change_method_case`, `change_constant_case` and
+/// `change_static_prop_case` as `#[php]` attributes on the `impl` block. The
+/// options are:
 ///
 /// - `#[php(change_method_case = "snake_case")]` - Renames the method to snake
 ///   case.
 /// - `#[php(change_constant_case = "snake_case")]` - Renames the constant to
 ///   snake case.
+/// - `#[php(change_static_prop_case = "snake_case")]` - Renames the static
+///   property to snake case.
 ///
 /// See the [`name` and `change_case`](./php.md#name-and-change_case) section
 /// for a list of all available cases.
@@ -716,9 +1306,13 @@ fn php_module_internal(args: TokenStream2, input: TokenStream2) -> TokenStream2
 ///   visibility of the method.
 /// - `#[php(name = "method_name")]` - Renames the PHP method to a different
 ///   identifier, without renaming the Rust method name.
+/// - `#[php(types(i = "int|string"))]` and `#[php(returns = "static")]` -
+///   Overrides the PHP types of parameter(s) and the return value.
 ///
-/// The `#[php(defaults)]` and `#[php(optional)]` attributes operate the same as
-/// the equivalent function attribute parameters.
+/// The `#[php(defaults)]`, `#[php(optional)]`, `#[php(types)]` and
+/// `#[php(returns)]` attributes operate the same as the equivalent function
+/// attribute parameters, see [type
+/// declarations](./function.md#type-declarations).
 ///
 /// ### Constructors
 ///
@@ -737,12 +1331,101 @@ fn php_module_internal(args: TokenStream2, input: TokenStream2) -> TokenStream2
 ///
 /// Constructors cannot use the visibility or rename attributes listed above.
 ///
+/// ### Abstract methods
+///
+/// Methods annotated with `#[php(abstract)]` are exported as abstract methods,
+/// which must be implemented by PHP classes extending the class. The method can
+/// either be declared without a body, in which case it is removed from the Rust
+/// `impl` block, or with a placeholder body such as `unimplemented!()`, which
+/// is never called from PHP.
+///
+/// Abstract methods must take `&self`, `&mut self` or `self_` and cannot be
+/// private. A class declaring abstract methods is abstract and cannot be
+/// instantiated from PHP. See the
+/// [`#[php_class]`](./classes.md#abstract-classes) documentation for how PHP
+/// classes can extend it.
+///
+/// ```rust,no_run,ignore
+/// # #![cfg_attr(windows, feature(abi_vectorcall))]
+/// # extern crate ext_php_rs;
+/// use ext_php_rs::prelude::*;
+///
+/// #[php_class]
+/// #[php(abstract)]
+/// pub struct Shape;
+///
+/// #[php_impl]
+/// impl Shape {
+///     pub fn __construct() -> Self {
+///         Self
+///     }
+///
+///     #[php(abstract)]
+///     pub fn area(&self) -> f64;
+///
+///     #[php(abstract)]
+///     pub fn name(&self) -> String {
+///         unimplemented!()
+///     }
+/// }
+/// # fn main() {}
+/// ```
+///
 /// ## Constants
 ///
 /// Constants are defined as regular Rust `impl` constants. Any type that
 /// implements `IntoZval` can be used as a constant. Constant visibility is not
 /// supported at the moment, and therefore no attributes are valid on constants.
 ///
+/// ## Static properties
+///
+/// Constants annotated with `#[php(static_prop)]` are exported as static
+/// properties instead, using the value of the constant as the initial value of
+/// the property. Static properties are renamed to camelCase by default, or with
+/// the rule given by `change_static_prop_case`, and the visibility can be
+/// changed with `#[php(vis = "protected")]` or `#[php(vis = "private")]`.
+///
+/// The current value of a static property can be read and written from Rust
+/// through the class entry, with `ClassEntry::get_static_property` and
+/// `ClassEntry::set_static_property`:
+///
+/// ```rust,no_run,ignore
+/// # #![cfg_attr(windows, feature(abi_vectorcall))]
+/// # extern crate ext_php_rs;
+/// use ext_php_rs::{class::RegisteredClass, prelude::*};
+///
+/// #[php_class]
+/// pub struct Counter;
+///
+/// #[php_impl]
+/// impl Counter {
+///     /// Number of times `increment` was called.
+///     #[php(static_prop)]
+///     const COUNT: i64 = 0;
+///
+///     pub fn increment() -> PhpResult<i64> {
+///         let ce = Self::get_metadata().ce();
+///         let count = ce.get_static_property::<i64>("count")? + 1;
+///         ce.set_static_property("count", count)?;
+///         Ok(count)
+///     }
+/// }
+/// # fn main() {}
+/// ```
+///
+/// This exports the following class:
+///
+/// ```php
+/// <?php
+///
+/// class Counter {
+///     /** Number of times `increment` was called. */
+///     public static $count;
+///
+///     public static function increment(): int {}
+/// }
+/// ```
+///
 /// ## Property getters and setters
 ///
 /// You can add properties to classes which use Rust functions as getters and/or
@@ -1030,11 +1713,117 @@ fn php_extern_internal(_: TokenStream2, input: TokenStream2) -> TokenStream2 {
 /// # fn main() {}
 /// ```
 ///
+/// ### Attributes
+///
+/// The conversion of a struct can be customized with `#[php]` attributes on the
+/// struct and its fields.
+///
+/// On the struct:
+///
+/// - `#[php(array)]` - Converts the struct to and from an associative array
+///   instead of an object.
+/// - `#[php(class = "Name")]` - Converts the struct into an object of the given
+///   class instead of `stdClass`. The class must exist when the value is
+///   converted, and its constructor is not called. Only objects which are
+///   instances of the class are accepted, and parameters and return values are
+///   declared with the class as their type.
+/// - `#[php(change_case = "camelCase")]` - Changes the case of all keys or
+///   property names. Available cases are the same as for
+///   [`change_case`](./php.md#name-and-change_case).
+///
+/// On the fields:
+///
+/// - `#[php(name = "name")]` or `#[php(change_case = "...")]` - Renames the key
+///   or property of the field.
+/// - `#[php(default)]` - Uses `Default::default()` when the key or property is
+///   missing. `#[php(default = expr)]` uses the given expression instead.
+/// - `#[php(skip)]` - Leaves the field out of the conversion. When converting
+///   from PHP, the field is set to `Default::default()`, or the expression
+///   given with `default = expr`.
+/// - `#[php(flatten)]` - Reads and writes the fields of the field, which must
+///   be a struct deriving `ZvalConvert`, from the same array or object instead
+///   of a nested one.
+///
+/// ```rust,no_run,ignore
+/// # #![cfg_attr(windows, feature(abi_vectorcall))]
+/// # extern crate ext_php_rs;
+/// use ext_php_rs::prelude::*;
+///
+/// #[derive(ZvalConvert)]
+/// pub struct Retry {
+///     attempts: u32,
+///     #[php(default = 100)]
+///     backoff_ms: u64,
+/// }
+///
+/// #[derive(ZvalConvert)]
+/// #[php(array, change_case = "camelCase")]
+/// pub struct Config {
+///     host_name: String,
+///     #[php(default)]
+///     port: u16,
+///     #[php(name = "label")]
+///     tag: Option<String>,
+///     #[php(skip)]
+///     connections: Vec<String>,
+///     #[php(flatten)]
+///     retry: Retry,
+/// }
+///
+/// #[derive(ZvalConvert)]
+/// #[php(class = "App\\Point")]
+/// pub struct Point {
+///     x: f64,
+///     y: f64,
+/// }
+///
+/// #[php_function]
+/// pub fn connect(config: Config) -> Config {
+///     config
+/// }
+///
+/// #[php_function]
+/// pub fn origin() -> Point {
+///     Point { x: 0.0, y: 0.0 }
+/// }
+///
+/// #[php_module]
+/// pub fn get_module(module: ModuleBuilder) -> ModuleBuilder {
+///     module
+///         .function(wrap_function!(connect))
+///         .function(wrap_function!(origin))
+/// }
+/// # fn main() {}
+/// ```
+///
+/// Calling from PHP:
+///
+/// ```php
+/// <?php
+///
+/// namespace App;
+///
+/// class Point
+/// {
+///     public float $x;
+///     public float $y;
+/// }
+///
+/// // ['hostName' => 'localhost', 'port' => 0, 'label' => null, 'attempts' => 3, 'backoffMs' => 100]
+/// var_dump(\connect(['hostName' => 'localhost', 'label' => null, 'attempts' => 3]));
+/// var_dump(\origin()); // object(App\Point)
+/// ```
+///
 /// ## Enums
 ///
 /// When used on an enum, the `FromZval` implementation will treat the enum as a
-/// tagged union with a mixed datatype. This allows you to accept multiple types
-/// in a parameter, for example, a string and an integer.
+/// tagged union. This allows you to accept multiple types in a parameter, for
+/// example, a string and an integer.
+///
+/// Parameters and return values of the enum are declared to PHP as a union of
+/// the types of the variants, e.g. `int|string`. If the enum has a default
+/// variant, parameters accept any value and are declared as `mixed`, and return
+/// values include `null`.
 ///
 /// The enum variants must not have named fields, and each variant must have
 /// exactly one field (the type to extract from the zval). Optionally, the enum
@@ -1092,7 +1881,7 @@ fn php_extern_internal(_: TokenStream2, input: TokenStream2) -> TokenStream2 {
 /// var_dump(give_union()); // int(5)
 /// ```
 // END DOCS FROM zval_convert.md
-#[proc_macro_derive(ZvalConvert)]
+#[proc_macro_derive(ZvalConvert, attributes(php))]
 pub fn zval_convert_derive(input: TokenStream) -> TokenStream {
     zval_convert_derive_internal(input.into()).into()
 }
@@ -1103,6 +1892,91 @@ fn zval_convert_derive_internal(input: TokenStream2) -> TokenStream2 {
     zval::parser(input).unwrap_or_else(|e| e.to_compile_error())
 }
 
+// BEGIN DOCS FROM gc_traverse.md
+/// # `GcTraverse` Derive Macro
+///
+/// The `#[derive(GcTraverse)]` macro derives the `GcTraverse` trait on a struct
+/// or enum. The trait reports the PHP values held by a class to the PHP cycle
+/// collector.
+///
+/// PHP frees values which reference each other, e.g. an object stored in a
+/// callback held by the same object, with its cycle collector. The collector
+/// only sees the values reported by the `get_gc` object handler, so without the
+/// trait PHP values held by the Rust struct of a `#[php_class]` form cycles
+/// which are never freed. When a class implements `GcTraverse`, the
+/// `#[php_class]` macro installs a `get_gc` handler reporting the values of the
+/// struct in addition to the properties of the object.
+///
+/// All fields of the struct or enum must implement `GcTraverse`. The trait is
+/// implemented for `Zval`, `ZendObject`, `ZBox<ZendObject>`, the common
+/// containers such as `Option`, `Vec` and `HashMap`, and scalar types such as
+/// integers and `String`, which hold no PHP values. Generics are allowed, the
+/// implementation adds a `GcTraverse` bound to all generic types.
+///
+/// Types which cannot derive the trait can implement it by adding their PHP
+/// values to the `GcBuffer` with `add_zval` and `add_object`.
+///
+/// ## Example
+///
+/// ```rust,no_run,ignore
+/// # #![cfg_attr(windows, feature(abi_vectorcall))]
+/// # extern crate ext_php_rs;
+/// use std::collections::HashMap;
+///
+/// use ext_php_rs::{prelude::*, types::Zval};
+///
+/// #[php_class]
+/// #[derive(GcTraverse)]
+/// pub struct EventEmitter {
+///     listeners: HashMap<String, Vec<Zval>>,
+/// }
+///
+/// #[php_impl]
+/// impl EventEmitter {
+///     pub fn __construct() -> Self {
+///         Self {
+///             listeners: HashMap::new(),
+///         }
+///     }
+///
+///     pub fn on(&mut self, event: String, listener: &Zval) {
+///         self.listeners
+///             .entry(event)
+///             .or_default()
+///             .push(listener.shallow_clone());
+///     }
+/// }
+///
+/// #[php_module]
+/// pub fn get_module(module: ModuleBuilder) -> ModuleBuilder {
+///     module.class::<EventEmitter>()
+/// }
+/// # fn main() {}
+/// ```
+///
+/// Listeners referring back to the emitter are freed by the cycle collector
+/// once the emitter is no longer used:
+///
+/// ```php
+/// <?php
+///
+/// $emitter = new EventEmitter();
+/// $emitter->on('event', function () use ($emitter) {});
+/// unset($emitter);
+/// gc_collect_cycles(); // frees the emitter and the listener
+/// ```
+// END DOCS FROM gc_traverse.md
+#[proc_macro_derive(GcTraverse)]
+pub fn gc_traverse_derive(input: TokenStream) -> TokenStream {
+    gc_traverse_derive_internal(input.into()).into()
+}
+
+fn gc_traverse_derive_internal(input: TokenStream2) -> TokenStream2 {
+    let input = parse_macro_input2!(input as DeriveInput);
+
+    gc::parser(input).unwrap_or_else(|e| e.to_compile_error())
+}
+
 /// Defines an `extern` function with the Zend fastcall convention based on
 /// operating system.
 ///
@@ -1262,6 +2136,7 @@ mod tests {
                 ("php_extern", php_extern_internal as AttributeFn),
                 ("php_function", php_function_internal as AttributeFn),
                 ("php_impl", php_impl_internal as AttributeFn),
+                ("php_interface", php_interface_internal as AttributeFn),
                 ("php_module", php_module_internal as AttributeFn),
             ],
         )
@@ -1,7 +1,7 @@
 use darling::FromAttributes;
 use proc_macro2::{Ident, TokenStream};
 use quote::quote;
-use syn::{ItemFn, Signature};
+use syn::{ItemFn, LitStr, Path, Signature};
 
 use crate::prelude::*;
 
@@ -9,6 +9,10 @@ use crate::prelude::*;
 #[darling(default, attributes(php))]
 pub(crate) struct PhpModuleAttribute {
     startup: Option<Ident>,
+    globals: Option<Path>,
+    requires: Vec<LitStr>,
+    conflicts: Vec<LitStr>,
+    optional: Vec<LitStr>,
 }
 
 pub fn parser(input: ItemFn) -> Result<TokenStream> {
@@ -22,8 +26,26 @@ pub fn parser(input: ItemFn) -> Result<TokenStream> {
     } else {
         quote! { 0i32 }
     };
+    let (globals_impl, globals) = if let Some(globals) = attr.globals {
+        (
+            quote! { impl ::ext_php_rs::zend::ModuleGlobals for #globals {} },
+            quote! { .globals::<#globals>() },
+        )
+    } else {
+        (TokenStream::new(), TokenStream::new())
+    };
+    let requires = &attr.requires;
+    let conflicts = &attr.conflicts;
+    let optional = &attr.optional;
+    let auto_register = if cfg!(feature = "inventory") {
+        quote! { .auto_register() }
+    } else {
+        TokenStream::new()
+    };
 
     Ok(quote! {
+        #globals_impl
+
         #[doc(hidden)]
         #[no_mangle]
         extern "C" fn get_module() -> *mut ::ext_php_rs::zend::ModuleEntry {
@@ -52,6 +74,11 @@ pub fn parser(input: ItemFn) -> Result<TokenStream> {
                 env!("CARGO_PKG_NAME"),
                 env!("CARGO_PKG_VERSION")
             ))
+            #globals
+            #(.requires(#requires))*
+            #(.conflicts(#conflicts))*
+            #(.optional(#optional))*
+            #auto_register
             .startup_function(ext_php_rs_startup);
 
             match builder.try_into() {
@@ -76,7 +103,8 @@ pub fn parser(input: ItemFn) -> Result<TokenStream> {
             let builder = internal(::ext_php_rs::builders::ModuleBuilder::new(
                 env!("CARGO_PKG_NAME"),
                 env!("CARGO_PKG_VERSION")
-            ));
+            ))
+            #auto_register;
 
             Description::new(builder.into())
         }
@@ -1,5 +1,10 @@
 use convert_case::{Case, Casing};
 use darling::FromMeta;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::LitStr;
+
+use crate::prelude::*;
 
 const MAGIC_METHOD: [&str; 17] = [
     "__construct",
@@ -65,6 +70,105 @@ impl PhpRename {
     }
 }
 
+/// A PHP type declaration, parsed from a string such as `?int` or
+/// `Countable&Traversable`.
+#[derive(Debug)]
+pub struct PhpType {
+    /// The `DataType` of the declaration.
+    pub ty: TokenStream,
+    /// Whether the type was prefixed with `?`.
+    pub nullable: bool,
+}
+
+impl PhpType {
+    /// Parses a PHP type declaration.
+    pub fn parse(lit: &LitStr) -> Result<Self> {
+        let value = lit.value();
+        let value = value.trim();
+        if let Some(ty) = value.strip_prefix('?') {
+            if ty.contains(['|', '&']) {
+                bail!(lit => "Union and intersection types cannot be prefixed with `?`.");
+            }
+            return Ok(Self {
+                ty: Self::parse_single(ty, lit)?,
+                nullable: true,
+            });
+        }
+
+        let mut types = value
+            .split('|')
+            .map(|ty| {
+                let ty = ty.trim();
+                if ty.contains('&') {
+                    let ty = ty
+                        .strip_prefix('(')
+                        .and_then(|ty| ty.strip_suffix(')'))
+                        .unwrap_or(ty);
+                    let classes = ty
+                        .split('&')
+                        .map(|class| Self::parse_class(class, lit))
+                        .collect::<Result<Vec<_>>>()?;
+                    Ok(quote! { ::ext_php_rs::flags::DataType::Intersection(&[#(#classes),*]) })
+                } else {
+                    Self::parse_single(ty, lit)
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let ty = if types.len() == 1 {
+            types.remove(0)
+        } else {
+            quote! { ::ext_php_rs::flags::DataType::Union(&[#(#types),*]) }
+        };
+        Ok(Self {
+            ty,
+            nullable: false,
+        })
+    }
+
+    /// Parses a type which is not a union or intersection.
+    fn parse_single(ty: &str, lit: &LitStr) -> Result<TokenStream> {
+        let variant = match ty.trim().to_ascii_lowercase().as_str() {
+            "int" => quote! { Long },
+            "float" => quote! { Double },
+            "string" => quote! { String },
+            "bool" => quote! { Bool },
+            "true" => quote! { True },
+            "false" => quote! { False },
+            "array" => quote! { Array },
+            "object" => quote! { Object(::std::option::Option::None) },
+            "iterable" => quote! { Iterable },
+            "callable" => quote! { Callable },
+            "mixed" => quote! { Mixed },
+            "null" => quote! { Null },
+            "void" => quote! { Void },
+            "never" => quote! { Never },
+            "static" => quote! { Static },
+            "self" => quote! { Self_ },
+            _ => {
+                let class = Self::parse_class(ty, lit)?;
+                quote! { Object(::std::option::Option::Some(#class)) }
+            }
+        };
+        Ok(quote! { ::ext_php_rs::flags::DataType::#variant })
+    }
+
+    /// Parses the name of a class or interface, removing the leading `\`.
+    fn parse_class(class: &str, lit: &LitStr) -> Result<String> {
+        let class = class.trim();
+        let class = class.strip_prefix('\\').unwrap_or(class);
+        let valid = class.split('\\').all(|part| {
+            part.chars()
+                .next()
+                .is_some_and(|c| c.is_alphabetic() || c == '_')
+                && part.chars().all(|c| c.is_alphanumeric() || c == '_')
+        });
+        if !valid {
+            bail!(lit => "Invalid PHP type `{}`.", class);
+        }
+        Ok(class.to_string())
+    }
+}
+
 #[derive(Debug, Copy, Clone, FromMeta, Default)]
 pub enum RenameRule {
     /// Methods won't be renamed.
@@ -134,7 +238,7 @@ where
 mod tests {
     use crate::parsing::{MethodRename, Rename};
 
-    use super::{PhpRename, RenameRule};
+    use super::{PhpRename, PhpType, RenameRule};
 
     #[test]
     fn php_rename() {
@@ -321,4 +425,45 @@ mod tests {
         assert_eq!(snake, original.rename(RenameRule::Snake));
         assert_eq!(screaming_snake, original.rename(RenameRule::ScreamingSnake));
     }
+
+    #[test]
+    fn php_type() {
+        let parse = |ty: &str| {
+            let lit = syn::LitStr::new(ty, proc_macro2::Span::call_site());
+            PhpType::parse(&lit).map(|ty| (ty.ty.to_string().replace(' ', ""), ty.nullable))
+        };
+        let data_type = |ty: &str| format!("::ext_php_rs::flags::DataType::{ty}");
+
+        assert_eq!(parse("int").unwrap(), (data_type("Long"), false));
+        assert_eq!(parse("?string").unwrap(), (data_type("String"), true));
+        assert_eq!(parse("static").unwrap(), (data_type("Static"), false));
+        assert_eq!(
+            parse("\\Foo\\Bar").unwrap(),
+            (
+                data_type("Object(::std::option::Option::Some(\"Foo\\\\Bar\"))"),
+                false
+            )
+        );
+        assert_eq!(
+            parse("int|string|null").unwrap(),
+            (
+                data_type(&format!(
+                    "Union(&[{},{},{}])",
+                    data_type("Long"),
+                    data_type("String"),
+                    data_type("Null")
+                )),
+                false
+            )
+        );
+        assert_eq!(
+            parse("Countable&Traversable").unwrap(),
+            (
+                data_type("Intersection(&[\"Countable\",\"Traversable\"])"),
+                false
+            )
+        );
+        assert!(parse("?int|string").is_err());
+        assert!(parse("int|").is_err());
+    }
 }
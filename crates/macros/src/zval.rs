@@ -1,16 +1,21 @@
-use darling::ToTokens;
+use darling::{util::Flag, FromAttributes, FromMeta, ToTokens};
 use proc_macro2::{Span, TokenStream};
 use quote::quote;
 use syn::{
-    punctuated::Punctuated, token::Where, DataEnum, DataStruct, DeriveInput, GenericParam,
-    Generics, Ident, ImplGenerics, Lifetime, LifetimeParam, TypeGenerics, Variant, WhereClause,
+    punctuated::Punctuated, token::Where, Attribute, DataEnum, DataStruct, DeriveInput, Expr,
+    GenericParam, Generics, Ident, ImplGenerics, Lifetime, LifetimeParam, TypeGenerics, Variant,
+    WhereClause,
 };
 
+use crate::parsing::{PhpRename, RenameRule};
 use crate::prelude::*;
 
 pub fn parser(input: DeriveInput) -> Result<TokenStream> {
     let DeriveInput {
-        generics, ident, ..
+        attrs,
+        generics,
+        ident,
+        ..
     } = input;
 
     let (into_impl_generics, ty_generics, where_clause) = generics.split_for_impl();
@@ -68,6 +73,7 @@ pub fn parser(input: DeriveInput) -> Result<TokenStream> {
     match input.data {
         syn::Data::Struct(data) => parse_struct(
             &data,
+            &attrs,
             &ident,
             &into_impl_generics,
             &from_impl_generics,
@@ -90,8 +96,64 @@ pub fn parser(input: DeriveInput) -> Result<TokenStream> {
     }
 }
 
+#[derive(FromAttributes, Debug, Default)]
+#[darling(attributes(php), default)]
+struct StructAttributes {
+    /// Converts the struct to and from an associative array instead of an
+    /// object.
+    array: Flag,
+    /// Name of the class the struct is converted into, instead of `stdClass`.
+    class: Option<String>,
+    /// Case of the keys or property names of all fields.
+    change_case: Option<RenameRule>,
+}
+
+#[derive(FromAttributes, Debug, Default)]
+#[darling(attributes(php), default)]
+struct FieldAttributes {
+    #[darling(flatten)]
+    rename: PhpRename,
+    /// Value of the field when the key or property is missing.
+    default: Option<FieldDefault>,
+    /// Excludes the field from the conversion.
+    skip: Flag,
+    /// Reads and writes the fields of the field's struct from the same array
+    /// or object.
+    flatten: Flag,
+}
+
+/// Default value of a field, either `Default::default()` when given as
+/// `#[php(default)]` or an expression when given as `#[php(default = ...)]`.
+#[derive(Debug)]
+enum FieldDefault {
+    Trait,
+    Expr(Expr),
+}
+
+impl FromMeta for FieldDefault {
+    fn from_word() -> darling::Result<Self> {
+        Ok(Self::Trait)
+    }
+
+    fn from_expr(expr: &Expr) -> darling::Result<Self> {
+        Ok(Self::Expr(expr.clone()))
+    }
+}
+
+impl ToTokens for FieldDefault {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            Self::Trait => quote! { ::std::default::Default::default() },
+            Self::Expr(expr) => expr.to_token_stream(),
+        }
+        .to_tokens(tokens);
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
 fn parse_struct(
     data: &DataStruct,
+    attrs: &[Attribute],
     ident: &Ident,
     into_impl_generics: &ImplGenerics,
     from_impl_generics: &Generics,
@@ -99,53 +161,141 @@ fn parse_struct(
     from_where_clause: &WhereClause,
     ty_generics: &TypeGenerics,
 ) -> Result<TokenStream> {
-    let into_fields = data
-        .fields
-        .iter()
-        .map(|field| {
-            let ident = field.ident.as_ref().ok_or_else(|| {
-                err!(field => "Fields require names when using the `#[derive(ZvalConvert)]` macro on a struct.")
-            })?;
-            let field_name = ident.to_string();
-
-            Ok(quote! {
-                obj.set_property(#field_name, self.#ident)?;
-            })
-        })
-        .collect::<Result<Vec<_>>>()?;
+    let struct_attr = StructAttributes::from_attributes(attrs)?;
+    if struct_attr.array.is_present() && struct_attr.class.is_some() {
+        bail!(ident => "`array` and `class` cannot be combined when using `#[derive(ZvalConvert)]`.");
+    }
 
-    let from_fields = data
-        .fields
-        .iter()
-        .map(|field| {
-            let ident = field.ident.as_ref().ok_or_else(|| {
-                err!(field => "Fields require names when using the `#[derive(ZvalConvert)]` macro on a struct.")
-            })?;
-            let field_name = ident.to_string();
-
-            Ok(quote! {
-                #ident: obj.get_property(#field_name)?,
-            })
-        })
-        .collect::<Result<Vec<_>>>()?;
+    let mut into_fields = vec![];
+    let mut from_fields = vec![];
+    for field in &data.fields {
+        let ident = field.ident.as_ref().ok_or_else(|| {
+            err!(field => "Fields require names when using the `#[derive(ZvalConvert)]` macro on a struct.")
+        })?;
+        let attr = FieldAttributes::from_attributes(&field.attrs)?;
+
+        if attr.skip.is_present() {
+            if attr.flatten.is_present() {
+                bail!(field => "`skip` and `flatten` cannot be combined.");
+            }
+            let default = attr.default.unwrap_or(FieldDefault::Trait);
+            from_fields.push(quote! { #ident: #default, });
+        } else if attr.flatten.is_present() {
+            if attr.default.is_some() {
+                bail!(field => "`default` cannot be used on flattened fields.");
+            }
+            into_fields.push(quote! {
+                ::ext_php_rs::internal::zval_convert::IntoZvalFields::into_fields(self.#ident, fields)?;
+            });
+            from_fields.push(quote! {
+                #ident: ::ext_php_rs::internal::zval_convert::FromZvalFields::from_fields(fields)?,
+            });
+        } else {
+            let name = attr.rename.rename(
+                ident.to_string(),
+                struct_attr.change_case.unwrap_or(RenameRule::None),
+            );
+            into_fields.push(quote! {
+                fields.set(#name, self.#ident)?;
+            });
+            let value = match &attr.default {
+                Some(default) => quote! { .unwrap_or_else(|| #default) },
+                None => quote! { .ok_or(::ext_php_rs::error::Error::InvalidProperty)? },
+            };
+            from_fields.push(quote! {
+                #ident: fields.get(#name)?#value,
+            });
+        }
+    }
+
+    let fields_impl = quote! {
+        impl #into_impl_generics ::ext_php_rs::internal::zval_convert::IntoZvalFields for #ident #ty_generics #into_where_clause {
+            fn into_fields(
+                self,
+                fields: &mut ::ext_php_rs::internal::zval_convert::FieldsMut<'_>,
+            ) -> ::ext_php_rs::error::Result<()> {
+                #(#into_fields)*
+                ::ext_php_rs::error::Result::Ok(())
+            }
+        }
+
+        impl #from_impl_generics ::ext_php_rs::internal::zval_convert::FromZvalFields<'_zval> for #ident #ty_generics #from_where_clause {
+            fn from_fields(
+                fields: &::ext_php_rs::internal::zval_convert::Fields<'_zval>,
+            ) -> ::ext_php_rs::error::Result<Self> {
+                ::ext_php_rs::error::Result::Ok(Self {
+                    #(#from_fields)*
+                })
+            }
+        }
+    };
+
+    if struct_attr.array.is_present() {
+        return Ok(quote! {
+            #fields_impl
+
+            impl #into_impl_generics ::ext_php_rs::convert::IntoZval for #ident #ty_generics #into_where_clause {
+                const TYPE: ::ext_php_rs::flags::DataType = ::ext_php_rs::flags::DataType::Array;
+                const NULLABLE: bool = false;
+
+                fn set_zval(self, zv: &mut ::ext_php_rs::types::Zval, persistent: bool) -> ::ext_php_rs::error::Result<()> {
+                    use ::ext_php_rs::convert::IntoZval;
+                    use ::ext_php_rs::internal::zval_convert::{FieldsMut, IntoZvalFields};
+
+                    let mut array = ::ext_php_rs::types::ZendHashTable::new();
+                    self.into_fields(&mut FieldsMut::Array(&mut array))?;
+                    array.set_zval(zv, persistent)
+                }
+            }
+
+            impl #from_impl_generics ::ext_php_rs::convert::FromZval<'_zval> for #ident #ty_generics #from_where_clause {
+                const TYPE: ::ext_php_rs::flags::DataType = ::ext_php_rs::flags::DataType::Array;
+
+                fn from_zval(zv: &'_zval ::ext_php_rs::types::Zval) -> ::std::option::Option<Self> {
+                    use ::ext_php_rs::internal::zval_convert::{Fields, FromZvalFields};
+
+                    Self::from_fields(&Fields::Array(zv.array()?)).ok()
+                }
+            }
+        });
+    }
+
+    let (new_object, class_check, ty) = match &struct_attr.class {
+        Some(class) => (
+            quote! { ::ext_php_rs::internal::zval_convert::new_object(#class)? },
+            quote! {
+                if !::ext_php_rs::internal::zval_convert::instance_of(obj, #class) {
+                    return ::std::option::Option::None;
+                }
+            },
+            quote! { ::ext_php_rs::flags::DataType::Object(Some(#class)) },
+        ),
+        None => (
+            quote! { ::ext_php_rs::types::ZendObject::new_stdclass() },
+            quote! {},
+            quote! { ::ext_php_rs::flags::DataType::Object(None) },
+        ),
+    };
 
     Ok(quote! {
+        #fields_impl
+
         impl #into_impl_generics ::ext_php_rs::convert::IntoZendObject for #ident #ty_generics #into_where_clause {
             fn into_zend_object(self) -> ::ext_php_rs::error::Result<
                 ::ext_php_rs::boxed::ZBox<
                     ::ext_php_rs::types::ZendObject
                 >
             > {
-                use ::ext_php_rs::convert::IntoZval;
+                use ::ext_php_rs::internal::zval_convert::{FieldsMut, IntoZvalFields};
 
-                let mut obj = ::ext_php_rs::types::ZendObject::new_stdclass();
-                #(#into_fields)*
+                let mut obj = #new_object;
+                self.into_fields(&mut FieldsMut::Object(&mut obj))?;
                 ::ext_php_rs::error::Result::Ok(obj)
             }
         }
 
         impl #into_impl_generics ::ext_php_rs::convert::IntoZval for #ident #ty_generics #into_where_clause {
-            const TYPE: ::ext_php_rs::flags::DataType = ::ext_php_rs::flags::DataType::Object(None);
+            const TYPE: ::ext_php_rs::flags::DataType = #ty;
             const NULLABLE: bool = false;
 
             fn set_zval(self, zv: &mut ::ext_php_rs::types::Zval, persistent: bool) -> ::ext_php_rs::error::Result<()> {
@@ -157,19 +307,21 @@ fn parse_struct(
 
         impl #from_impl_generics ::ext_php_rs::convert::FromZendObject<'_zval> for #ident #ty_generics #from_where_clause {
             fn from_zend_object(obj: &'_zval ::ext_php_rs::types::ZendObject) -> ::ext_php_rs::error::Result<Self> {
-                ::ext_php_rs::error::Result::Ok(Self {
-                    #(#from_fields)*
-                })
+                use ::ext_php_rs::internal::zval_convert::{Fields, FromZvalFields};
+
+                Self::from_fields(&Fields::Object(obj))
             }
         }
 
         impl #from_impl_generics ::ext_php_rs::convert::FromZval<'_zval> for #ident #ty_generics #from_where_clause {
-            const TYPE: ::ext_php_rs::flags::DataType = ::ext_php_rs::flags::DataType::Object(None);
+            const TYPE: ::ext_php_rs::flags::DataType = #ty;
 
             fn from_zval(zv: &'_zval ::ext_php_rs::types::Zval) -> ::std::option::Option<Self> {
                 use ::ext_php_rs::convert::FromZendObject;
 
-                Self::from_zend_object(zv.object()?).ok()
+                let obj = zv.object()?;
+                #class_check
+                Self::from_zend_object(obj).ok()
             }
         }
     })
@@ -196,7 +348,28 @@ fn parse_enum(
         })
     });
 
+    // Variants without a field are converted into `null`.
+    let into_null = data
+        .variants
+        .iter()
+        .any(|variant| variant.fields.is_empty())
+        .then(|| quote! { ::ext_php_rs::flags::DataType::Null });
+    let into_types = data
+        .variants
+        .iter()
+        .filter_map(|variant| match &variant.fields {
+            syn::Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                let ty = &fields.unnamed.first()?.ty;
+                Some(quote! { <#ty as ::ext_php_rs::convert::IntoZval>::TYPE })
+            }
+            _ => None,
+        });
+    let into_type = quote! {
+        ::ext_php_rs::flags::DataType::union(&[#(#into_types,)* #into_null])
+    };
+
     let mut default = None;
+    let mut from_types = vec![];
     let from_variants = data.variants.iter().map(|variant| {
         let Variant {
             ident,
@@ -211,6 +384,7 @@ fn parse_enum(
                 }
 
                 let ty = &fields.unnamed.first().unwrap().ty;
+                from_types.push(quote! { <#ty as ::ext_php_rs::convert::FromZval<'_zval>>::TYPE });
 
                 Ok(Some(quote! {
                     if let Some(value) = <#ty>::from_zval(zval) {
@@ -231,11 +405,17 @@ fn parse_enum(
             syn::Fields::Named(_) => bail!(fields => "Enum variants must be unnamed and have only one field inside the variant when using `#[derive(ZvalConvert)]`.")
         }
     }).collect::<Result<Vec<_>>>()?;
+    // The default variant accepts any value.
+    let from_type = if default.is_some() {
+        quote! { ::ext_php_rs::flags::DataType::Mixed }
+    } else {
+        quote! { ::ext_php_rs::flags::DataType::union(&[#(#from_types),*]) }
+    };
     let default = default.unwrap_or_else(|| quote! { None });
 
     Ok(quote! {
         impl #into_impl_generics ::ext_php_rs::convert::IntoZval for #ident #ty_generics #into_where_clause {
-            const TYPE: ::ext_php_rs::flags::DataType = ::ext_php_rs::flags::DataType::Mixed;
+            const TYPE: ::ext_php_rs::flags::DataType = #into_type;
             const NULLABLE: bool = false;
 
             fn set_zval(
@@ -256,7 +436,7 @@ fn parse_enum(
         }
 
         impl #from_impl_generics ::ext_php_rs::convert::FromZval<'_zval> for #ident #ty_generics #from_where_clause {
-            const TYPE: ::ext_php_rs::flags::DataType = ::ext_php_rs::flags::DataType::Mixed;
+            const TYPE: ::ext_php_rs::flags::DataType = #from_type;
 
             fn from_zval(zval: &'_zval ::ext_php_rs::types::Zval) -> ::std::option::Option<Self> {
                 #(#from_variants)*
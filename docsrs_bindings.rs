@@ -83,6 +83,8 @@ where
 pub const ZEND_DEBUG: u32 = 1;
 pub const _ZEND_TYPE_NAME_BIT: u32 = 16777216;
 pub const _ZEND_TYPE_LITERAL_NAME_BIT: u32 = 8388608;
+pub const _ZEND_TYPE_LIST_BIT: u32 = 4194304;
+pub const _ZEND_TYPE_INTERSECTION_BIT: u32 = 524288;
 pub const _ZEND_TYPE_NULLABLE_BIT: u32 = 2;
 pub const HT_MIN_SIZE: u32 = 8;
 pub const IS_UNDEF: u32 = 0;
@@ -100,10 +102,14 @@ pub const IS_CONSTANT_AST: u32 = 11;
 pub const IS_CALLABLE: u32 = 12;
 pub const IS_ITERABLE: u32 = 13;
 pub const IS_VOID: u32 = 14;
+pub const IS_STATIC: u32 = 15;
 pub const IS_MIXED: u32 = 16;
+pub const IS_NEVER: u32 = 17;
 pub const IS_INDIRECT: u32 = 12;
 pub const IS_PTR: u32 = 13;
 pub const _IS_BOOL: u32 = 18;
+pub const GC_NOT_COLLECTABLE: u32 = 16;
+pub const GC_FLAGS_SHIFT: u32 = 0;
 pub const Z_TYPE_FLAGS_SHIFT: u32 = 8;
 pub const IS_TYPE_REFCOUNTED: u32 = 1;
 pub const IS_TYPE_COLLECTABLE: u32 = 2;
@@ -143,6 +149,7 @@ pub const ZEND_ACC_HAS_TYPE_HINTS: u32 = 256;
 pub const ZEND_ACC_TOP_LEVEL: u32 = 512;
 pub const ZEND_ACC_PRELOADED: u32 = 1024;
 pub const ZEND_ACC_PROMOTED: u32 = 256;
+pub const ZEND_ACC_READONLY: u32 = 128;
 pub const ZEND_ACC_INTERFACE: u32 = 1;
 pub const ZEND_ACC_TRAIT: u32 = 2;
 pub const ZEND_ACC_ANON_CLASS: u32 = 4;
@@ -178,10 +185,31 @@ pub const ZEND_ACC_STRICT_TYPES: u32 = 2147483648;
 pub const ZEND_INTERNAL_FUNCTION: u32 = 1;
 pub const ZEND_USER_FUNCTION: u32 = 2;
 pub const ZEND_EVAL_CODE: u32 = 4;
+pub const ZEND_CALL_HAS_EXTRA_NAMED_PARAMS: u32 = 134217728;
+pub const ZEND_ADD: u32 = 1;
+pub const ZEND_SUB: u32 = 2;
+pub const ZEND_MUL: u32 = 3;
+pub const ZEND_DIV: u32 = 4;
+pub const ZEND_MOD: u32 = 5;
+pub const ZEND_SL: u32 = 6;
+pub const ZEND_SR: u32 = 7;
+pub const ZEND_CONCAT: u32 = 8;
+pub const ZEND_BW_OR: u32 = 9;
+pub const ZEND_BW_AND: u32 = 10;
+pub const ZEND_BW_XOR: u32 = 11;
+pub const ZEND_POW: u32 = 12;
+pub const ZEND_BW_NOT: u32 = 13;
+pub const BP_VAR_W: u32 = 1;
+pub const BP_VAR_RW: u32 = 2;
+pub const BP_VAR_IS: u32 = 3;
+pub const BP_VAR_UNSET: u32 = 5;
 pub const ZEND_ISEMPTY: u32 = 1;
 pub const _ZEND_SEND_MODE_SHIFT: u32 = 25;
 pub const _ZEND_IS_VARIADIC_BIT: u32 = 134217728;
 pub const ZEND_MODULE_API_NO: u32 = 20240924;
+pub const MODULE_DEP_REQUIRED: u32 = 1;
+pub const MODULE_DEP_CONFLICTS: u32 = 2;
+pub const MODULE_DEP_OPTIONAL: u32 = 3;
 pub const USING_ZTS: u32 = 0;
 pub const MAY_BE_BOOL: u32 = 12;
 pub const MAY_BE_ANY: u32 = 1022;
@@ -303,6 +331,12 @@ pub struct zend_type {
     pub type_mask: u32,
 }
 #[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct zend_type_list {
+    pub num_types: u32,
+    pub types: [zend_type; 1usize],
+}
+#[repr(C)]
 #[derive(Copy, Clone)]
 pub union _zend_value {
     pub lval: zend_long,
@@ -556,6 +590,36 @@ pub struct zend_get_gc_buffer {
     pub end: *mut zval,
     pub start: *mut zval,
 }
+extern "C" {
+    pub fn zend_get_gc_buffer_create() -> *mut zend_get_gc_buffer;
+}
+extern "C" {
+    pub fn zend_get_gc_buffer_grow(gc_buffer: *mut zend_get_gc_buffer);
+}
+pub type rsrc_dtor_func_t = ::std::option::Option<unsafe extern "C" fn(res: *mut zend_resource)>;
+extern "C" {
+    pub fn zend_list_delete(res: *mut zend_resource);
+}
+extern "C" {
+    pub fn zend_list_close(res: *mut zend_resource);
+}
+extern "C" {
+    pub fn zend_register_resource(
+        rsrc_pointer: *mut ::std::os::raw::c_void,
+        rsrc_type: ::std::os::raw::c_int,
+    ) -> *mut zend_resource;
+}
+extern "C" {
+    pub fn zend_rsrc_list_get_rsrc_type(res: *mut zend_resource) -> *const ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn zend_register_list_destructors_ex(
+        ld: rsrc_dtor_func_t,
+        pld: rsrc_dtor_func_t,
+        type_name: *const ::std::os::raw::c_char,
+        module_number: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
 pub type zend_string_init_interned_func_t = ::std::option::Option<
     unsafe extern "C" fn(
         str_: *const ::std::os::raw::c_char,
@@ -744,6 +808,12 @@ extern "C" {
     pub fn zval_ptr_dtor(zval_ptr: *mut zval);
 }
 pub type zend_object_iterator = _zend_object_iterator;
+extern "C" {
+    pub fn zend_iterator_init(iter: *mut zend_object_iterator);
+}
+extern "C" {
+    pub fn zend_create_internal_iterator_zval(return_value: *mut zval, obj: *mut zval) -> zend_result;
+}
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct _zend_object_iterator_funcs {
@@ -1278,6 +1348,9 @@ extern "C" {
         cache_slot: *mut *mut ::std::os::raw::c_void,
     ) -> *mut zval;
 }
+extern "C" {
+    pub fn zend_std_compare_objects(o1: *mut zval, o2: *mut zval) -> ::std::os::raw::c_int;
+}
 extern "C" {
     pub fn zend_std_has_property(
         object: *mut zend_object,
@@ -1286,6 +1359,27 @@ extern "C" {
         cache_slot: *mut *mut ::std::os::raw::c_void,
     ) -> ::std::os::raw::c_int;
 }
+extern "C" {
+    pub fn zend_std_get_property_ptr_ptr(
+        object: *mut zend_object,
+        member: *mut zend_string,
+        type_: ::std::os::raw::c_int,
+        cache_slot: *mut *mut ::std::os::raw::c_void,
+    ) -> *mut zval;
+}
+extern "C" {
+    pub fn zend_std_unset_property(
+        object: *mut zend_object,
+        member: *mut zend_string,
+        cache_slot: *mut *mut ::std::os::raw::c_void,
+    );
+}
+extern "C" {
+    pub fn zend_ref_del_type_source(
+        source_list: *mut zend_property_info_source_list,
+        prop: *const zend_property_info,
+    );
+}
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct _zend_strtod_bigint {
@@ -1878,6 +1972,17 @@ pub struct _zend_function_entry {
 }
 pub type zend_function_entry = _zend_function_entry;
 #[repr(C)]
+pub struct _zend_fcall_info {
+    pub size: usize,
+    pub function_name: zval,
+    pub retval: *mut zval,
+    pub params: *mut zval,
+    pub object: *mut zend_object,
+    pub param_count: u32,
+    pub named_params: *mut HashTable,
+}
+pub type zend_fcall_info = _zend_fcall_info;
+#[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct _zend_fcall_info_cache {
     pub function_handler: *mut zend_function,
@@ -1898,6 +2003,11 @@ extern "C" {
         parent_ce: *mut zend_class_entry,
     ) -> *mut zend_class_entry;
 }
+extern "C" {
+    pub fn zend_register_internal_interface(
+        orig_class_entry: *mut zend_class_entry,
+    ) -> *mut zend_class_entry;
+}
 extern "C" {
     pub fn zend_is_callable(
         callable: *mut zval,
@@ -1905,6 +2015,26 @@ extern "C" {
         callable_name: *mut *mut zend_string,
     ) -> bool;
 }
+extern "C" {
+    pub fn zend_is_callable_ex(
+        callable: *mut zval,
+        object: *mut zend_object,
+        check_flags: u32,
+        callable_name: *mut *mut zend_string,
+        fcc: *mut zend_fcall_info_cache,
+        error: *mut *mut ::std::os::raw::c_char,
+    ) -> bool;
+}
+extern "C" {
+    pub fn zend_declare_typed_property(
+        ce: *mut zend_class_entry,
+        name: *mut zend_string,
+        property: *mut zval,
+        access_type: ::std::os::raw::c_int,
+        doc_comment: *mut zend_string,
+        type_: zend_type,
+    ) -> *mut zend_property_info;
+}
 extern "C" {
     pub fn zend_declare_property(
         ce: *mut zend_class_entry,
@@ -1914,6 +2044,20 @@ extern "C" {
         access_type: ::std::os::raw::c_int,
     );
 }
+extern "C" {
+    pub fn zend_update_static_property_ex(
+        scope: *mut zend_class_entry,
+        name: *mut zend_string,
+        value: *mut zval,
+    ) -> zend_result;
+}
+extern "C" {
+    pub fn zend_read_static_property_ex(
+        scope: *mut zend_class_entry,
+        name: *mut zend_string,
+        silent: bool,
+    ) -> *mut zval;
+}
 extern "C" {
     pub fn zend_declare_class_constant(
         ce: *mut zend_class_entry,
@@ -1935,6 +2079,15 @@ extern "C" {
         named_params: *mut HashTable,
     ) -> zend_result;
 }
+extern "C" {
+    pub fn zend_call_function(
+        fci: *mut zend_fcall_info,
+        fci_cache: *mut zend_fcall_info_cache,
+    ) -> zend_result;
+}
+extern "C" {
+    pub fn zend_release_fcall_info_cache(fcc: *mut zend_fcall_info_cache);
+}
 extern "C" {
     pub fn zend_call_known_function(
         fn_: *mut zend_function,
@@ -2607,6 +2760,9 @@ extern "C" {
         module_number: ::std::os::raw::c_int,
     ) -> zend_result;
 }
+extern "C" {
+    pub fn zend_unregister_ini_entries(module_number: ::std::os::raw::c_int);
+}
 pub type zend_ini_parser_cb_t = ::std::option::Option<
     unsafe extern "C" fn(
         arg1: *mut zval,
@@ -2664,6 +2820,15 @@ extern "C" {
 extern "C" {
     pub fn php_info_print_table_row(num_cols: ::std::os::raw::c_int, ...);
 }
+extern "C" {
+    pub fn php_info_print_table_colspan_header(
+        num_cols: ::std::os::raw::c_int,
+        header: *const ::std::os::raw::c_char,
+    );
+}
+extern "C" {
+    pub fn display_ini_entries(module: *mut zend_module_entry);
+}
 extern "C" {
     pub fn php_info_print_table_start();
 }
@@ -2738,6 +2903,9 @@ extern "C" {
 extern "C" {
     pub static mut zend_ce_error_exception: *mut zend_class_entry;
 }
+extern "C" {
+    pub static mut zend_ce_error: *mut zend_class_entry;
+}
 extern "C" {
     pub static mut zend_ce_compile_error: *mut zend_class_entry;
 }
@@ -1,11 +1,12 @@
 //! Builder and objects relating to function and method arguments.
 
-use std::{ffi::CString, ptr};
+use std::{collections::HashMap, ffi::CString, ptr};
 
 use crate::{
-    convert::{FromZvalMut, IntoZvalDyn},
+    convert::{FromZval, FromZvalMut, IntoZvalDyn},
     describe::{abi, Parameter},
     error::{Error, Result},
+    exception::throw,
     ffi::{
         _zend_expected_type, _zend_expected_type_Z_EXPECTED_ARRAY,
         _zend_expected_type_Z_EXPECTED_BOOL, _zend_expected_type_Z_EXPECTED_DOUBLE,
@@ -14,22 +15,25 @@ use crate::{
         zend_internal_arg_info, zend_wrong_parameters_count_error,
     },
     flags::DataType,
-    types::Zval,
-    zend::ZendType,
+    types::{ZendHashTable, Zval},
+    zend::{ce, ZendType},
 };
 
 /// Represents an argument to a function.
 #[must_use]
 #[derive(Debug)]
+#[allow(clippy::struct_excessive_bools)]
 pub struct Arg<'a> {
     name: String,
     r#type: DataType,
     as_ref: bool,
     allow_null: bool,
     pub(crate) variadic: bool,
+    named_rest: bool,
     default_value: Option<String>,
     zval: Option<&'a mut Zval>,
     variadic_zvals: Vec<Option<&'a mut Zval>>,
+    named_params: Option<&'a ZendHashTable>,
 }
 
 impl<'a> Arg<'a> {
@@ -46,9 +50,11 @@ impl<'a> Arg<'a> {
             as_ref: false,
             allow_null: false,
             variadic: false,
+            named_rest: false,
             default_value: None,
             zval: None,
             variadic_zvals: vec![],
+            named_params: None,
         }
     }
 
@@ -65,6 +71,18 @@ impl<'a> Arg<'a> {
         self
     }
 
+    /// Sets the argument as receiving the named arguments which do not match
+    /// another parameter, like `...$options` in PHP. The argument must be the
+    /// last argument of the function.
+    ///
+    /// Passing more positional arguments than the function has parameters is
+    /// an error.
+    pub fn is_named_rest(mut self) -> Self {
+        self.variadic = true;
+        self.named_rest = true;
+        self
+    }
+
     /// Sets the argument as nullable.
     pub fn allow_null(mut self) -> Self {
         self.allow_null = true;
@@ -121,6 +139,30 @@ impl<'a> Arg<'a> {
             .collect()
     }
 
+    /// Retrieves the named arguments received by an argument set with
+    /// [`Arg::is_named_rest`], keyed by their name.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidNamedArgument`] with the name of the first
+    /// argument whose value cannot be converted into `T`.
+    pub fn named_rest_vals<T>(&self) -> Result<HashMap<String, T>>
+    where
+        T: FromZval<'a>,
+    {
+        self.named_params
+            .into_iter()
+            .flatten()
+            .map(|(key, zv)| {
+                let key = key.to_string();
+                match T::from_zval(zv.dereference()) {
+                    Some(val) => Ok((key, val)),
+                    None => Err(Error::InvalidNamedArgument(key)),
+                }
+            })
+            .collect()
+    }
+
     /// Attempts to return a reference to the arguments internal Zval.
     ///
     /// # Returns
@@ -174,6 +216,18 @@ impl<'a> Arg<'a> {
             },
         })
     }
+
+    /// Returns the internal PHP argument info of an optional argument.
+    ///
+    /// Nullable arguments without a default value default to `null`, which
+    /// allows PHP to skip them when named arguments are passed.
+    pub(crate) fn as_optional_arg_info(&self) -> Result<ArgInfo> {
+        let mut arg_info = self.as_arg_info()?;
+        if arg_info.default_value.is_null() && self.allow_null && !self.variadic {
+            arg_info.default_value = CString::new("null")?.into_raw();
+        }
+        Ok(arg_info)
+    }
 }
 
 impl From<Arg<'_>> for _zend_expected_type {
@@ -202,7 +256,7 @@ impl From<Arg<'_>> for Parameter {
         Parameter {
             name: val.name.into(),
             ty: Some(val.r#type).into(),
-            nullable: val.allow_null,
+            nullable: val.allow_null && !val.r#type.accepts_null(),
             variadic: val.variadic,
             default: val.default_value.map(abi::RString::from).into(),
         }
@@ -218,6 +272,7 @@ pub struct ArgParser<'a, 'b> {
     args: Vec<&'b mut Arg<'a>>,
     min_num_args: Option<usize>,
     arg_zvals: Vec<Option<&'a mut Zval>>,
+    named_params: Option<&'a ZendHashTable>,
 }
 
 impl<'a, 'b> ArgParser<'a, 'b> {
@@ -227,9 +282,23 @@ impl<'a, 'b> ArgParser<'a, 'b> {
             args: vec![],
             min_num_args: None,
             arg_zvals,
+            named_params: None,
         }
     }
 
+    /// Sets the named arguments which do not match a parameter of the
+    /// function, see [`ExecuteData::extra_named_params`].
+    ///
+    /// # Parameters
+    ///
+    /// * `named_params` - The extra named arguments.
+    ///
+    /// [`ExecuteData::extra_named_params`]: crate::zend::ExecuteData::extra_named_params
+    pub fn named_params(mut self, named_params: Option<&'a ZendHashTable>) -> Self {
+        self.named_params = named_params;
+        self
+    }
+
     /// Adds a new argument to the parser.
     ///
     /// # Parameters
@@ -260,8 +329,9 @@ impl<'a, 'b> ArgParser<'a, 'b> {
     /// # Errors
     ///
     /// Returns an [`Error`] type if there were too many or too little arguments
-    /// passed to the function. The user has already been notified so you
-    /// should break execution after seeing an error type.
+    /// passed to the function, or named arguments which do not match a
+    /// parameter. The user has already been notified so you should break
+    /// execution after seeing an error type.
     ///
     /// Also returns an error if the number of min/max arguments exceeds
     /// `u32::MAX`
@@ -270,11 +340,19 @@ impl<'a, 'b> ArgParser<'a, 'b> {
         let mut min_num_args = self.min_num_args.unwrap_or(max_num_args);
         let num_args = self.arg_zvals.len();
         let has_variadic = self.args.last().is_some_and(|arg| arg.variadic);
+        let has_named_rest = self.args.last().is_some_and(|arg| arg.named_rest);
         if has_variadic {
-            min_num_args = min_num_args.saturating_sub(1);
+            min_num_args = min_num_args.min(max_num_args - 1);
         }
+        // The named rest argument only receives named arguments.
+        let max_num_args = if has_named_rest {
+            max_num_args - 1
+        } else {
+            max_num_args
+        };
 
-        if num_args < min_num_args || (!has_variadic && num_args > max_num_args) {
+        if num_args < min_num_args || ((!has_variadic || has_named_rest) && num_args > max_num_args)
+        {
             // SAFETY: Exported C function is safe, return value is unused and parameters
             // are copied.
             unsafe {
@@ -286,6 +364,19 @@ impl<'a, 'b> ArgParser<'a, 'b> {
             return Err(Error::IncorrectArguments(num_args, min_num_args));
         }
 
+        if let Some(named_params) = self.named_params {
+            let Some(arg) = self.args.last_mut().filter(|arg| arg.named_rest) else {
+                let name = named_params
+                    .iter()
+                    .next()
+                    .map(|(key, _)| key.to_string())
+                    .unwrap_or_default();
+                throw(ce::error(), &format!("Unknown named parameter ${name}"))?;
+                return Err(Error::UnknownNamedArgument(name));
+            };
+            arg.named_params = Some(named_params);
+        }
+
         for (i, arg_zval) in self.arg_zvals.into_iter().enumerate() {
             let arg = match self.args.get_mut(i) {
                 Some(arg) => Some(arg),
@@ -371,6 +462,16 @@ mod tests {
         assert_eq!(result.unwrap(), 42);
     }
 
+    #[test]
+    fn test_is_named_rest() {
+        let arg = Arg::new("test", DataType::Mixed).is_named_rest();
+        assert!(arg.variadic);
+        assert!(arg.named_rest);
+        assert!(arg
+            .named_rest_vals::<i64>()
+            .is_ok_and(|vals| vals.is_empty()));
+    }
+
     #[test]
     fn test_val_no_value() {
         let mut arg = Arg::new("test", DataType::Long);
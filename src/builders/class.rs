@@ -8,27 +8,59 @@ use crate::{
     error::{Error, Result},
     exception::PhpException,
     ffi::{
-        zend_declare_class_constant, zend_declare_property, zend_do_implement_interface,
-        zend_register_internal_class_ex,
+        zend_declare_class_constant, zend_declare_property, zend_declare_typed_property,
+        zend_do_implement_interface, zend_register_internal_class_ex,
+        zend_register_internal_interface,
+    },
+    flags::{ClassFlags, DataType, MethodFlags, PropertyFlags, ZvalTypeFlags},
+    ops::PhpIterable,
+    types::{ZendClassObject, ZendIterator, ZendObject, ZendStr, Zval},
+    zend::{
+        self, get_iterator_method, ClassEntry, ClassIterator, ExecuteData, FunctionEntry, ZendType,
     },
-    flags::{ClassFlags, MethodFlags, PropertyFlags},
-    types::{ZendClassObject, ZendObject, ZendStr, Zval},
-    zend::{ClassEntry, ExecuteData, FunctionEntry},
     zend_fastcall,
 };
 
 type ConstantEntry = (String, Box<dyn FnOnce() -> Result<Zval>>, DocComments);
+type StaticPropertyEntry = (
+    String,
+    Box<dyn FnOnce() -> Result<Zval>>,
+    PropertyFlags,
+    DocComments,
+);
+
+/// Default value of a declared property and its representation in stubs.
+pub(crate) type PropertyDefault = (Box<dyn FnOnce() -> Result<Zval>>, String);
+
+/// A property declared on a class.
+pub(crate) struct PropertyEntry {
+    pub(crate) name: String,
+    pub(crate) flags: PropertyFlags,
+    /// Declared type of the property and whether it is nullable.
+    pub(crate) ty: Option<(DataType, bool)>,
+    pub(crate) default: Option<PropertyDefault>,
+    pub(crate) docs: DocComments,
+}
+
+/// Handler creating an iterator over an object of a class.
+type GetIterator = unsafe extern "C" fn(
+    ce: *mut ClassEntry,
+    object: *mut Zval,
+    by_ref: std::os::raw::c_int,
+) -> *mut ZendIterator;
 
 /// Builder for registering a class in PHP.
 #[must_use]
 pub struct ClassBuilder {
     pub(crate) name: String,
-    ce: ClassEntry,
+    pub(crate) ce: ClassEntry,
     pub(crate) extends: Option<ClassEntryInfo>,
     pub(crate) interfaces: Vec<ClassEntryInfo>,
     pub(crate) methods: Vec<(FunctionBuilder<'static>, MethodFlags)>,
     object_override: Option<unsafe extern "C" fn(class_type: *mut ClassEntry) -> *mut ZendObject>,
-    pub(crate) properties: Vec<(String, PropertyFlags, DocComments)>,
+    get_iterator: Option<GetIterator>,
+    pub(crate) properties: Vec<PropertyEntry>,
+    pub(crate) static_properties: Vec<StaticPropertyEntry>,
     pub(crate) constants: Vec<ConstantEntry>,
     register: Option<fn(&'static mut ClassEntry)>,
     pub(crate) docs: DocComments,
@@ -51,7 +83,9 @@ impl ClassBuilder {
             interfaces: vec![],
             methods: vec![],
             object_override: None,
+            get_iterator: None,
             properties: vec![],
+            static_properties: vec![],
             constants: vec![],
             register: None,
             docs: &[],
@@ -68,7 +102,8 @@ impl ClassBuilder {
         self
     }
 
-    /// Implements an interface on the class.
+    /// Implements an interface on the class. When building an interface, the
+    /// given interface is extended instead.
     ///
     /// # Parameters
     ///
@@ -93,28 +128,147 @@ impl ClassBuilder {
         self
     }
 
-    /// Adds a property to the class. The initial type of the property is given
-    /// by the type of the given default. Note that the user can change the
-    /// type.
+    /// Adds an untyped property to the class. The property defaults to `null`,
+    /// use [`ClassBuilder::property_default`] to change the default.
     ///
     /// # Parameters
     ///
     /// * `name` - The name of the property to add to the class.
-    /// * `default` - The default value of the property.
     /// * `flags` - Flags relating to the property. See [`PropertyFlags`].
     /// * `docs` - Documentation comments for the property.
+    pub fn property<T: Into<String>>(
+        mut self,
+        name: T,
+        flags: PropertyFlags,
+        docs: DocComments,
+    ) -> Self {
+        self.properties.push(PropertyEntry {
+            name: name.into(),
+            flags,
+            ty: None,
+            default: None,
+            docs,
+        });
+        self
+    }
+
+    /// Adds a typed property to the class, e.g. `public ?int $id;`.
+    ///
+    /// Readonly properties must be typed, a [`PropertyFlags::Readonly`]
+    /// property with a type which cannot be declared in PHP is declared as
+    /// `mixed`.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - The name of the property to add to the class.
+    /// * `ty` - The type of the property. Types which cannot be declared on a
+    ///   property in PHP, such as `callable`, are ignored.
+    /// * `nullable` - Whether the property accepts `null`.
+    /// * `flags` - Flags relating to the property. See [`PropertyFlags`].
+    /// * `docs` - Documentation comments for the property.
+    pub fn typed_property<T: Into<String>>(
+        mut self,
+        name: T,
+        ty: DataType,
+        nullable: bool,
+        flags: PropertyFlags,
+        docs: DocComments,
+    ) -> Self {
+        self.properties.push(PropertyEntry {
+            name: name.into(),
+            flags,
+            ty: Some((ty, nullable)),
+            default: None,
+            docs,
+        });
+        self
+    }
+
+    /// Sets the default value of a property previously added to the class.
+    ///
+    /// The default is the initial value of the property in the class
+    /// declaration, visible through reflection and to objects of PHP
+    /// subclasses which do not call the parent constructor.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - The name of the property.
+    /// * `value` - The default value of the property.
+    /// * `stub` - The PHP representation of the default value, used when
+    ///   generating stubs, e.g. `'foo'`.
     ///
     /// # Panics
     ///
-    /// Function will panic if the given `default` cannot be converted into a
-    /// [`Zval`].
-    pub fn property<T: Into<String>>(
+    /// Panics if no property named `name` has been added to the class.
+    pub fn property_default<T: Into<String>>(
+        self,
+        name: &str,
+        value: impl IntoZval + 'static,
+        stub: T,
+    ) -> Self {
+        self.dyn_property_default(name, Box::new(|| value.into_zval(true)), stub.into())
+    }
+
+    /// Sets the default value of a property to the value returned by
+    /// `value` when the class is registered.
+    pub(crate) fn dyn_property_default(
+        mut self,
+        name: &str,
+        value: Box<dyn FnOnce() -> Result<Zval>>,
+        stub: String,
+    ) -> Self {
+        let property = self
+            .properties
+            .iter_mut()
+            .rfind(|property| property.name == name)
+            .unwrap_or_else(|| panic!("Property `{name}` has not been added to the class"));
+        property.default = Some((value, stub));
+        self
+    }
+
+    /// Adds a static property to the class, e.g. `public static $count = 0;`.
+    /// The [`PropertyFlags::Static`] flag is added to the given flags.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - The name of the property to add to the class.
+    /// * `value` - The initial value of the property.
+    /// * `flags` - Flags relating to the property. See [`PropertyFlags`].
+    /// * `docs` - Documentation comments for the property.
+    pub fn static_property<T: Into<String>>(
         mut self,
         name: T,
+        value: impl IntoZval + 'static,
         flags: PropertyFlags,
         docs: DocComments,
     ) -> Self {
-        self.properties.push((name.into(), flags, docs));
+        self.static_properties
+            .push((name.into(), Box::new(|| value.into_zval(true)), flags, docs));
+        self
+    }
+
+    /// Adds a static property to the class from a `dyn` object. The
+    /// [`PropertyFlags::Static`] flag is added to the given flags.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - The name of the property to add to the class.
+    /// * `value` - The initial value of the property.
+    /// * `flags` - Flags relating to the property. See [`PropertyFlags`].
+    /// * `docs` - Documentation comments for the property.
+    pub fn dyn_static_property<T: Into<String>>(
+        mut self,
+        name: T,
+        value: &'static dyn IntoZvalDyn,
+        flags: PropertyFlags,
+        docs: DocComments,
+    ) -> Self {
+        self.static_properties.push((
+            name.into(),
+            Box::new(move || value.as_zval(true)),
+            flags,
+            docs,
+        ));
         self
     }
 
@@ -250,6 +404,27 @@ impl ClassBuilder {
         )
     }
 
+    /// Makes objects of the class iterable with `foreach`, using the Rust
+    /// iterator returned by [`PhpIterable::get_iterator`]. The class implements
+    /// `IteratorAggregate`, with a `getIterator()` method returning an
+    /// iterator over the object.
+    ///
+    /// # Parameters
+    ///
+    /// * `T` - The type of the objects of the class.
+    pub fn iterable<T: PhpIterable>(mut self) -> Self {
+        self.get_iterator = Some(ClassIterator::<T>::get_iterator);
+        self.implements((zend::ce::aggregate, "\\IteratorAggregate"))
+            .method(
+                FunctionBuilder::new("getIterator", get_iterator_method).returns(
+                    DataType::Object(Some("\\Iterator")),
+                    false,
+                    false,
+                ),
+                MethodFlags::Public,
+            )
+    }
+
     /// Function to register the class with PHP. This function is called after
     /// the class is built.
     ///
@@ -301,17 +476,24 @@ impl ClassBuilder {
         let func = Box::into_raw(methods.into_boxed_slice()) as *const FunctionEntry;
         self.ce.info.internal.builtin_functions = func;
 
-        let class = unsafe {
-            zend_register_internal_class_ex(
-                &raw mut self.ce,
-                match self.extends {
-                    Some((ptr, _)) => ptr::from_ref(ptr()).cast_mut(),
-                    None => std::ptr::null_mut(),
-                },
-            )
-            .as_mut()
-            .ok_or(Error::InvalidPointer)?
+        let class = if self.ce.is_interface() {
+            debug_assert!(
+                self.extends.is_none(),
+                "Interfaces cannot extend a class, use `implements` instead."
+            );
+            unsafe { zend_register_internal_interface(&raw mut self.ce) }
+        } else {
+            unsafe {
+                zend_register_internal_class_ex(
+                    &raw mut self.ce,
+                    match self.extends {
+                        Some((ptr, _)) => ptr::from_ref(ptr()).cast_mut(),
+                        None => std::ptr::null_mut(),
+                    },
+                )
+            }
         };
+        let class = unsafe { class.as_mut() }.ok_or(Error::InvalidPointer)?;
 
         // disable serialization if the class has an associated object
         if self.object_override.is_some() {
@@ -325,6 +507,12 @@ impl ClassBuilder {
             }
         }
 
+        // Must be set before `IteratorAggregate` is implemented, which otherwise
+        // iterates over the object through the `getIterator()` method.
+        if let Some(get_iterator) = self.get_iterator {
+            class.get_iterator = Some(get_iterator);
+        }
+
         for (iface, _) in self.interfaces {
             let interface = iface();
             assert!(
@@ -335,14 +523,19 @@ impl ClassBuilder {
             unsafe { zend_do_implement_interface(class, ptr::from_ref(interface).cast_mut()) };
         }
 
-        for (name, flags, _) in self.properties {
+        for property in self.properties {
+            declare_property(class, property)?;
+        }
+
+        for (name, value, flags, _) in self.static_properties {
+            let value = Box::into_raw(Box::new(value()?));
             unsafe {
                 zend_declare_property(
                     class,
                     CString::new(name.as_str())?.as_ptr(),
                     name.len() as _,
-                    &mut Zval::new(),
-                    flags.bits().try_into()?,
+                    value,
+                    (flags | PropertyFlags::Static).bits().try_into()?,
                 );
             }
         }
@@ -373,6 +566,61 @@ impl ClassBuilder {
     }
 }
 
+/// Declares a property on a registered class.
+fn declare_property(class: &mut ClassEntry, property: PropertyEntry) -> Result<()> {
+    let PropertyEntry {
+        name,
+        flags,
+        ty,
+        default,
+        ..
+    } = property;
+    let default = default.map(|(value, _)| value()).transpose()?;
+    #[cfg(php81)]
+    let readonly = flags.contains(PropertyFlags::Readonly);
+    #[cfg(not(php81))]
+    let readonly = false;
+    let ty = ty
+        .and_then(|(ty, nullable)| ZendType::property_type(ty, nullable))
+        .or_else(|| {
+            readonly
+                .then(|| ZendType::property_type(DataType::Mixed, false))
+                .flatten()
+        });
+
+    if let Some(ty) = ty {
+        // Typed properties without a default start out uninitialized instead of
+        // `null`.
+        let mut default = default.unwrap_or_else(|| {
+            let mut undef = Zval::new();
+            undef.u1.type_info = ZvalTypeFlags::Undef.bits();
+            undef
+        });
+        unsafe {
+            zend_declare_typed_property(
+                class,
+                ZendStr::new_interned(&name, true).into_raw(),
+                &raw mut default,
+                flags.bits().try_into()?,
+                ptr::null_mut(),
+                ty,
+            );
+        }
+    } else {
+        unsafe {
+            zend_declare_property(
+                class,
+                CString::new(name.as_str())?.as_ptr(),
+                name.len() as _,
+                &mut default.unwrap_or_default(),
+                flags.bits().try_into()?,
+            );
+        }
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use crate::test::test_function;
@@ -388,7 +636,8 @@ mod tests {
         assert_eq!(class.interfaces, vec![]);
         assert_eq!(class.methods.len(), 0);
         assert_eq!(class.object_override, None);
-        assert_eq!(class.properties, vec![]);
+        assert_eq!(class.properties.len(), 0);
+        assert_eq!(class.static_properties.len(), 0);
         assert_eq!(class.constants.len(), 0);
         assert_eq!(class.register, None);
         assert_eq!(class.docs, &[] as DocComments);
@@ -418,14 +667,51 @@ mod tests {
     #[test]
     fn test_property() {
         let class = ClassBuilder::new("Foo").property("bar", PropertyFlags::Public, &["Doc 1"]);
-        assert_eq!(
-            class.properties,
-            vec![(
-                "bar".to_string(),
-                PropertyFlags::Public,
-                &["Doc 1"] as DocComments
-            )]
+        assert_eq!(class.properties.len(), 1);
+        assert_eq!(class.properties[0].name, "bar");
+        assert_eq!(class.properties[0].flags, PropertyFlags::Public);
+        assert_eq!(class.properties[0].ty, None);
+        assert!(class.properties[0].default.is_none());
+        assert_eq!(class.properties[0].docs, &["Doc 1"] as DocComments);
+    }
+
+    #[test]
+    fn test_typed_property() {
+        let class = ClassBuilder::new("Foo").typed_property(
+            "bar",
+            DataType::Long,
+            true,
+            PropertyFlags::Public,
+            &[],
         );
+        assert_eq!(class.properties[0].ty, Some((DataType::Long, true)));
+    }
+
+    #[test]
+    fn test_property_default() {
+        let class = ClassBuilder::new("Foo")
+            .property("bar", PropertyFlags::Public, &[])
+            .property_default("bar", 5, "5");
+        let (_, stub) = class.properties[0]
+            .default
+            .as_ref()
+            .expect("Default was not set");
+        assert_eq!(stub, "5");
+    }
+
+    #[test]
+    #[should_panic(expected = "Property `bar` has not been added to the class")]
+    fn test_property_default_missing() {
+        let _ = ClassBuilder::new("Foo").property_default("bar", 5, "5");
+    }
+
+    #[test]
+    fn test_static_property() {
+        let class =
+            ClassBuilder::new("Foo").static_property("count", 0, PropertyFlags::Public, &["Doc 1"]);
+        assert_eq!(class.static_properties.len(), 1);
+        assert_eq!(class.static_properties[0].0, "count");
+        assert_eq!(class.static_properties[0].2, PropertyFlags::Public);
     }
 
     #[test]
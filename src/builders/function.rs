@@ -138,7 +138,9 @@ impl<'a> FunctionBuilder<'a> {
     pub fn returns(mut self, type_: DataType, as_ref: bool, allow_null: bool) -> Self {
         self.retval = Some(type_);
         self.ret_as_ref = as_ref;
-        self.ret_as_null = allow_null && type_ != DataType::Void && type_ != DataType::Mixed;
+        self.ret_as_null = allow_null
+            && !matches!(type_, DataType::Void | DataType::Never)
+            && !type_.accepts_null();
         self
     }
 
@@ -171,7 +173,7 @@ impl<'a> FunctionBuilder<'a> {
 
         if variadic {
             self.function.flags |= MethodFlags::Variadic.bits();
-            n_req = n_req.saturating_sub(1);
+            n_req = n_req.min(self.args.len() - 1);
         }
 
         // argument header, retval etc
@@ -195,7 +197,14 @@ impl<'a> FunctionBuilder<'a> {
         args.extend(
             self.args
                 .iter()
-                .map(Arg::as_arg_info)
+                .enumerate()
+                .map(|(i, arg)| {
+                    if i < n_req {
+                        arg.as_arg_info()
+                    } else {
+                        arg.as_optional_arg_info()
+                    }
+                })
                 .collect::<Result<Vec<_>>>()?,
         );
 
@@ -0,0 +1,220 @@
+use std::{ffi::CString, fmt::Debug};
+
+use crate::{
+    ffi::{
+        display_ini_entries, php_info_print_table_colspan_header, php_info_print_table_end,
+        php_info_print_table_header, php_info_print_table_row, php_info_print_table_start,
+    },
+    zend::ModuleEntry,
+};
+
+/// The maximum number of cells in a header or row of an [`InfoTable`].
+pub const INFO_TABLE_MAX_COLUMNS: usize = 8;
+
+/// Builds the information shown about the extension by `phpinfo()`, which is
+/// registered with [`ModuleBuilder::info_table`]. PHP renders the table as
+/// text or HTML, depending on the SAPI.
+///
+/// ```rust,no_run
+/// use ext_php_rs::builders::{InfoTable, ModuleBuilder};
+///
+/// let module = ModuleBuilder::new("ext-name", "ext-version").info_table(|| {
+///     InfoTable::new()
+///         .header(["my extension", "enabled"])
+///         .row(["Version", env!("CARGO_PKG_VERSION")])
+///         .colspan_header(2, "Settings")
+///         .ini_entries()
+/// });
+/// ```
+///
+/// [`ModuleBuilder::info_table`]: crate::builders::ModuleBuilder::info_table
+#[must_use]
+#[derive(Debug, Default)]
+pub struct InfoTable {
+    items: Vec<InfoTableItem>,
+}
+
+#[derive(Debug)]
+enum InfoTableItem {
+    Header(Vec<CString>),
+    Row(Vec<CString>),
+    ColspanHeader(i32, CString),
+    IniEntries,
+}
+
+impl InfoTable {
+    /// Creates a new, empty information table.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a header to the table.
+    ///
+    /// # Parameters
+    ///
+    /// * `cells` - The cells of the header.
+    ///
+    /// # Panics
+    ///
+    /// * If the header has no cells or more than [`INFO_TABLE_MAX_COLUMNS`]
+    ///   cells.
+    /// * If a cell contains a NUL byte.
+    pub fn header<I>(mut self, cells: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Vec<u8>>,
+    {
+        self.items.push(InfoTableItem::Header(Self::cells(cells)));
+        self
+    }
+
+    /// Adds a row to the table. Empty cells are shown as having no value.
+    ///
+    /// # Parameters
+    ///
+    /// * `cells` - The cells of the row.
+    ///
+    /// # Panics
+    ///
+    /// * If the row has no cells or more than [`INFO_TABLE_MAX_COLUMNS`]
+    ///   cells.
+    /// * If a cell contains a NUL byte.
+    pub fn row<I>(mut self, cells: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Vec<u8>>,
+    {
+        self.items.push(InfoTableItem::Row(Self::cells(cells)));
+        self
+    }
+
+    /// Adds a header spanning multiple columns to the table.
+    ///
+    /// # Parameters
+    ///
+    /// * `columns` - The number of columns the header spans.
+    /// * `header` - The text of the header.
+    ///
+    /// # Panics
+    ///
+    /// * If the number of columns does not fit into an `i32`.
+    /// * If the header contains a NUL byte.
+    pub fn colspan_header(mut self, columns: usize, header: impl Into<Vec<u8>>) -> Self {
+        self.items.push(InfoTableItem::ColspanHeader(
+            columns.try_into().expect("Invalid number of columns"),
+            CString::new(header).expect("Info table header contains a NUL byte"),
+        ));
+        self
+    }
+
+    /// Shows the INI settings of the extension at this position. Otherwise,
+    /// the INI settings are shown after the table.
+    pub fn ini_entries(mut self) -> Self {
+        self.items.push(InfoTableItem::IniEntries);
+        self
+    }
+
+    /// Converts the cells of a header or row into C strings.
+    fn cells<I>(cells: I) -> Vec<CString>
+    where
+        I: IntoIterator,
+        I::Item: Into<Vec<u8>>,
+    {
+        let cells = cells
+            .into_iter()
+            .map(|cell| CString::new(cell).expect("Info table cell contains a NUL byte"))
+            .collect::<Vec<_>>();
+        assert!(
+            (1..=INFO_TABLE_MAX_COLUMNS).contains(&cells.len()),
+            "Info table rows must have between 1 and {INFO_TABLE_MAX_COLUMNS} cells"
+        );
+        cells
+    }
+
+    /// Prints the table as part of the `phpinfo()` output.
+    ///
+    /// # Parameters
+    ///
+    /// * `module` - The module the table is printed for.
+    pub(crate) fn print(&self, module: *mut ModuleEntry) {
+        let mut in_table = false;
+        let mut ini_entries = false;
+        for item in &self.items {
+            if let InfoTableItem::IniEntries = item {
+                if in_table {
+                    unsafe { php_info_print_table_end() };
+                    in_table = false;
+                }
+                unsafe { display_ini_entries(module) };
+                ini_entries = true;
+                continue;
+            }
+            if !in_table {
+                unsafe { php_info_print_table_start() };
+                in_table = true;
+            }
+            match item {
+                InfoTableItem::Header(cells) => print_cells(php_info_print_table_header, cells),
+                InfoTableItem::Row(cells) => print_cells(php_info_print_table_row, cells),
+                InfoTableItem::ColspanHeader(columns, header) => unsafe {
+                    php_info_print_table_colspan_header(*columns, header.as_ptr());
+                },
+                InfoTableItem::IniEntries => unreachable!(),
+            }
+        }
+        if in_table {
+            unsafe { php_info_print_table_end() };
+        }
+        if !ini_entries {
+            unsafe { display_ini_entries(module) };
+        }
+    }
+}
+
+/// A closure building the information table, registered with
+/// [`ModuleBuilder::info_table`](crate::builders::ModuleBuilder::info_table).
+pub(crate) struct InfoTableFunc(Box<dyn Fn() -> InfoTable + Send + Sync>);
+
+impl InfoTableFunc {
+    /// Creates the function from a closure.
+    pub(crate) fn new<F>(func: F) -> Self
+    where
+        F: Fn() -> InfoTable + Send + Sync + 'static,
+    {
+        Self(Box::new(func))
+    }
+
+    /// Builds the information table.
+    pub(crate) fn build(&self) -> InfoTable {
+        (self.0)()
+    }
+}
+
+impl Debug for InfoTableFunc {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("InfoTableFunc").finish_non_exhaustive()
+    }
+}
+
+/// Calls one of the variadic PHP functions printing a table header or row.
+fn print_cells(print: unsafe extern "C" fn(i32, ...), cells: &[CString]) {
+    macro_rules! print {
+        ($($n: literal => [$($i: literal),*]),*) => {
+            match cells.len() {
+                $($n => unsafe { print($n, $(cells[$i].as_ptr()),*) },)*
+                _ => unreachable!("Info table rows have at most {INFO_TABLE_MAX_COLUMNS} cells"),
+            }
+        };
+    }
+
+    print!(
+        1 => [0],
+        2 => [0, 1],
+        3 => [0, 1, 2],
+        4 => [0, 1, 2, 3],
+        5 => [0, 1, 2, 3, 4],
+        6 => [0, 1, 2, 3, 4, 5],
+        7 => [0, 1, 2, 3, 4, 5, 6],
+        8 => [0, 1, 2, 3, 4, 5, 6, 7]
+    );
+}
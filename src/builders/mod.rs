@@ -5,6 +5,7 @@ mod class;
 #[cfg(feature = "enum")]
 mod enum_builder;
 mod function;
+mod info_table;
 #[cfg(all(php82, feature = "embed"))]
 mod ini;
 mod module;
@@ -12,11 +13,15 @@ mod module;
 mod sapi;
 
 pub use class::ClassBuilder;
+pub(crate) use class::PropertyEntry;
 #[cfg(feature = "enum")]
 pub use enum_builder::EnumBuilder;
 pub use function::FunctionBuilder;
+pub(crate) use info_table::InfoTableFunc;
+pub use info_table::{InfoTable, INFO_TABLE_MAX_COLUMNS};
 #[cfg(all(php82, feature = "embed"))]
 pub use ini::IniBuilder;
+pub(crate) use module::StartupShutdownFunc;
 pub use module::{ModuleBuilder, ModuleStartup};
 #[cfg(feature = "embed")]
 pub use sapi::SapiBuilder;
@@ -1,6 +1,20 @@
-use std::{convert::TryFrom, ffi::CString, mem, ptr};
+#[cfg(feature = "inventory")]
+use std::collections::HashSet;
+use std::{
+    convert::TryFrom,
+    ffi::{c_void, CString},
+    fmt::Display,
+    mem, ptr,
+    sync::OnceLock,
+};
 
-use super::{ClassBuilder, FunctionBuilder};
+use super::{ClassBuilder, FunctionBuilder, InfoTable, InfoTableFunc};
+#[cfg(feature = "inventory")]
+use crate::internal::registry::{ModuleItem, ModuleItemKind};
+#[cfg(php_zts)]
+use crate::zend::module_globals_id_ptr;
+#[cfg(not(php_zts))]
+use crate::zend::module_globals_ptr;
 #[cfg(feature = "enum")]
 use crate::{builders::enum_builder::EnumBuilder, enum_::RegisteredEnum};
 use crate::{
@@ -8,8 +22,16 @@ use crate::{
     constant::IntoConst,
     describe::DocComments,
     error::Result,
-    ffi::{ext_php_rs_php_build_id, ZEND_MODULE_API_NO},
-    zend::{FunctionEntry, ModuleEntry},
+    ffi::{
+        display_ini_entries, ext_php_rs_php_build_id, zend_unregister_ini_entries,
+        MODULE_DEP_CONFLICTS, MODULE_DEP_OPTIONAL, MODULE_DEP_REQUIRED, ZEND_MODULE_API_NO,
+    },
+    flags::ClassFlags,
+    types::RegisteredResource,
+    zend::{
+        register_module_globals, FunctionEntry, IniEntryDef, IniSetting, IniSettingEntry, IniValue,
+        LifecycleHook, LifecycleHooks, ModuleDep, ModuleEntry, ModuleGlobals,
+    },
     PHP_DEBUG, PHP_ZTS,
 };
 
@@ -48,14 +70,27 @@ pub struct ModuleBuilder<'a> {
     pub(crate) functions: Vec<FunctionBuilder<'a>>,
     pub(crate) constants: Vec<(String, Box<dyn IntoConst + Send>, DocComments)>,
     pub(crate) classes: Vec<fn() -> ClassBuilder>,
+    pub(crate) interfaces: Vec<fn() -> ClassBuilder>,
     #[cfg(feature = "enum")]
     pub(crate) enums: Vec<fn() -> EnumBuilder>,
+    pub(crate) resources: Vec<fn(i32) -> Result<()>>,
+    pub(crate) ini_settings: Vec<&'static dyn IniSettingEntry>,
+    pub(crate) dependencies: Vec<(String, u32)>,
+    #[cfg(feature = "inventory")]
+    class_names: HashSet<&'static str>,
+    minit_hooks: Vec<LifecycleHook>,
+    mshutdown_hooks: Vec<LifecycleHook>,
+    rinit_hooks: Vec<LifecycleHook>,
+    rshutdown_hooks: Vec<LifecycleHook>,
+    globals_ctor: Option<GlobalsFunc>,
+    globals_dtor: Option<GlobalsFunc>,
     startup_func: Option<StartupShutdownFunc>,
     shutdown_func: Option<StartupShutdownFunc>,
     request_startup_func: Option<StartupShutdownFunc>,
     request_shutdown_func: Option<StartupShutdownFunc>,
     post_deactivate_func: Option<unsafe extern "C" fn() -> i32>,
     info_func: Option<InfoFunc>,
+    info_table: Option<InfoTableFunc>,
 }
 
 impl ModuleBuilder<'_> {
@@ -96,6 +131,40 @@ impl ModuleBuilder<'_> {
         self
     }
 
+    /// Declares that the extension requires another extension. PHP loads the
+    /// required extension first, and refuses to load the extension if the
+    /// required extension is not available.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the required extension, e.g. `json`.
+    pub fn requires(mut self, name: impl Into<String>) -> Self {
+        self.dependencies.push((name.into(), MODULE_DEP_REQUIRED));
+        self
+    }
+
+    /// Declares that the extension conflicts with another extension. PHP
+    /// refuses to load the extension if the conflicting extension is loaded.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the conflicting extension.
+    pub fn conflicts(mut self, name: impl Into<String>) -> Self {
+        self.dependencies.push((name.into(), MODULE_DEP_CONFLICTS));
+        self
+    }
+
+    /// Declares that the extension optionally depends on another extension.
+    /// If the extension is available, PHP loads it first.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the optional extension.
+    pub fn optional(mut self, name: impl Into<String>) -> Self {
+        self.dependencies.push((name.into(), MODULE_DEP_OPTIONAL));
+        self
+    }
+
     /// Sets the startup function for the extension.
     ///
     /// # Arguments
@@ -150,6 +219,74 @@ impl ModuleBuilder<'_> {
         self
     }
 
+    /// Adds a hook which is called when the extension is started, after its
+    /// constants, classes and other items have been registered. Returning an
+    /// error or panicking prevents the extension from starting.
+    ///
+    /// Hooks of the same stage are called in the order they were added, after
+    /// the function set with [`ModuleBuilder::startup_function`].
+    ///
+    /// # Arguments
+    ///
+    /// * `hook` - The hook to be called on startup, which is passed the module
+    ///   number.
+    pub fn on_minit<F, E>(mut self, hook: F) -> Self
+    where
+        F: Fn(i32) -> Result<(), E> + Send + Sync + 'static,
+        E: Display,
+    {
+        self.minit_hooks.push(LifecycleHook::new(hook));
+        self
+    }
+
+    /// Adds a hook which is called when the extension is shut down. All hooks
+    /// are called, even if one of them fails or panics.
+    ///
+    /// # Arguments
+    ///
+    /// * `hook` - The hook to be called on shutdown, which is passed the
+    ///   module number.
+    pub fn on_mshutdown<F, E>(mut self, hook: F) -> Self
+    where
+        F: Fn(i32) -> Result<(), E> + Send + Sync + 'static,
+        E: Display,
+    {
+        self.mshutdown_hooks.push(LifecycleHook::new(hook));
+        self
+    }
+
+    /// Adds a hook which is called when a request is started. Returning an
+    /// error or panicking fails the request.
+    ///
+    /// # Arguments
+    ///
+    /// * `hook` - The hook to be called on request startup, which is passed
+    ///   the module number.
+    pub fn on_rinit<F, E>(mut self, hook: F) -> Self
+    where
+        F: Fn(i32) -> Result<(), E> + Send + Sync + 'static,
+        E: Display,
+    {
+        self.rinit_hooks.push(LifecycleHook::new(hook));
+        self
+    }
+
+    /// Adds a hook which is called when a request is shut down. All hooks are
+    /// called, even if one of them fails or panics.
+    ///
+    /// # Arguments
+    ///
+    /// * `hook` - The hook to be called on request shutdown, which is passed
+    ///   the module number.
+    pub fn on_rshutdown<F, E>(mut self, hook: F) -> Self
+    where
+        F: Fn(i32) -> Result<(), E> + Send + Sync + 'static,
+        E: Display,
+    {
+        self.rshutdown_hooks.push(LifecycleHook::new(hook));
+        self
+    }
+
     /// Sets the extension information function for the extension.
     ///
     /// # Arguments
@@ -158,6 +295,38 @@ impl ModuleBuilder<'_> {
     ///   the extension.
     pub fn info_function(mut self, func: InfoFunc) -> Self {
         self.info_func = Some(func);
+        self.info_table = None;
+        self
+    }
+
+    /// Sets the function building the table shown by `phpinfo()` for the
+    /// extension, replacing the function set with
+    /// [`ModuleBuilder::info_function`].
+    ///
+    /// # Arguments
+    ///
+    /// * `func` - The closure building the information table, called
+    ///   whenever `phpinfo()` shows the extension.
+    pub fn info_table<F>(mut self, func: F) -> Self
+    where
+        F: Fn() -> InfoTable + Send + Sync + 'static,
+    {
+        self.info_table = Some(InfoTableFunc::new(func));
+        self.info_func = None;
+        self
+    }
+
+    /// Sets the type holding the globals of the extension. The globals are
+    /// created when the extension is loaded, and are accessed with
+    /// [`ModuleGlobals::get`] and [`ModuleGlobals::get_mut`].
+    ///
+    /// # Panics
+    ///
+    /// * Panics if the globals have already been set to another type.
+    pub fn globals<T: ModuleGlobals>(mut self) -> Self {
+        let (ctor, dtor) = register_module_globals::<T>();
+        self.globals_ctor = Some(ctor);
+        self.globals_dtor = Some(dtor);
         self
     }
 
@@ -198,8 +367,10 @@ impl ModuleBuilder<'_> {
     ///
     /// * Panics if a constant could not be registered.
     pub fn class<T: RegisteredClass>(mut self) -> Self {
+        #[cfg(feature = "inventory")]
+        self.class_names.insert(T::CLASS_NAME);
         self.classes.push(|| {
-            let mut builder = ClassBuilder::new(T::CLASS_NAME);
+            let mut builder = ClassBuilder::new(T::CLASS_NAME).flags(T::FLAGS);
             for (method, flags) in T::method_builders() {
                 builder = builder.method(method, flags);
             }
@@ -215,8 +386,24 @@ impl ModuleBuilder<'_> {
                     .expect("Failed to register constant");
             }
             for (name, prop_info) in T::get_properties() {
-                builder = builder.property(name, prop_info.flags, prop_info.docs);
+                builder = match prop_info.ty {
+                    Some(ty) => builder.typed_property(
+                        name,
+                        ty,
+                        prop_info.nullable,
+                        prop_info.flags,
+                        prop_info.docs,
+                    ),
+                    None => builder.property(name, prop_info.flags, prop_info.docs),
+                };
+                if let Some((default, stub)) = prop_info.default {
+                    builder = builder.dyn_property_default(name, Box::new(default), stub.into());
+                }
+            }
+            for (name, flags, value, docs) in T::static_properties() {
+                builder = builder.dyn_static_property(*name, *value, *flags, docs);
             }
+            builder = T::modify_builder(builder);
             if let Some(modifier) = T::BUILDER_MODIFIER {
                 builder = modifier(builder);
             }
@@ -231,12 +418,51 @@ impl ModuleBuilder<'_> {
         self
     }
 
+    /// Adds an interface to the extension. Interfaces are registered before
+    /// any classes, so classes added with [`ModuleBuilder::class`] can
+    /// implement them.
+    ///
+    /// # Panics
+    ///
+    /// * Panics if a constant could not be registered.
+    pub fn interface<T: RegisteredClass>(mut self) -> Self {
+        #[cfg(feature = "inventory")]
+        self.class_names.insert(T::CLASS_NAME);
+        self.interfaces.push(|| {
+            let mut builder = ClassBuilder::new(T::CLASS_NAME);
+            for (method, flags) in T::method_builders() {
+                builder = builder.method(method, flags);
+            }
+            for interface in T::IMPLEMENTS {
+                builder = builder.implements(*interface);
+            }
+            for (name, value, docs) in T::constants() {
+                builder = builder
+                    .dyn_constant(*name, *value, docs)
+                    .expect("Failed to register constant");
+            }
+            if let Some(modifier) = T::BUILDER_MODIFIER {
+                builder = modifier(builder);
+            }
+
+            builder
+                .flags(T::FLAGS | ClassFlags::Interface)
+                .registration(|ce| {
+                    T::get_metadata().set_ce(ce);
+                })
+                .docs(T::DOC_COMMENTS)
+        });
+        self
+    }
+
     /// Adds an enum to the extension.
     #[cfg(feature = "enum")]
     pub fn enumeration<T>(mut self) -> Self
     where
         T: RegisteredClass + RegisteredEnum,
     {
+        #[cfg(feature = "inventory")]
+        self.class_names.insert(T::CLASS_NAME);
         self.enums.push(|| {
             let mut builder = EnumBuilder::new(T::CLASS_NAME);
             for case in T::CASES {
@@ -255,6 +481,52 @@ impl ModuleBuilder<'_> {
 
         self
     }
+
+    /// Adds all functions, classes, interfaces and enums declared with the
+    /// `#[php_function]`, `#[php_class]`, `#[php_interface]` and `#[php_enum]`
+    /// macros in any crate linked into the extension. Items which have already
+    /// been added to the module are skipped.
+    ///
+    /// This is called by the `#[php_module]` macro after the module function
+    /// has run. Classes are added after the classes and interfaces they extend
+    /// or implement, if those are registered automatically as well.
+    #[cfg(feature = "inventory")]
+    #[cfg_attr(docs, doc(cfg(feature = "inventory")))]
+    pub fn auto_register(mut self) -> Self {
+        let mut items = inventory::iter::<ModuleItem>().collect::<Vec<_>>();
+        // The order of the items depends on the linker, so they are sorted to
+        // register them in the same order on every build.
+        items.sort_by_key(|item| item.name);
+        for item in order_module_items(&items) {
+            let registered = match item.kind {
+                ModuleItemKind::Function => self.functions.iter().any(|f| f.name == item.name),
+                ModuleItemKind::Class => self.class_names.contains(item.name),
+            };
+            if !registered {
+                self = (item.register)(self);
+            }
+        }
+        self
+    }
+
+    /// Adds an INI setting to the extension. INI settings are registered when
+    /// the extension is started, and unregistered when it is shut down.
+    ///
+    /// # Arguments
+    ///
+    /// * `setting` - The INI setting to add.
+    pub fn ini_setting<T: IniValue>(mut self, setting: &'static IniSetting<T>) -> Self {
+        self.ini_settings.push(setting);
+        self
+    }
+
+    /// Adds a resource type to the extension. Resource types are registered
+    /// before any classes.
+    pub fn resource<T: RegisteredResource>(mut self) -> Self {
+        self.resources
+            .push(|module_number| T::resource_type().register(module_number));
+        self
+    }
 }
 
 /// Artifacts from the [`ModuleBuilder`] that should be revisited inside the
@@ -262,8 +534,12 @@ impl ModuleBuilder<'_> {
 pub struct ModuleStartup {
     constants: Vec<(String, Box<dyn IntoConst + Send>)>,
     classes: Vec<fn() -> ClassBuilder>,
+    interfaces: Vec<fn() -> ClassBuilder>,
     #[cfg(feature = "enum")]
     enums: Vec<fn() -> EnumBuilder>,
+    resources: Vec<fn(i32) -> Result<()>>,
+    ini_settings: Vec<&'static dyn IniSettingEntry>,
+    hooks: LifecycleHooks,
 }
 
 impl ModuleStartup {
@@ -272,16 +548,34 @@ impl ModuleStartup {
     ///
     /// # Errors
     ///
-    /// * Returns an error if a constant could not be registered.
+    /// * Returns an error if a constant or resource type could not be
+    ///   registered, or if a startup hook failed.
     ///
     /// # Panics
     ///
     /// * Panics if a class could not be registered.
-    pub fn startup(self, _ty: i32, mod_num: i32) -> Result<()> {
+    pub fn startup(self, ty: i32, mod_num: i32) -> Result<()> {
         for (name, val) in self.constants {
             val.register_constant(&name, mod_num)?;
         }
 
+        if !self.ini_settings.is_empty() {
+            let entries = self
+                .ini_settings
+                .into_iter()
+                .map(IniSettingEntry::entry)
+                .collect();
+            IniEntryDef::register(entries, mod_num);
+        }
+
+        for resource in self.resources {
+            resource(mod_num)?;
+        }
+
+        self.interfaces.into_iter().map(|i| i()).for_each(|i| {
+            i.register().expect("Failed to build interface");
+        });
+
         self.classes.into_iter().map(|c| c()).for_each(|c| {
             c.register().expect("Failed to build class");
         });
@@ -294,16 +588,153 @@ impl ModuleStartup {
                 e.register().expect("Failed to build enum");
             });
 
-        Ok(())
+        self.hooks.run(ty, mod_num)
     }
 }
 
 /// A function to be called when the extension is starting up or shutting down.
 pub type StartupShutdownFunc = unsafe extern "C" fn(_type: i32, _module_number: i32) -> i32;
 
+/// A function to be called when the module globals are created or destroyed.
+pub type GlobalsFunc = unsafe extern "C" fn(globals: *mut c_void);
+
 /// A function to be called when `phpinfo();` is called.
 pub type InfoFunc = unsafe extern "C" fn(zend_module: *mut ModuleEntry);
 
+/// Shutdown function and hooks of the extension, called by
+/// [`module_shutdown_function`].
+static MODULE_SHUTDOWN_HOOKS: OnceLock<LifecycleHooks> = OnceLock::new();
+
+/// Request startup function and hooks of the extension, called by
+/// [`request_startup_function`].
+static REQUEST_STARTUP_HOOKS: OnceLock<LifecycleHooks> = OnceLock::new();
+
+/// Request shutdown function and hooks of the extension, called by
+/// [`request_shutdown_function`].
+static REQUEST_SHUTDOWN_HOOKS: OnceLock<LifecycleHooks> = OnceLock::new();
+
+/// Info function set by the extension, called by [`ini_info_function`].
+static MODULE_INFO_FUNC: OnceLock<Option<InfoFunc>> = OnceLock::new();
+
+/// Function building the information table of the extension, called by
+/// [`info_table_function`].
+static MODULE_INFO_TABLE: OnceLock<InfoTableFunc> = OnceLock::new();
+
+/// Info function of extensions with an information table, printing the table.
+unsafe extern "C" fn info_table_function(module: *mut ModuleEntry) {
+    if let Some(func) = MODULE_INFO_TABLE.get() {
+        func.build().print(module);
+    }
+}
+
+/// Shutdown function of extensions with INI settings or shutdown hooks,
+/// calling the shutdown function and hooks of the extension and unregistering
+/// the settings.
+unsafe extern "C" fn module_shutdown_function(ty: i32, module_number: i32) -> i32 {
+    let result = MODULE_SHUTDOWN_HOOKS
+        .get()
+        .map_or(0, |hooks| hooks.run_ffi(ty, module_number));
+    zend_unregister_ini_entries(module_number);
+    result
+}
+
+/// Request startup function of extensions with request startup hooks.
+unsafe extern "C" fn request_startup_function(ty: i32, module_number: i32) -> i32 {
+    REQUEST_STARTUP_HOOKS
+        .get()
+        .map_or(0, |hooks| hooks.run_ffi(ty, module_number))
+}
+
+/// Request shutdown function of extensions with request shutdown hooks.
+unsafe extern "C" fn request_shutdown_function(ty: i32, module_number: i32) -> i32 {
+    REQUEST_SHUTDOWN_HOOKS
+        .get()
+        .map_or(0, |hooks| hooks.run_ffi(ty, module_number))
+}
+
+/// Returns the lifecycle function of a stage, which is `wrapper` if the stage
+/// has hooks or `force` is set, storing the function and hooks in `storage`.
+fn lifecycle_function(
+    storage: &'static OnceLock<LifecycleHooks>,
+    hooks: LifecycleHooks,
+    wrapper: StartupShutdownFunc,
+    force: bool,
+) -> Option<StartupShutdownFunc> {
+    if hooks.is_empty() && !force {
+        return hooks.func();
+    }
+    let _ = storage.set(hooks);
+    Some(wrapper)
+}
+
+/// Info function of extensions with INI settings, calling the info function of
+/// the extension and showing the settings.
+unsafe extern "C" fn ini_info_function(module: *mut ModuleEntry) {
+    if let Some(func) = MODULE_INFO_FUNC.get().copied().flatten() {
+        func(module);
+    }
+    display_ini_entries(module);
+}
+
+/// Orders module items so that classes follow the classes and interfaces they
+/// extend or implement, keeping the order of `items` otherwise.
+#[cfg(feature = "inventory")]
+fn order_module_items<'a>(items: &[&'a ModuleItem]) -> Vec<&'a ModuleItem> {
+    fn visit<'a>(
+        index: usize,
+        items: &[&'a ModuleItem],
+        visited: &mut [bool],
+        ordered: &mut Vec<&'a ModuleItem>,
+    ) {
+        if mem::replace(&mut visited[index], true) {
+            return;
+        }
+        for dependency in (items[index].dependencies)() {
+            let position = items
+                .iter()
+                .position(|item| item.kind == ModuleItemKind::Class && item.name == dependency);
+            if let Some(position) = position {
+                visit(position, items, visited, ordered);
+            }
+        }
+        ordered.push(items[index]);
+    }
+
+    let mut visited = vec![false; items.len()];
+    let mut ordered = Vec::with_capacity(items.len());
+    for index in 0..items.len() {
+        visit(index, items, &mut visited, &mut ordered);
+    }
+    ordered
+}
+
+/// Builds the null-terminated array of module dependencies, which is kept
+/// alive for as long as the module is loaded.
+fn dependencies(dependencies: Vec<(String, u32)>) -> Result<*const ModuleDep> {
+    if dependencies.is_empty() {
+        return Ok(ptr::null());
+    }
+
+    let mut deps = dependencies
+        .into_iter()
+        .map(|(name, ty)| {
+            Ok(ModuleDep {
+                name: CString::new(name)?.into_raw(),
+                rel: ptr::null(),
+                version: ptr::null(),
+                type_: ty.try_into()?,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    deps.push(ModuleDep {
+        name: ptr::null(),
+        rel: ptr::null(),
+        version: ptr::null(),
+        type_: 0,
+    });
+    Ok(Box::into_raw(deps.into_boxed_slice()) as *const ModuleDep)
+}
+
 /// Builds a [`ModuleEntry`] and [`ModuleStartup`] from a [`ModuleBuilder`].
 /// This is the entry point for the module to be registered with PHP.
 impl TryFrom<ModuleBuilder<'_>> for (ModuleEntry, ModuleStartup) {
@@ -318,6 +749,8 @@ impl TryFrom<ModuleBuilder<'_>> for (ModuleEntry, ModuleStartup) {
         functions.push(FunctionEntry::end());
         let functions = Box::into_raw(functions.into_boxed_slice()) as *const FunctionEntry;
 
+        let deps = dependencies(builder.dependencies)?;
+
         let name = CString::new(builder.name)?.into_raw();
         let version = CString::new(builder.version)?.into_raw();
 
@@ -328,9 +761,45 @@ impl TryFrom<ModuleBuilder<'_>> for (ModuleEntry, ModuleStartup) {
                 .map(|(n, v, _)| (n, v))
                 .collect(),
             classes: builder.classes,
+            interfaces: builder.interfaces,
             #[cfg(feature = "enum")]
             enums: builder.enums,
+            resources: builder.resources,
+            ini_settings: builder.ini_settings,
+            hooks: LifecycleHooks::new(None, builder.minit_hooks, false),
+        };
+
+        // The INI settings are unregistered on shutdown and shown by
+        // `phpinfo()`, and the lifecycle hooks are called, by wrapping the
+        // functions of the module.
+        let shutdown_func = lifecycle_function(
+            &MODULE_SHUTDOWN_HOOKS,
+            LifecycleHooks::new(builder.shutdown_func, builder.mshutdown_hooks, true),
+            module_shutdown_function,
+            !startup.ini_settings.is_empty(),
+        );
+        let mut info_func = if startup.ini_settings.is_empty() {
+            builder.info_func
+        } else {
+            let _ = MODULE_INFO_FUNC.set(builder.info_func);
+            Some(ini_info_function as InfoFunc)
         };
+        if let Some(info_table) = builder.info_table {
+            let _ = MODULE_INFO_TABLE.set(info_table);
+            info_func = Some(info_table_function);
+        }
+        let request_startup_func = lifecycle_function(
+            &REQUEST_STARTUP_HOOKS,
+            LifecycleHooks::new(builder.request_startup_func, builder.rinit_hooks, false),
+            request_startup_function,
+            false,
+        );
+        let request_shutdown_func = lifecycle_function(
+            &REQUEST_SHUTDOWN_HOOKS,
+            LifecycleHooks::new(builder.request_shutdown_func, builder.rshutdown_hooks, true),
+            request_shutdown_function,
+            false,
+        );
 
         Ok((
             ModuleEntry {
@@ -339,22 +808,34 @@ impl TryFrom<ModuleBuilder<'_>> for (ModuleEntry, ModuleStartup) {
                 zend_debug: u8::from(PHP_DEBUG),
                 zts: u8::from(PHP_ZTS),
                 ini_entry: ptr::null(),
-                deps: ptr::null(),
+                deps,
                 name,
                 functions,
                 module_startup_func: builder.startup_func,
-                module_shutdown_func: builder.shutdown_func,
-                request_startup_func: builder.request_startup_func,
-                request_shutdown_func: builder.request_shutdown_func,
-                info_func: builder.info_func,
+                module_shutdown_func: shutdown_func,
+                request_startup_func,
+                request_shutdown_func,
+                info_func,
                 version,
-                globals_size: 0,
+                globals_size: if builder.globals_ctor.is_some() {
+                    mem::size_of::<*mut c_void>()
+                } else {
+                    0
+                },
                 #[cfg(not(php_zts))]
-                globals_ptr: ptr::null_mut(),
+                globals_ptr: if builder.globals_ctor.is_some() {
+                    module_globals_ptr()
+                } else {
+                    ptr::null_mut()
+                },
                 #[cfg(php_zts)]
-                globals_id_ptr: ptr::null_mut(),
-                globals_ctor: None,
-                globals_dtor: None,
+                globals_id_ptr: if builder.globals_ctor.is_some() {
+                    module_globals_id_ptr()
+                } else {
+                    ptr::null_mut()
+                },
+                globals_ctor: builder.globals_ctor,
+                globals_dtor: builder.globals_dtor,
                 post_deactivate_func: builder.post_deactivate_func,
                 module_started: 0,
                 type_: 0,
@@ -383,14 +864,25 @@ mod tests {
         assert!(builder.functions.is_empty());
         assert!(builder.constants.is_empty());
         assert!(builder.classes.is_empty());
+        assert!(builder.interfaces.is_empty());
         assert!(builder.startup_func.is_none());
         assert!(builder.shutdown_func.is_none());
         assert!(builder.request_startup_func.is_none());
         assert!(builder.request_shutdown_func.is_none());
         assert!(builder.post_deactivate_func.is_none());
         assert!(builder.info_func.is_none());
+        assert!(builder.info_table.is_none());
         #[cfg(feature = "enum")]
         assert!(builder.enums.is_empty());
+        assert!(builder.resources.is_empty());
+        assert!(builder.ini_settings.is_empty());
+        assert!(builder.dependencies.is_empty());
+        assert!(builder.minit_hooks.is_empty());
+        assert!(builder.mshutdown_hooks.is_empty());
+        assert!(builder.rinit_hooks.is_empty());
+        assert!(builder.rshutdown_hooks.is_empty());
+        assert!(builder.globals_ctor.is_none());
+        assert!(builder.globals_dtor.is_none());
     }
 
     #[test]
@@ -446,6 +938,104 @@ mod tests {
         assert!(builder.info_func.is_some());
     }
 
+    #[test]
+    fn test_lifecycle_hooks() {
+        let builder = ModuleBuilder::new("test", "1.0")
+            .on_minit(|_| Ok::<_, String>(()))
+            .on_rinit(|_| Ok::<_, String>(()))
+            .on_rinit(|_| Ok::<_, String>(()))
+            .on_rshutdown(|_| Ok::<_, String>(()))
+            .on_mshutdown(|_| Ok::<_, String>(()));
+        assert_eq!(builder.minit_hooks.len(), 1);
+        assert_eq!(builder.rinit_hooks.len(), 2);
+        assert_eq!(builder.rshutdown_hooks.len(), 1);
+        assert_eq!(builder.mshutdown_hooks.len(), 1);
+    }
+
+    #[cfg(feature = "inventory")]
+    inventory::submit! {
+        ModuleItem {
+            name: "test_auto_registered",
+            kind: ModuleItemKind::Function,
+            register: |builder| {
+                builder.function(FunctionBuilder::new("test_auto_registered", test_function))
+            },
+            dependencies: Vec::new,
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "inventory")]
+    fn test_auto_register() {
+        let builder = ModuleBuilder::new("test", "1.0").auto_register();
+        assert!(builder
+            .functions
+            .iter()
+            .any(|f| f.name == "test_auto_registered"));
+
+        let builder = ModuleBuilder::new("test", "1.0")
+            .function(FunctionBuilder::new("test_auto_registered", test_function))
+            .auto_register();
+        assert_eq!(
+            builder
+                .functions
+                .iter()
+                .filter(|f| f.name == "test_auto_registered")
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "inventory")]
+    fn test_order_module_items() {
+        let class = |name: &'static str, dependencies: fn() -> Vec<&'static str>| ModuleItem {
+            name,
+            kind: ModuleItemKind::Class,
+            register: |builder| builder,
+            dependencies,
+        };
+        let apple = class("Apple", || vec!["Banana"]);
+        let banana = class("Banana", || vec!["Cherry", "Countable"]);
+        let cherry = class("Cherry", Vec::new);
+        let date = class("Date", Vec::new);
+        let ordered = order_module_items(&[&apple, &banana, &cherry, &date]);
+        assert_eq!(
+            ordered.iter().map(|item| item.name).collect::<Vec<_>>(),
+            ["Cherry", "Banana", "Apple", "Date"]
+        );
+    }
+
+    #[test]
+    fn test_dependencies() {
+        let builder = ModuleBuilder::new("test", "1.0")
+            .requires("json")
+            .conflicts("apcu")
+            .optional("pdo");
+        assert_eq!(
+            builder.dependencies,
+            vec![
+                ("json".to_owned(), MODULE_DEP_REQUIRED),
+                ("apcu".to_owned(), MODULE_DEP_CONFLICTS),
+                ("pdo".to_owned(), MODULE_DEP_OPTIONAL),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_set_info_table() {
+        let builder = ModuleBuilder::new("test", "1.0")
+            .info_function(test_info_function)
+            .info_table(InfoTable::new);
+        assert!(builder.info_func.is_none());
+        assert!(builder.info_table.is_some());
+
+        let version = String::from("1.0");
+        let builder = ModuleBuilder::new("test", "1.0")
+            .info_table(move || InfoTable::new().row(["Version", version.as_str()]));
+        assert!(builder.info_table.is_some());
+    }
+
     #[test]
     fn test_add_function() {
         let builder =
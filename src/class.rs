@@ -13,7 +13,7 @@ use crate::{
     convert::IntoZvalDyn,
     describe::DocComments,
     exception::PhpException,
-    flags::{ClassFlags, MethodFlags},
+    flags::{ClassFlags, MethodFlags, PropertyFlags},
     internal::property::PropertyInfo,
     zend::{ClassEntry, ExecuteData, ZendObjectHandlers},
 };
@@ -72,6 +72,37 @@ pub trait RegisteredClass: Sized + 'static {
 
     /// Returns the constants provided by the class.
     fn constants() -> &'static [(&'static str, &'static dyn IntoZvalDyn, DocComments)];
+
+    /// Returns the static properties provided by the class, along with their
+    /// initial values.
+    #[must_use]
+    fn static_properties() -> &'static [(
+        &'static str,
+        PropertyFlags,
+        &'static dyn IntoZvalDyn,
+        DocComments,
+    )] {
+        &[]
+    }
+
+    /// Modifies the object handlers of the class after they have been
+    /// initialized from the standard handlers. Used by [`macro@php_class`] to
+    /// install the handlers of the traits in [`crate::ops`] implemented by
+    /// the class.
+    ///
+    /// [`macro@php_class`]: crate::php_class
+    #[inline]
+    fn modify_handlers(_handlers: &mut ZendObjectHandlers) {}
+
+    /// Modifies the builder of the class before the class is registered. Used
+    /// by [`macro@php_class`] to register the class entry handlers of the
+    /// traits in [`crate::ops`] implemented by the class.
+    ///
+    /// [`macro@php_class`]: crate::php_class
+    #[inline]
+    fn modify_builder(builder: ClassBuilder) -> ClassBuilder {
+        builder
+    }
 }
 
 /// Stores metadata about a classes Rust constructor, including the function
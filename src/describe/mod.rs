@@ -5,9 +5,9 @@ use std::vec::Vec as StdVec;
 #[cfg(feature = "enum")]
 use crate::builders::EnumBuilder;
 use crate::{
-    builders::{ClassBuilder, FunctionBuilder},
+    builders::{ClassBuilder, FunctionBuilder, PropertyEntry},
     constant::IntoConst,
-    flags::{DataType, MethodFlags, PropertyFlags},
+    flags::{ClassFlags, DataType, MethodFlags, PropertyFlags},
     prelude::ModuleBuilder,
 };
 use abi::{Option, RString, Str, Vec};
@@ -84,8 +84,9 @@ impl From<ModuleBuilder<'_>> for Module {
 
         #[allow(unused_mut)]
         let mut classes = builder
-            .classes
+            .interfaces
             .into_iter()
+            .chain(builder.classes)
             .map(|c| c().into())
             .collect::<StdVec<_>>();
 
@@ -146,7 +147,7 @@ impl From<FunctionBuilder<'_>> for Function {
                 .retval
                 .map(|r| Retval {
                     ty: r,
-                    nullable: r != DataType::Mixed && ret_allow_null,
+                    nullable: !r.accepts_null() && ret_allow_null,
                 })
                 .into(),
             params: val
@@ -193,6 +194,25 @@ pub struct Class {
     pub methods: Vec<Method>,
     /// Constants of the class.
     pub constants: Vec<Constant>,
+    /// Class flags, see [`ClassFlags`].
+    pub flags: u32,
+}
+
+impl Class {
+    /// Returns `true` if the class is an interface.
+    #[must_use]
+    pub fn is_interface(&self) -> bool {
+        ClassFlags::from_bits_truncate(self.flags).contains(ClassFlags::Interface)
+    }
+
+    /// Returns `true` if the class is abstract, either explicitly or because it
+    /// declares abstract methods.
+    #[must_use]
+    pub fn is_abstract(&self) -> bool {
+        !self.is_interface()
+            && (ClassFlags::from_bits_truncate(self.flags).contains(ClassFlags::Abstract)
+                || self.methods.iter().any(|method| method.r#abstract))
+    }
 }
 
 #[cfg(feature = "closure")]
@@ -225,9 +245,11 @@ impl Class {
                 }),
                 r#static: false,
                 visibility: Visibility::Public,
+                r#abstract: false,
             }]
             .into(),
             constants: StdVec::new().into(),
+            flags: 0,
         }
     }
 }
@@ -253,7 +275,13 @@ impl From<ClassBuilder> for Class {
             properties: val
                 .properties
                 .into_iter()
-                .map(Property::from)
+                .map(Property::from_entry)
+                .chain(
+                    val.static_properties
+                        .into_iter()
+                        .map(|(name, _, flags, docs)| (name, flags | PropertyFlags::Static, docs))
+                        .map(Property::from),
+                )
                 .collect::<StdVec<_>>()
                 .into(),
             methods: val
@@ -269,6 +297,7 @@ impl From<ClassBuilder> for Class {
                 .map(Constant::from)
                 .collect::<StdVec<_>>()
                 .into(),
+            flags: val.ce.ce_flags,
         }
     }
 }
@@ -361,15 +390,17 @@ pub struct Property {
     pub name: RString,
     /// Documentation comments for the property.
     pub docs: DocBlock,
-    /// Type of the property (Not implemented #376)
+    /// Type of the property.
     pub ty: Option<DataType>,
     /// Visibility of the property.
     pub vis: Visibility,
     /// Whether the property is static.
     pub static_: bool,
-    /// Whether the property is nullable. (Not implemented #376)
+    /// Whether the property is readonly.
+    pub readonly: bool,
+    /// Whether the property is nullable.
     pub nullable: bool,
-    /// Default value of the property. (Not implemented #376)
+    /// Default value of the property.
     pub default: Option<RString>,
 }
 
@@ -377,12 +408,13 @@ impl From<(String, PropertyFlags, DocComments)> for Property {
     fn from(value: (String, PropertyFlags, DocComments)) -> Self {
         let (name, flags, docs) = value;
         let static_ = flags.contains(PropertyFlags::Static);
+        #[cfg(php81)]
+        let readonly = flags.contains(PropertyFlags::Readonly);
+        #[cfg(not(php81))]
+        let readonly = false;
         let vis = Visibility::from(flags);
-        // TODO: Implement ty #376
         let ty = abi::Option::None;
-        // TODO: Implement default #376
         let default = abi::Option::<abi::RString>::None;
-        // TODO: Implement nullable #376
         let nullable = false;
         let docs = docs.into();
 
@@ -392,12 +424,34 @@ impl From<(String, PropertyFlags, DocComments)> for Property {
             ty,
             vis,
             static_,
+            readonly,
             nullable,
             default,
         }
     }
 }
 
+impl Property {
+    fn from_entry(value: PropertyEntry) -> Self {
+        let PropertyEntry {
+            name,
+            flags,
+            ty,
+            default,
+            docs,
+        } = value;
+        let mut property = Self::from((name, flags, docs));
+        if let Some((ty, nullable)) = ty {
+            property.ty = abi::Option::Some(ty);
+            property.nullable = nullable && ty != DataType::Mixed;
+        }
+        if let Some((_, stub)) = default {
+            property.default = abi::Option::Some(stub.into());
+        }
+        property
+    }
+}
+
 /// Represents a method attached to an exported class.
 #[repr(C)]
 #[derive(Debug, PartialEq)]
@@ -416,6 +470,8 @@ pub struct Method {
     pub r#static: bool,
     /// Visibility of the method.
     pub visibility: Visibility,
+    /// Whether the method is abstract.
+    pub r#abstract: bool,
 }
 
 impl From<(FunctionBuilder<'_>, MethodFlags)> for Method {
@@ -436,7 +492,7 @@ impl From<(FunctionBuilder<'_>, MethodFlags)> for Method {
                 .retval
                 .map(|r| Retval {
                     ty: r,
-                    nullable: r != DataType::Mixed && ret_allow_null,
+                    nullable: !r.accepts_null() && ret_allow_null,
                 })
                 .into(),
             params: builder
@@ -448,6 +504,7 @@ impl From<(FunctionBuilder<'_>, MethodFlags)> for Method {
             ty: flags.into(),
             r#static: flags.contains(MethodFlags::Static),
             visibility: flags.into(),
+            r#abstract: flags.contains(MethodFlags::Abstract),
         }
     }
 }
@@ -670,6 +727,7 @@ mod tests {
                 ty: Option::None,
                 vis: Visibility::Public,
                 static_: false,
+                readonly: false,
                 nullable: false,
                 default: Option::None,
             }
@@ -685,8 +743,70 @@ mod tests {
                 retval: Option::None,
                 r#static: false,
                 visibility: Visibility::Protected,
+                r#abstract: false,
             }
         );
+        assert!(!class.is_interface());
+    }
+
+    #[test]
+    fn test_class_property_default_from() {
+        let builder = ClassBuilder::new("TestClass")
+            .typed_property("count", DataType::Long, false, PropertyFlags::Public, &[])
+            .property_default("count", 5, "5");
+        let class: Class = builder.into();
+
+        assert_eq!(class.properties[0].ty, Option::Some(DataType::Long));
+        assert_eq!(class.properties[0].default, Option::Some("5".into()));
+    }
+
+    #[test]
+    fn test_interface_from() {
+        let builder = ClassBuilder::new("TestInterface")
+            .flags(ClassFlags::Interface)
+            .implements((|| todo!(), "Countable"))
+            .method(
+                FunctionBuilder::new_abstract("count"),
+                MethodFlags::Public | MethodFlags::Abstract,
+            );
+        let class: Class = builder.into();
+
+        assert!(class.is_interface());
+        assert_eq!(class.implements, vec!["Countable".into()].into());
+        assert_eq!(class.methods.len(), 1);
+        assert!(class.methods[0].r#abstract);
+    }
+
+    #[test]
+    fn test_class_static_property_from() {
+        let builder = ClassBuilder::new("TestClass").static_property(
+            "count",
+            0,
+            PropertyFlags::Protected,
+            &["doc1"],
+        );
+        let class: Class = builder.into();
+
+        assert_eq!(class.properties.len(), 1);
+        assert_eq!(class.properties[0].name, "count".into());
+        assert_eq!(class.properties[0].vis, Visibility::Protected);
+        assert!(class.properties[0].static_);
+    }
+
+    #[test]
+    fn test_abstract_class_from() {
+        let builder = ClassBuilder::new("TestAbstract").method(
+            FunctionBuilder::new_abstract("area"),
+            MethodFlags::Public | MethodFlags::Abstract,
+        );
+        let class: Class = builder.into();
+        assert!(class.is_abstract());
+
+        let class: Class = ClassBuilder::new("TestAbstract")
+            .flags(ClassFlags::Abstract)
+            .into();
+        assert!(class.is_abstract());
+        assert!(!class.is_interface());
     }
 
     #[test]
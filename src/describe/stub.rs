@@ -146,10 +146,7 @@ impl ToStub for Function {
 
         if let Option::Some(retval) = &self.ret {
             write!(buf, ": ")?;
-            if retval.nullable {
-                write!(buf, "?")?;
-            }
-            retval.ty.fmt_stub(buf)?;
+            fmt_type_stub(&retval.ty, retval.nullable, buf)?;
         }
 
         writeln!(buf, " {{}}")
@@ -159,11 +156,7 @@ impl ToStub for Function {
 impl ToStub for Parameter {
     fn fmt_stub(&self, buf: &mut String) -> FmtResult {
         if let Option::Some(ty) = &self.ty {
-            if self.nullable {
-                write!(buf, "?")?;
-            }
-
-            ty.fmt_stub(buf)?;
+            fmt_type_stub(ty, self.nullable, buf)?;
             write!(buf, " ")?;
         }
 
@@ -175,8 +168,53 @@ impl ToStub for Parameter {
     }
 }
 
+/// Writes a parameter, property or return type, which is prefixed by `?` if it
+/// is nullable, or followed by `|null` if it is a union type.
+fn fmt_type_stub(ty: &DataType, nullable: bool, buf: &mut String) -> FmtResult {
+    match ty {
+        DataType::Union(_) if nullable => {
+            ty.fmt_stub(buf)?;
+            write!(buf, "|null")
+        }
+        _ => {
+            if nullable {
+                write!(buf, "?")?;
+            }
+            ty.fmt_stub(buf)
+        }
+    }
+}
+
 impl ToStub for DataType {
     fn fmt_stub(&self, buf: &mut String) -> FmtResult {
+        match self {
+            DataType::Union(types) => {
+                // Types are only written once, e.g. when an enum has multiple
+                // variants converted into strings.
+                let mut written = StdVec::new();
+                for ty in *types {
+                    let stub = match ty {
+                        DataType::Intersection(_) => format!("({})", ty.to_stub()?),
+                        _ => ty.to_stub()?,
+                    };
+                    if !written.contains(&stub) {
+                        written.push(stub);
+                    }
+                }
+                return write!(buf, "{}", written.join("|"));
+            }
+            DataType::Intersection(classes) => {
+                for (i, class) in classes.iter().enumerate() {
+                    if i > 0 {
+                        write!(buf, "&")?;
+                    }
+                    write!(buf, "\\{class}")?;
+                }
+                return Ok(());
+            }
+            _ => {}
+        }
+
         let mut fqdn = "\\".to_owned();
         write!(
             buf,
@@ -196,6 +234,10 @@ impl ToStub for DataType {
                 DataType::Reference => "reference",
                 DataType::Callable => "callable",
                 DataType::Iterable => "iterable",
+                DataType::Null => "null",
+                DataType::Static => "static",
+                DataType::Self_ => "self",
+                DataType::Never => "never",
                 _ => "mixed",
             }
         )
@@ -226,7 +268,13 @@ impl ToStub for Class {
         self.docs.fmt_stub(buf)?;
 
         let (_, name) = split_namespace(self.name.as_ref());
-        write!(buf, "class {name} ")?;
+        if self.is_interface() {
+            write!(buf, "interface {name} ")?;
+        } else if self.is_abstract() {
+            write!(buf, "abstract class {name} ")?;
+        } else {
+            write!(buf, "class {name} ")?;
+        }
 
         if let Option::Some(extends) = &self.extends {
             write!(buf, "extends {extends} ")?;
@@ -235,7 +283,12 @@ impl ToStub for Class {
         if !self.implements.is_empty() {
             write!(
                 buf,
-                "implements {} ",
+                "{} {} ",
+                if self.is_interface() {
+                    "extends"
+                } else {
+                    "implements"
+                },
                 self.implements
                     .iter()
                     .map(RString::as_str)
@@ -246,10 +299,16 @@ impl ToStub for Class {
 
         writeln!(buf, "{{")?;
 
+        let methods = self.methods.iter().map(|method| {
+            let mut buf = String::new();
+            method.fmt_member_stub(&mut buf, self.is_interface())?;
+            Ok(indent(&buf, 4))
+        });
+
         buf.push_str(
             &stub(&self.constants)
                 .chain(stub(&self.properties))
-                .chain(stub(&self.methods))
+                .chain(methods)
                 .collect::<Result<StdVec<_>, FmtError>>()?
                 .join(NEW_LINE_SEPARATOR),
         );
@@ -303,8 +362,12 @@ impl ToStub for Property {
         if self.static_ {
             write!(buf, "static ")?;
         }
+        if self.readonly {
+            write!(buf, "readonly ")?;
+        }
         if let Option::Some(ty) = &self.ty {
-            ty.fmt_stub(buf)?;
+            fmt_type_stub(ty, self.nullable, buf)?;
+            write!(buf, " ")?;
         }
         write!(buf, "${}", self.name)?;
         if let Option::Some(default) = &self.default {
@@ -330,11 +393,23 @@ impl ToStub for Visibility {
 
 impl ToStub for Method {
     fn fmt_stub(&self, buf: &mut String) -> FmtResult {
+        self.fmt_member_stub(buf, false)
+    }
+}
+
+impl Method {
+    /// Writes the method stub. Methods declared on an interface are implicitly
+    /// abstract, so the `abstract` keyword is omitted for them.
+    fn fmt_member_stub(&self, buf: &mut String, interface: bool) -> FmtResult {
         self.docs.fmt_stub(buf)?;
         self.visibility.fmt_stub(buf)?;
 
         write!(buf, " ")?;
 
+        if self.r#abstract && !interface {
+            write!(buf, "abstract ")?;
+        }
+
         if matches!(self.ty, MethodType::Static) {
             write!(buf, "static ")?;
         }
@@ -353,14 +428,15 @@ impl ToStub for Method {
         if !matches!(self.ty, MethodType::Constructor) {
             if let Option::Some(retval) = &self.retval {
                 write!(buf, ": ")?;
-                if retval.nullable {
-                    write!(buf, "?")?;
-                }
-                retval.ty.fmt_stub(buf)?;
+                fmt_type_stub(&retval.ty, retval.nullable, buf)?;
             }
         }
 
-        writeln!(buf, " {{}}")
+        if self.r#abstract {
+            writeln!(buf, ";")
+        } else {
+            writeln!(buf, " {{}}")
+        }
     }
 }
 
@@ -451,4 +527,42 @@ mod test {
             format!("    hello{nl}    world{nl}", nl = NEW_LINE_SEPARATOR)
         );
     }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    pub fn test_union_and_intersection_types() {
+        use super::ToStub;
+        use crate::{args::Arg, describe::Parameter, flags::DataType};
+
+        const UNION: DataType = DataType::Union(&[DataType::Long, DataType::String]);
+
+        assert_eq!(UNION.to_stub().unwrap(), "int|string");
+        assert_eq!(
+            DataType::Intersection(&["Countable", "Traversable"])
+                .to_stub()
+                .unwrap(),
+            "\\Countable&\\Traversable"
+        );
+        assert_eq!(
+            DataType::Union(&[
+                DataType::Intersection(&["Countable", "Traversable"]),
+                DataType::Null
+            ])
+            .to_stub()
+            .unwrap(),
+            "(\\Countable&\\Traversable)|null"
+        );
+        assert_eq!(
+            Parameter::from(Arg::new("value", UNION).allow_null())
+                .to_stub()
+                .unwrap(),
+            "int|string|null $value"
+        );
+        assert_eq!(
+            Parameter::from(Arg::new("value", DataType::Long).allow_null())
+                .to_stub()
+                .unwrap(),
+            "?int $value"
+        );
+    }
 }
@@ -29,6 +29,19 @@ pub enum Error {
     /// number of arguments expected, and the second representing the number of
     /// arguments that were received.
     IncorrectArguments(usize, usize),
+    /// A named argument was given to a PHP function which has no parameter of
+    /// that name.
+    ///
+    /// The enum carries the name of the argument.
+    UnknownNamedArgument(String),
+    /// The value of a named argument collected by a named rest argument could
+    /// not be converted into the type of the rest argument.
+    ///
+    /// The enum carries the name of the argument.
+    InvalidNamedArgument(String),
+    /// A positional argument was added after a named argument when building
+    /// the arguments of a call.
+    PositionalAfterNamedArgument,
     /// There was an error converting a Zval into a primitive type.
     ///
     /// The enum carries the data type of the Zval.
@@ -60,6 +73,10 @@ pub enum Error {
     Callable,
     /// An object was expected.
     Object,
+    /// The class with the given name does not exist.
+    ///
+    /// The enum carries the name of the class.
+    UnknownClass(String),
     /// An invalid exception type was thrown.
     InvalidException(ClassFlags),
     /// Converting integer arguments resulted in an overflow.
@@ -70,6 +87,12 @@ pub enum Error {
     StreamWrapperRegistrationFailure,
     /// A failure occurred while unregistering the stream wrapper
     StreamWrapperUnregistrationFailure,
+    /// A failure occurred while registering a resource type
+    ResourceTypeRegistrationFailure,
+    /// A lifecycle hook of the extension failed.
+    ///
+    /// The enum carries the error message of the hook.
+    LifecycleHook(String),
 }
 
 impl Display for Error {
@@ -79,6 +102,13 @@ impl Display for Error {
                 f,
                 "Expected at least {expected} arguments, got {n} arguments."
             ),
+            Error::UnknownNamedArgument(name) => write!(f, "Unknown named parameter ${name}"),
+            Error::InvalidNamedArgument(name) => {
+                write!(f, "Invalid value given for named argument ${name}.")
+            }
+            Error::PositionalAfterNamedArgument => {
+                write!(f, "Cannot use positional argument after named argument.")
+            }
             Error::ZvalConversion(ty) => write!(
                 f,
                 "Could not convert Zval from type {ty} into primitive type."
@@ -97,6 +127,7 @@ impl Display for Error {
             Error::InvalidUtf8 => write!(f, "Invalid Utf8 byte sequence."),
             Error::Callable => write!(f, "Could not call given function."),
             Error::Object => write!(f, "An object was expected."),
+            Error::UnknownClass(name) => write!(f, "Class \"{name}\" does not exist."),
             Error::InvalidException(flags) => {
                 write!(f, "Invalid exception type was thrown: {flags:?}")
             }
@@ -113,6 +144,10 @@ impl Display for Error {
                     "A failure occurred while unregistering the stream wrapper"
                 )
             }
+            Error::ResourceTypeRegistrationFailure => {
+                write!(f, "A failure occurred while registering a resource type")
+            }
+            Error::LifecycleHook(e) => write!(f, "Lifecycle hook failed: {e}"),
         }
     }
 }
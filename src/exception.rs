@@ -131,6 +131,13 @@ impl From<anyhow::Error> for PhpException {
     }
 }
 
+#[cfg(feature = "serde")]
+impl From<crate::serde::Error> for PhpException {
+    fn from(err: crate::serde::Error) -> Self {
+        Self::new(err.to_string(), 0, crate::zend::ce::exception())
+    }
+}
+
 /// Throws an exception with a given message. See [`ClassEntry`] for some
 /// built-in exception types.
 ///
@@ -4,6 +4,8 @@ use bitflags::bitflags;
 
 #[cfg(php81)]
 use crate::ffi::ZEND_ACC_ENUM;
+#[cfg(php81)]
+use crate::ffi::ZEND_ACC_READONLY;
 #[cfg(not(php82))]
 use crate::ffi::ZEND_ACC_REUSE_GET_ITERATOR;
 use crate::ffi::{
@@ -12,20 +14,20 @@ use crate::ffi::{
     E_RECOVERABLE_ERROR, E_STRICT, E_USER_DEPRECATED, E_USER_ERROR, E_USER_NOTICE, E_USER_WARNING,
     E_WARNING, IS_ARRAY, IS_CALLABLE, IS_CONSTANT_AST, IS_DOUBLE, IS_FALSE, IS_INDIRECT,
     IS_ITERABLE, IS_LONG, IS_MIXED, IS_NULL, IS_OBJECT, IS_PTR, IS_REFERENCE, IS_RESOURCE,
-    IS_STRING, IS_TRUE, IS_TYPE_COLLECTABLE, IS_TYPE_REFCOUNTED, IS_UNDEF, IS_VOID, PHP_INI_ALL,
-    PHP_INI_PERDIR, PHP_INI_SYSTEM, PHP_INI_USER, ZEND_ACC_ABSTRACT, ZEND_ACC_ANON_CLASS,
-    ZEND_ACC_CALL_VIA_TRAMPOLINE, ZEND_ACC_CHANGED, ZEND_ACC_CLOSURE, ZEND_ACC_CONSTANTS_UPDATED,
-    ZEND_ACC_CTOR, ZEND_ACC_DEPRECATED, ZEND_ACC_DONE_PASS_TWO, ZEND_ACC_EARLY_BINDING,
-    ZEND_ACC_FAKE_CLOSURE, ZEND_ACC_FINAL, ZEND_ACC_GENERATOR, ZEND_ACC_HAS_FINALLY_BLOCK,
-    ZEND_ACC_HAS_RETURN_TYPE, ZEND_ACC_HAS_TYPE_HINTS, ZEND_ACC_HEAP_RT_CACHE, ZEND_ACC_IMMUTABLE,
-    ZEND_ACC_IMPLICIT_ABSTRACT_CLASS, ZEND_ACC_INTERFACE, ZEND_ACC_LINKED, ZEND_ACC_NEARLY_LINKED,
-    ZEND_ACC_NEVER_CACHE, ZEND_ACC_NO_DYNAMIC_PROPERTIES, ZEND_ACC_PRELOADED, ZEND_ACC_PRIVATE,
-    ZEND_ACC_PROMOTED, ZEND_ACC_PROTECTED, ZEND_ACC_PUBLIC, ZEND_ACC_RESOLVED_INTERFACES,
-    ZEND_ACC_RESOLVED_PARENT, ZEND_ACC_RETURN_REFERENCE, ZEND_ACC_STATIC, ZEND_ACC_STRICT_TYPES,
-    ZEND_ACC_TOP_LEVEL, ZEND_ACC_TRAIT, ZEND_ACC_TRAIT_CLONE, ZEND_ACC_UNRESOLVED_VARIANCE,
-    ZEND_ACC_USES_THIS, ZEND_ACC_USE_GUARDS, ZEND_ACC_VARIADIC, ZEND_EVAL_CODE,
-    ZEND_HAS_STATIC_IN_METHODS, ZEND_INTERNAL_FUNCTION, ZEND_USER_FUNCTION, Z_TYPE_FLAGS_SHIFT,
-    _IS_BOOL,
+    IS_STATIC, IS_STRING, IS_TRUE, IS_TYPE_COLLECTABLE, IS_TYPE_REFCOUNTED, IS_UNDEF, IS_VOID,
+    PHP_INI_ALL, PHP_INI_PERDIR, PHP_INI_SYSTEM, PHP_INI_USER, ZEND_ACC_ABSTRACT,
+    ZEND_ACC_ANON_CLASS, ZEND_ACC_CALL_VIA_TRAMPOLINE, ZEND_ACC_CHANGED, ZEND_ACC_CLOSURE,
+    ZEND_ACC_CONSTANTS_UPDATED, ZEND_ACC_CTOR, ZEND_ACC_DEPRECATED, ZEND_ACC_DONE_PASS_TWO,
+    ZEND_ACC_EARLY_BINDING, ZEND_ACC_FAKE_CLOSURE, ZEND_ACC_FINAL, ZEND_ACC_GENERATOR,
+    ZEND_ACC_HAS_FINALLY_BLOCK, ZEND_ACC_HAS_RETURN_TYPE, ZEND_ACC_HAS_TYPE_HINTS,
+    ZEND_ACC_HEAP_RT_CACHE, ZEND_ACC_IMMUTABLE, ZEND_ACC_IMPLICIT_ABSTRACT_CLASS,
+    ZEND_ACC_INTERFACE, ZEND_ACC_LINKED, ZEND_ACC_NEARLY_LINKED, ZEND_ACC_NEVER_CACHE,
+    ZEND_ACC_NO_DYNAMIC_PROPERTIES, ZEND_ACC_PRELOADED, ZEND_ACC_PRIVATE, ZEND_ACC_PROMOTED,
+    ZEND_ACC_PROTECTED, ZEND_ACC_PUBLIC, ZEND_ACC_RESOLVED_INTERFACES, ZEND_ACC_RESOLVED_PARENT,
+    ZEND_ACC_RETURN_REFERENCE, ZEND_ACC_STATIC, ZEND_ACC_STRICT_TYPES, ZEND_ACC_TOP_LEVEL,
+    ZEND_ACC_TRAIT, ZEND_ACC_TRAIT_CLONE, ZEND_ACC_UNRESOLVED_VARIANCE, ZEND_ACC_USES_THIS,
+    ZEND_ACC_USE_GUARDS, ZEND_ACC_VARIADIC, ZEND_EVAL_CODE, ZEND_HAS_STATIC_IN_METHODS,
+    ZEND_INTERNAL_FUNCTION, ZEND_USER_FUNCTION, Z_TYPE_FLAGS_SHIFT, _IS_BOOL,
 };
 
 use std::{convert::TryFrom, fmt::Display};
@@ -234,6 +236,9 @@ bitflags! {
         const Static = ZEND_ACC_STATIC;
         /// Promoted property
         const Promoted = ZEND_ACC_PROMOTED;
+        /// Readonly property
+        #[cfg(php81)]
+        const Readonly = ZEND_ACC_READONLY;
     }
 }
 
@@ -395,6 +400,19 @@ pub enum DataType {
     Ptr,
     /// Indirect (internal)
     Indirect,
+    /// Union of types, e.g. `int|string`. Nested unions are flattened.
+    Union(&'static [DataType]),
+    /// Intersection of classes or interfaces, e.g. `Countable&Traversable`.
+    ///
+    /// Intersection types are declared as `object` before PHP 8.3, and cannot
+    /// be nullable.
+    Intersection(&'static [&'static str]),
+    /// `static`, only valid as a return type.
+    Static,
+    /// `self`, the class declaring the method.
+    Self_,
+    /// `never`, only valid as a return type. Declared as `void` on PHP 8.0.
+    Never,
 }
 
 impl Default for DataType {
@@ -416,16 +434,58 @@ impl DataType {
             DataType::Double => IS_DOUBLE,
             DataType::String => IS_STRING,
             DataType::Array => IS_ARRAY,
-            DataType::Object(_) => IS_OBJECT,
+            DataType::Object(_) | DataType::Intersection(_) | DataType::Self_ => IS_OBJECT,
             DataType::Resource | DataType::Reference => IS_RESOURCE,
             DataType::Indirect => IS_INDIRECT,
             DataType::Callable => IS_CALLABLE,
             DataType::ConstantExpression => IS_CONSTANT_AST,
             DataType::Void => IS_VOID,
-            DataType::Mixed => IS_MIXED,
+            DataType::Mixed | DataType::Union(_) => IS_MIXED,
             DataType::Bool => _IS_BOOL,
             DataType::Ptr => IS_PTR,
             DataType::Iterable => IS_ITERABLE,
+            DataType::Static => IS_STATIC,
+            #[cfg(php81)]
+            DataType::Never => crate::ffi::IS_NEVER,
+            #[cfg(not(php81))]
+            DataType::Never => IS_VOID,
+        }
+    }
+
+    /// Returns the union of the given types, or [`DataType::Mixed`] if any of
+    /// them is `mixed`, which cannot be part of a union.
+    #[must_use]
+    pub const fn union(types: &'static [DataType]) -> Self {
+        if Self::contains_mixed(types) {
+            DataType::Mixed
+        } else {
+            DataType::Union(types)
+        }
+    }
+
+    /// Returns true if any of the types, including the members of nested
+    /// unions, is `mixed`.
+    const fn contains_mixed(types: &[DataType]) -> bool {
+        let mut i = 0;
+        while i < types.len() {
+            match &types[i] {
+                DataType::Mixed => return true,
+                DataType::Union(inner) if Self::contains_mixed(inner) => return true,
+                _ => {}
+            }
+            i += 1;
+        }
+        false
+    }
+
+    /// Returns true if the data type accepts `null`, i.e. it is `null`, `mixed`
+    /// or a union containing `null`.
+    #[must_use]
+    pub fn accepts_null(&self) -> bool {
+        match self {
+            DataType::Null | DataType::Mixed => true,
+            DataType::Union(types) => types.iter().any(DataType::accepts_null),
+            _ => false,
         }
     }
 }
@@ -533,6 +593,19 @@ impl Display for DataType {
             DataType::Ptr => write!(f, "Pointer"),
             DataType::Indirect => write!(f, "Indirect"),
             DataType::Iterable => write!(f, "Iterable"),
+            DataType::Union(types) => {
+                for (i, type_) in types.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, "|")?;
+                    }
+                    write!(f, "{type_}")?;
+                }
+                Ok(())
+            }
+            DataType::Intersection(classes) => write!(f, "{}", classes.join("&")),
+            DataType::Static => write!(f, "Static"),
+            DataType::Self_ => write!(f, "Self"),
+            DataType::Never => write!(f, "Never"),
         }
     }
 }
@@ -581,4 +654,18 @@ mod tests {
         test!(IS_REFERENCE_EX, Reference);
         test!(IS_CONSTANT_AST_EX, ConstantExpression);
     }
+
+    #[test]
+    fn test_union() {
+        const TYPES: &[DataType] = &[DataType::Long, DataType::String];
+        assert_eq!(DataType::union(TYPES), DataType::Union(TYPES));
+        assert_eq!(
+            DataType::union(&[DataType::Long, DataType::Mixed]),
+            DataType::Mixed
+        );
+        assert_eq!(
+            DataType::union(&[DataType::Long, DataType::Union(&[DataType::Mixed])]),
+            DataType::Mixed
+        );
+    }
 }
@@ -0,0 +1,248 @@
+//! Support for the PHP cycle collector.
+//!
+//! PHP frees most values through reference counting, and relies on a cycle
+//! collector to free values which reference each other. The collector only
+//! sees the values an object reports through its `get_gc` handler, so PHP
+//! values held by a Rust class, e.g. a callback referring back to the object,
+//! form cycles which are never freed.
+//!
+//! Implementing [`GcTraverse`] on a type exported with [`macro@php_class`]
+//! reports the PHP values held by the Rust struct to the collector. The trait
+//! can be derived for structs and enums whose fields all implement it.
+//!
+//! [`macro@php_class`]: crate::php_class
+
+use std::{
+    cell::RefCell,
+    collections::{BTreeMap, HashMap, VecDeque},
+    ptr,
+    rc::Rc,
+    sync::Arc,
+};
+
+use crate::{
+    boxed::{ZBox, ZBoxable},
+    ffi::{zend_get_gc_buffer, zend_get_gc_buffer_grow},
+    flags::ZvalTypeFlags,
+    types::{ZendObject, Zval},
+};
+
+/// Implemented on types which hold PHP values, to report them to the cycle
+/// collector.
+///
+/// The trait is implemented for [`Zval`], [`ZendObject`] and the common
+/// containers and scalar types, and can be derived with
+/// [`derive@GcTraverse`](crate::GcTraverse).
+pub trait GcTraverse {
+    /// Adds the PHP values held by `self` to the buffer.
+    ///
+    /// # Parameters
+    ///
+    /// * `buffer` - The buffer the values are added to.
+    fn gc_traverse(&self, buffer: &mut GcBuffer);
+}
+
+/// Buffer collecting the PHP values held by an object for the cycle collector.
+pub struct GcBuffer<'a> {
+    buffer: &'a mut zend_get_gc_buffer,
+}
+
+impl<'a> GcBuffer<'a> {
+    /// Creates a buffer adding values to the given Zend buffer.
+    pub(crate) fn new(buffer: &'a mut zend_get_gc_buffer) -> Self {
+        Self { buffer }
+    }
+
+    /// Adds a value to the buffer. Values which are not reference counted are
+    /// ignored.
+    ///
+    /// # Parameters
+    ///
+    /// * `zval` - The value to add.
+    pub fn add_zval(&mut self, zval: &Zval) {
+        // SAFETY: `u1` union is only used for easier bitmasking. It is valid to read
+        // from either of the variants.
+        let flags = unsafe { ZvalTypeFlags::from_bits_retain(zval.u1.type_info) };
+        if flags.contains(ZvalTypeFlags::RefCounted) {
+            // SAFETY: The buffer only holds a copy of the value, which is not dropped.
+            unsafe { ptr::copy_nonoverlapping(zval, self.next(), 1) };
+        }
+    }
+
+    /// Adds an object to the buffer.
+    ///
+    /// # Parameters
+    ///
+    /// * `object` - The object to add.
+    pub fn add_object(&mut self, object: &ZendObject) {
+        let mut zval = Zval::new();
+        zval.u1.type_info = ZvalTypeFlags::ObjectEx.bits();
+        zval.value.obj = ptr::from_ref(object).cast_mut();
+        // SAFETY: The buffer holds the object without taking a reference, as Zend
+        // does for objects added to the buffer.
+        unsafe { ptr::write(self.next(), zval) };
+    }
+
+    /// Returns the next free slot of the buffer and advances the buffer past it.
+    fn next(&mut self) -> *mut Zval {
+        if self.buffer.cur == self.buffer.end {
+            // SAFETY: The buffer is a valid Zend buffer.
+            unsafe { zend_get_gc_buffer_grow(self.buffer) };
+        }
+        let slot = self.buffer.cur;
+        // SAFETY: The buffer has at least one free slot.
+        self.buffer.cur = unsafe { slot.add(1) };
+        slot
+    }
+}
+
+impl GcTraverse for Zval {
+    #[inline]
+    fn gc_traverse(&self, buffer: &mut GcBuffer) {
+        buffer.add_zval(self);
+    }
+}
+
+impl GcTraverse for ZendObject {
+    #[inline]
+    fn gc_traverse(&self, buffer: &mut GcBuffer) {
+        buffer.add_object(self);
+    }
+}
+
+impl<T: GcTraverse + ZBoxable> GcTraverse for ZBox<T> {
+    #[inline]
+    fn gc_traverse(&self, buffer: &mut GcBuffer) {
+        (**self).gc_traverse(buffer);
+    }
+}
+
+impl<T: GcTraverse + ?Sized> GcTraverse for &T {
+    #[inline]
+    fn gc_traverse(&self, buffer: &mut GcBuffer) {
+        (**self).gc_traverse(buffer);
+    }
+}
+
+impl<T: GcTraverse + ?Sized> GcTraverse for Box<T> {
+    #[inline]
+    fn gc_traverse(&self, buffer: &mut GcBuffer) {
+        (**self).gc_traverse(buffer);
+    }
+}
+
+impl<T: GcTraverse + ?Sized> GcTraverse for Rc<T> {
+    #[inline]
+    fn gc_traverse(&self, buffer: &mut GcBuffer) {
+        (**self).gc_traverse(buffer);
+    }
+}
+
+impl<T: GcTraverse + ?Sized> GcTraverse for Arc<T> {
+    #[inline]
+    fn gc_traverse(&self, buffer: &mut GcBuffer) {
+        (**self).gc_traverse(buffer);
+    }
+}
+
+impl<T: GcTraverse + ?Sized> GcTraverse for RefCell<T> {
+    /// Values of a cell which is mutably borrowed are not reported.
+    #[inline]
+    fn gc_traverse(&self, buffer: &mut GcBuffer) {
+        if let Ok(value) = self.try_borrow() {
+            value.gc_traverse(buffer);
+        }
+    }
+}
+
+impl<T: GcTraverse> GcTraverse for Option<T> {
+    #[inline]
+    fn gc_traverse(&self, buffer: &mut GcBuffer) {
+        if let Some(value) = self {
+            value.gc_traverse(buffer);
+        }
+    }
+}
+
+impl<T: GcTraverse> GcTraverse for [T] {
+    #[inline]
+    fn gc_traverse(&self, buffer: &mut GcBuffer) {
+        for value in self {
+            value.gc_traverse(buffer);
+        }
+    }
+}
+
+impl<T: GcTraverse, const N: usize> GcTraverse for [T; N] {
+    #[inline]
+    fn gc_traverse(&self, buffer: &mut GcBuffer) {
+        self.as_slice().gc_traverse(buffer);
+    }
+}
+
+impl<T: GcTraverse> GcTraverse for Vec<T> {
+    #[inline]
+    fn gc_traverse(&self, buffer: &mut GcBuffer) {
+        self.as_slice().gc_traverse(buffer);
+    }
+}
+
+impl<T: GcTraverse> GcTraverse for VecDeque<T> {
+    #[inline]
+    fn gc_traverse(&self, buffer: &mut GcBuffer) {
+        for value in self {
+            value.gc_traverse(buffer);
+        }
+    }
+}
+
+impl<K, V: GcTraverse, S> GcTraverse for HashMap<K, V, S> {
+    #[inline]
+    fn gc_traverse(&self, buffer: &mut GcBuffer) {
+        for value in self.values() {
+            value.gc_traverse(buffer);
+        }
+    }
+}
+
+impl<K, V: GcTraverse> GcTraverse for BTreeMap<K, V> {
+    #[inline]
+    fn gc_traverse(&self, buffer: &mut GcBuffer) {
+        for value in self.values() {
+            value.gc_traverse(buffer);
+        }
+    }
+}
+
+macro_rules! gc_traverse_noop {
+    ($($ty: ty),*) => {
+        $(
+            impl GcTraverse for $ty {
+                #[inline]
+                fn gc_traverse(&self, _: &mut GcBuffer) {}
+            }
+        )*
+    };
+}
+
+gc_traverse_noop!(
+    (),
+    bool,
+    char,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    f32,
+    f64,
+    str,
+    String
+);
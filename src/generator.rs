@@ -0,0 +1,265 @@
+//! Types and functions used for exporting lazy Rust iterators to PHP.
+
+use std::collections::HashMap;
+
+use crate::{
+    builders::{ClassBuilder, FunctionBuilder},
+    class::{ClassEntryInfo, ClassMetadata, RegisteredClass},
+    convert::IntoZval,
+    describe::DocComments,
+    error::Result,
+    exception::PhpException,
+    flags::{ClassFlags, DataType, MethodFlags},
+    internal::property::PropertyInfo,
+    types::Zval,
+    zend::{ce, ExecuteData},
+    zend_fastcall,
+};
+
+/// Class entry and handlers for Rust generators.
+static GENERATOR_META: ClassMetadata<Generator> = ClassMetadata::new();
+
+/// Wrapper around a Rust iterator, which can be exported to PHP.
+///
+/// Unlike [`Vec`], which is converted into a PHP array holding all elements,
+/// the elements of a generator are only produced while PHP iterates over it,
+/// e.g. when streaming rows from a database cursor. The iterator must have a
+/// static lifetime, and its elements must implement [`IntoZval`].
+///
+/// Internally, generators are implemented as a PHP class. A class
+/// `RustGenerator` implementing `Iterator` is registered:
+///
+/// ```php
+/// <?php
+///
+/// final class RustGenerator implements Iterator {
+///     public function current(): mixed {}
+///     public function key(): mixed {}
+///     public function next(): void {}
+///     public function valid(): bool {}
+///     public function rewind(): void {}
+/// }
+/// ```
+///
+/// Like PHP generators, a generator can only be iterated once, rewinding it
+/// after it has advanced throws an exception.
+pub struct Generator {
+    iter: Box<dyn Iterator<Item = Result<(Zval, Zval)>>>,
+    current: Option<(Zval, Zval)>,
+    started: bool,
+    advanced: bool,
+}
+
+unsafe impl Send for Generator {}
+unsafe impl Sync for Generator {}
+
+impl Generator {
+    /// Wraps a Rust iterator into a type which can be returned to PHP. The
+    /// keys of the elements are their positions, starting from 0.
+    ///
+    /// # Parameters
+    ///
+    /// * `iter` - The iterator to wrap.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use ext_php_rs::generator::Generator;
+    ///
+    /// let squares = Generator::new((1..=1_000_000_i64).map(|i| i * i));
+    /// ```
+    pub fn new<I>(iter: I) -> Self
+    where
+        I: IntoIterator + 'static,
+        I::Item: IntoZval,
+    {
+        Self::with_keys(iter.into_iter().enumerate())
+    }
+
+    /// Wraps a Rust iterator over key-value pairs into a type which can be
+    /// returned to PHP.
+    ///
+    /// # Parameters
+    ///
+    /// * `iter` - The iterator to wrap.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use ext_php_rs::generator::Generator;
+    ///
+    /// let users = Generator::with_keys([("alice", 1), ("bob", 2)]);
+    /// ```
+    pub fn with_keys<I, K, V>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)> + 'static,
+        K: IntoZval,
+        V: IntoZval,
+    {
+        Self {
+            iter: Box::new(
+                iter.into_iter()
+                    .map(|(key, value)| Ok((key.into_zval(false)?, value.into_zval(false)?))),
+            ),
+            current: None,
+            started: false,
+            advanced: false,
+        }
+    }
+
+    /// Builds the class entry for [`Generator`], registering it with PHP. This
+    /// function should only be called once inside your module startup
+    /// function.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the function is called more than once.
+    pub fn build() {
+        assert!(!GENERATOR_META.has_ce(), "Generator class already built.");
+
+        ClassBuilder::new("RustGenerator")
+            .flags(ClassFlags::Final)
+            .implements((ce::iterator, "\\Iterator"))
+            .method(
+                FunctionBuilder::new("current", Self::current).returns(
+                    DataType::Mixed,
+                    false,
+                    true,
+                ),
+                MethodFlags::Public,
+            )
+            .method(
+                FunctionBuilder::new("key", Self::key).returns(DataType::Mixed, false, true),
+                MethodFlags::Public,
+            )
+            .method(
+                FunctionBuilder::new("next", Self::next).returns(DataType::Void, false, false),
+                MethodFlags::Public,
+            )
+            .method(
+                FunctionBuilder::new("valid", Self::valid).returns(DataType::Bool, false, false),
+                MethodFlags::Public,
+            )
+            .method(
+                FunctionBuilder::new("rewind", Self::rewind).returns(DataType::Void, false, false),
+                MethodFlags::Public,
+            )
+            .object_override::<Self>()
+            .registration(|ce| GENERATOR_META.set_ce(ce))
+            .register()
+            .expect("Failed to build `RustGenerator` PHP class.");
+    }
+
+    /// Fetches the first element if the generator has not been started.
+    fn start(&mut self) -> Result<()> {
+        if !self.started {
+            self.started = true;
+            self.current = self.iter.next().transpose()?;
+        }
+        Ok(())
+    }
+
+    /// Moves the generator to the next element.
+    fn advance(&mut self) -> Result<()> {
+        self.start()?;
+        self.advanced = true;
+        self.current = self.iter.next().transpose()?;
+        Ok(())
+    }
+
+    /// Returns the generator the method is called on, after starting it.
+    fn this(ex: &mut ExecuteData) -> Option<&mut Self> {
+        let (parser, this) = ex.parser_method::<Self>();
+        parser.parse().ok()?;
+        let this = &mut **this.expect("Internal generator function called on non-generator class");
+        if let Err(e) = this.start() {
+            let _ = PhpException::default(e.to_string()).throw();
+            return None;
+        }
+        Some(this)
+    }
+
+    zend_fastcall! {
+        /// Implementation of `Iterator::current()`.
+        extern "C" fn current(ex: &mut ExecuteData, retval: &mut Zval) {
+            if let Some((_, value)) = Self::this(ex).and_then(|this| this.current.as_ref()) {
+                *retval = value.shallow_clone();
+            }
+        }
+    }
+
+    zend_fastcall! {
+        /// Implementation of `Iterator::key()`.
+        extern "C" fn key(ex: &mut ExecuteData, retval: &mut Zval) {
+            if let Some((key, _)) = Self::this(ex).and_then(|this| this.current.as_ref()) {
+                *retval = key.shallow_clone();
+            }
+        }
+    }
+
+    zend_fastcall! {
+        /// Implementation of `Iterator::next()`.
+        extern "C" fn next(ex: &mut ExecuteData, _: &mut Zval) {
+            if let Some(this) = Self::this(ex) {
+                if let Err(e) = this.advance() {
+                    let _ = PhpException::default(e.to_string()).throw();
+                }
+            }
+        }
+    }
+
+    zend_fastcall! {
+        /// Implementation of `Iterator::valid()`.
+        extern "C" fn valid(ex: &mut ExecuteData, retval: &mut Zval) {
+            if let Some(this) = Self::this(ex) {
+                retval.set_bool(this.current.is_some());
+            }
+        }
+    }
+
+    zend_fastcall! {
+        /// Implementation of `Iterator::rewind()`.
+        extern "C" fn rewind(ex: &mut ExecuteData, _: &mut Zval) {
+            if Self::this(ex).is_some_and(|this| this.advanced) {
+                let _ = PhpException::default(
+                    "Cannot rewind a generator that was already run".into(),
+                )
+                .throw();
+            }
+        }
+    }
+}
+
+impl RegisteredClass for Generator {
+    const CLASS_NAME: &'static str = "RustGenerator";
+
+    const BUILDER_MODIFIER: Option<fn(ClassBuilder) -> ClassBuilder> = None;
+    const EXTENDS: Option<ClassEntryInfo> = None;
+    const IMPLEMENTS: &'static [ClassEntryInfo] = &[];
+
+    fn get_metadata() -> &'static ClassMetadata<Self> {
+        &GENERATOR_META
+    }
+
+    fn get_properties<'a>() -> HashMap<&'static str, PropertyInfo<'a, Self>> {
+        HashMap::new()
+    }
+
+    fn method_builders() -> Vec<(FunctionBuilder<'static>, MethodFlags)> {
+        unimplemented!()
+    }
+
+    fn constructor() -> Option<crate::class::ConstructorMeta<Self>> {
+        None
+    }
+
+    fn constants() -> &'static [(
+        &'static str,
+        &'static dyn crate::convert::IntoZvalDyn,
+        DocComments,
+    )] {
+        unimplemented!()
+    }
+}
+
+class_derives!(Generator);
@@ -1,12 +1,15 @@
 use std::{collections::HashMap, marker::PhantomData};
 
 use crate::{
-    builders::FunctionBuilder,
+    builders::{ClassBuilder, FunctionBuilder},
     class::{ConstructorMeta, RegisteredClass},
     convert::{IntoZval, IntoZvalDyn},
     describe::DocComments,
-    flags::MethodFlags,
+    flags::{MethodFlags, PropertyFlags},
+    gc::GcTraverse,
+    ops::{PhpArrayAccess, PhpCast, PhpCompare, PhpCountable, PhpIterable, PhpOperators},
     props::Property,
+    zend::ZendObjectHandlers,
 };
 
 /// Collector used to collect methods for PHP classes.
@@ -24,6 +27,14 @@ pub trait PhpClassImpl<T: RegisteredClass> {
     fn get_method_props<'a>(self) -> HashMap<&'static str, Property<'a, T>>;
     fn get_constructor(self) -> Option<ConstructorMeta<T>>;
     fn get_constants(self) -> &'static [(&'static str, &'static dyn IntoZvalDyn, DocComments)];
+    fn get_static_properties(
+        self,
+    ) -> &'static [(
+        &'static str,
+        PropertyFlags,
+        &'static dyn IntoZvalDyn,
+        DocComments,
+    )];
 }
 
 /// Default implementation for classes without an `impl` block. Classes that do
@@ -50,6 +61,168 @@ impl<T: RegisteredClass> PhpClassImpl<T> for &'_ PhpClassImplCollector<T> {
     fn get_constants(self) -> &'static [(&'static str, &'static dyn IntoZvalDyn, DocComments)] {
         &[]
     }
+
+    #[inline]
+    fn get_static_properties(
+        self,
+    ) -> &'static [(
+        &'static str,
+        PropertyFlags,
+        &'static dyn IntoZvalDyn,
+        DocComments,
+    )] {
+        &[]
+    }
+}
+
+/// Installs the `compare` handler for classes implementing [`PhpCompare`].
+/// Uses the same specialisation as [`PhpClassImpl`], the implementation for
+/// the collector reference is the fallback for classes which do not implement
+/// the trait.
+pub trait PhpCompareHandler {
+    fn install_compare(self, handlers: &mut ZendObjectHandlers);
+}
+
+impl<T: PhpCompare> PhpCompareHandler for PhpClassImplCollector<T> {
+    #[inline]
+    fn install_compare(self, handlers: &mut ZendObjectHandlers) {
+        handlers.compare = Some(ZendObjectHandlers::compare::<T>);
+    }
+}
+
+impl<T: RegisteredClass> PhpCompareHandler for &'_ PhpClassImplCollector<T> {
+    #[inline]
+    fn install_compare(self, _: &mut ZendObjectHandlers) {}
+}
+
+/// Installs the `cast_object` handler for classes implementing [`PhpCast`].
+pub trait PhpCastHandler {
+    fn install_cast(self, handlers: &mut ZendObjectHandlers);
+}
+
+impl<T: PhpCast> PhpCastHandler for PhpClassImplCollector<T> {
+    #[inline]
+    fn install_cast(self, handlers: &mut ZendObjectHandlers) {
+        handlers.cast_object = Some(ZendObjectHandlers::cast_object::<T>);
+    }
+}
+
+impl<T: RegisteredClass> PhpCastHandler for &'_ PhpClassImplCollector<T> {
+    #[inline]
+    fn install_cast(self, _: &mut ZendObjectHandlers) {}
+}
+
+/// Installs the `do_operation` handler for classes implementing
+/// [`PhpOperators`].
+pub trait PhpOperatorsHandler {
+    fn install_operators(self, handlers: &mut ZendObjectHandlers);
+}
+
+impl<T: PhpOperators> PhpOperatorsHandler for PhpClassImplCollector<T> {
+    #[inline]
+    fn install_operators(self, handlers: &mut ZendObjectHandlers) {
+        handlers.do_operation = Some(ZendObjectHandlers::do_operation::<T>);
+    }
+}
+
+impl<T: RegisteredClass> PhpOperatorsHandler for &'_ PhpClassImplCollector<T> {
+    #[inline]
+    fn install_operators(self, _: &mut ZendObjectHandlers) {}
+}
+
+/// Installs the `count_elements` handler for classes implementing
+/// [`PhpCountable`].
+pub trait PhpCountableHandler {
+    fn install_countable(self, handlers: &mut ZendObjectHandlers);
+}
+
+impl<T: PhpCountable> PhpCountableHandler for PhpClassImplCollector<T> {
+    #[inline]
+    fn install_countable(self, handlers: &mut ZendObjectHandlers) {
+        handlers.count_elements = Some(ZendObjectHandlers::count_elements::<T>);
+    }
+}
+
+impl<T: RegisteredClass> PhpCountableHandler for &'_ PhpClassImplCollector<T> {
+    #[inline]
+    fn install_countable(self, _: &mut ZendObjectHandlers) {}
+}
+
+/// Installs the dimension handlers for classes implementing
+/// [`PhpArrayAccess`].
+pub trait PhpArrayAccessHandler {
+    fn install_array_access(self, handlers: &mut ZendObjectHandlers);
+}
+
+impl<T: PhpArrayAccess> PhpArrayAccessHandler for PhpClassImplCollector<T> {
+    #[inline]
+    fn install_array_access(self, handlers: &mut ZendObjectHandlers) {
+        handlers.read_dimension = Some(ZendObjectHandlers::read_dimension::<T>);
+        handlers.write_dimension = Some(ZendObjectHandlers::write_dimension::<T>);
+        handlers.has_dimension = Some(ZendObjectHandlers::has_dimension::<T>);
+        handlers.unset_dimension = Some(ZendObjectHandlers::unset_dimension::<T>);
+    }
+}
+
+impl<T: RegisteredClass> PhpArrayAccessHandler for &'_ PhpClassImplCollector<T> {
+    #[inline]
+    fn install_array_access(self, _: &mut ZendObjectHandlers) {}
+}
+
+/// Installs the `clone_obj` handler for classes implementing [`Clone`].
+/// Objects of other classes cannot be cloned, PHP throws an `Error` instead.
+pub trait PhpCloneHandler {
+    fn install_clone(self, handlers: &mut ZendObjectHandlers);
+}
+
+impl<T: RegisteredClass + Clone> PhpCloneHandler for PhpClassImplCollector<T> {
+    #[inline]
+    fn install_clone(self, handlers: &mut ZendObjectHandlers) {
+        handlers.clone_obj = Some(ZendObjectHandlers::clone_obj::<T>);
+    }
+}
+
+impl<T: RegisteredClass> PhpCloneHandler for &'_ PhpClassImplCollector<T> {
+    #[inline]
+    fn install_clone(self, handlers: &mut ZendObjectHandlers) {
+        handlers.clone_obj = None;
+    }
+}
+
+/// Installs the `get_gc` handler for classes implementing [`GcTraverse`].
+pub trait PhpGcHandler {
+    fn install_gc(self, handlers: &mut ZendObjectHandlers);
+}
+
+impl<T: RegisteredClass + GcTraverse> PhpGcHandler for PhpClassImplCollector<T> {
+    #[inline]
+    fn install_gc(self, handlers: &mut ZendObjectHandlers) {
+        handlers.get_gc = Some(ZendObjectHandlers::get_gc::<T>);
+    }
+}
+
+impl<T: RegisteredClass> PhpGcHandler for &'_ PhpClassImplCollector<T> {
+    #[inline]
+    fn install_gc(self, _: &mut ZendObjectHandlers) {}
+}
+
+/// Registers the iterator of classes implementing [`PhpIterable`].
+pub trait PhpIterableHandler {
+    fn install_iterable(self, builder: ClassBuilder) -> ClassBuilder;
+}
+
+impl<T: PhpIterable> PhpIterableHandler for PhpClassImplCollector<T> {
+    #[inline]
+    fn install_iterable(self, builder: ClassBuilder) -> ClassBuilder {
+        builder.iterable::<T>()
+    }
+}
+
+impl<T: RegisteredClass> PhpIterableHandler for &'_ PhpClassImplCollector<T> {
+    #[inline]
+    fn install_iterable(self, builder: ClassBuilder) -> ClassBuilder {
+        builder
+    }
 }
 
 // This implementation is only used for `TYPE` and `NULLABLE`.
@@ -6,6 +6,9 @@ use crate::builders::ModuleStartup;
 pub mod class;
 pub mod function;
 pub mod property;
+#[cfg(feature = "inventory")]
+pub mod registry;
+pub mod zval_convert;
 
 /// A mutex type that contains a [`ModuleStartup`] instance.
 pub type ModuleStartupMutex = Mutex<Option<ModuleStartup>>;
@@ -16,7 +19,8 @@ pub type ModuleStartupMutex = Mutex<Option<ModuleStartup>>;
 pub const MODULE_STARTUP_INIT: ModuleStartupMutex = const_mutex(None);
 
 /// Called by startup functions registered with the [`#[php_startup]`] macro.
-/// Initializes all classes that are defined by ext-php-rs (i.e. `Closure`).
+/// Initializes all classes that are defined by ext-php-rs (i.e. `Closure` and
+/// `Generator`).
 ///
 /// [`#[php_startup]`]: `crate::php_startup`
 // TODO: Measure this
@@ -25,4 +29,6 @@ pub const MODULE_STARTUP_INIT: ModuleStartupMutex = const_mutex(None);
 pub fn ext_php_rs_startup() {
     #[cfg(feature = "closure")]
     crate::closure::Closure::build();
+    #[cfg(feature = "generator")]
+    crate::generator::Generator::build();
 }
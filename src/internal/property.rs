@@ -1,7 +1,20 @@
-use crate::{describe::DocComments, flags::PropertyFlags, props::Property};
+use crate::{
+    describe::DocComments,
+    error::Result,
+    flags::{DataType, PropertyFlags},
+    props::Property,
+    types::Zval,
+};
+
+/// Function returning the default value of a property, and the representation
+/// of the default in stubs.
+pub type PropertyDefault = (fn() -> Result<Zval>, &'static str);
 
 pub struct PropertyInfo<'a, T> {
     pub prop: Property<'a, T>,
     pub flags: PropertyFlags,
+    pub ty: Option<DataType>,
+    pub nullable: bool,
+    pub default: Option<PropertyDefault>,
     pub docs: DocComments,
 }
@@ -0,0 +1,44 @@
+//! Items registered with the module automatically when the `inventory`
+//! feature is enabled.
+
+#[doc(hidden)]
+pub use inventory;
+
+use crate::{builders::ModuleBuilder, class::RegisteredClass};
+
+/// A function, class, interface or enum submitted by the `#[php_function]`,
+/// `#[php_class]`, `#[php_interface]` and `#[php_enum]` macros, which is added
+/// to the module by [`ModuleBuilder::auto_register`].
+pub struct ModuleItem {
+    /// The name of the item in PHP.
+    pub name: &'static str,
+    /// The kind of the item.
+    pub kind: ModuleItemKind,
+    /// Adds the item to the module.
+    pub register: for<'a> fn(ModuleBuilder<'a>) -> ModuleBuilder<'a>,
+    /// Returns the names of the classes and interfaces the item extends or
+    /// implements, which are added to the module before the item.
+    pub dependencies: fn() -> Vec<&'static str>,
+}
+
+/// The kind of a [`ModuleItem`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModuleItemKind {
+    /// A function.
+    Function,
+    /// A class, interface or enum.
+    Class,
+}
+
+/// Returns the names of the parent class and the interfaces of `T`, the
+/// [`ModuleItem::dependencies`] of classes, interfaces and enums.
+#[must_use]
+pub fn class_dependencies<T: RegisteredClass>() -> Vec<&'static str> {
+    T::EXTENDS
+        .iter()
+        .chain(T::IMPLEMENTS)
+        .map(|&(_, name)| name.trim_start_matches('\\'))
+        .collect()
+}
+
+inventory::collect!(ModuleItem);
@@ -0,0 +1,101 @@
+//! Helpers used by structs deriving `ZvalConvert` to read and write their
+//! fields.
+
+use crate::{
+    boxed::ZBox,
+    convert::{FromZval, IntoZval},
+    error::{Error, Result},
+    types::{ZendHashTable, ZendObject},
+    zend::ClassEntry,
+};
+
+/// The array or object the fields of a struct are read from.
+pub enum Fields<'a> {
+    /// An associative array.
+    Array(&'a ZendHashTable),
+    /// An object.
+    Object(&'a ZendObject),
+}
+
+impl<'a> Fields<'a> {
+    /// Reads a field, returning [`None`] if the key or property does not
+    /// exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value could not be converted into `T`.
+    pub fn get<T: FromZval<'a>>(&self, name: &str) -> Result<Option<T>> {
+        match self {
+            Self::Array(array) => array
+                .get(name)
+                .map(|zv| T::from_zval(zv).ok_or_else(|| Error::ZvalConversion(zv.get_type())))
+                .transpose(),
+            Self::Object(obj) => match obj.get_property(name) {
+                Err(Error::InvalidProperty) => Ok(None),
+                res => res.map(Some),
+            },
+        }
+    }
+}
+
+/// The array or object the fields of a struct are written to.
+pub enum FieldsMut<'a> {
+    /// An associative array.
+    Array(&'a mut ZendHashTable),
+    /// An object.
+    Object(&'a mut ZendObject),
+}
+
+impl FieldsMut<'_> {
+    /// Writes a field.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value could not be converted into a zval.
+    pub fn set(&mut self, name: &str, value: impl IntoZval) -> Result<()> {
+        match self {
+            Self::Array(array) => array.insert(name, value),
+            Self::Object(obj) => obj.set_property(name, value),
+        }
+    }
+}
+
+/// Reads the fields of a struct deriving `ZvalConvert`. Allows the struct to
+/// be flattened into other structs with `#[php(flatten)]`.
+pub trait FromZvalFields<'a>: Sized {
+    /// Reads the struct from the given fields.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a required field is missing or could not be
+    /// converted.
+    fn from_fields(fields: &Fields<'a>) -> Result<Self>;
+}
+
+/// Writes the fields of a struct deriving `ZvalConvert`. Allows the struct to
+/// be flattened into other structs with `#[php(flatten)]`.
+pub trait IntoZvalFields {
+    /// Writes the fields of the struct.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a field could not be converted into a zval.
+    fn into_fields(self, fields: &mut FieldsMut<'_>) -> Result<()>;
+}
+
+/// Creates an object of the class with the given name, without calling its
+/// constructor.
+///
+/// # Errors
+///
+/// * [`Error::UnknownClass`] - If the class does not exist.
+pub fn new_object(class: &str) -> Result<ZBox<ZendObject>> {
+    let ce = ClassEntry::try_find(class).ok_or_else(|| Error::UnknownClass(class.into()))?;
+    Ok(ZendObject::new(ce))
+}
+
+/// Returns whether the object is an instance of the class with the given name.
+#[must_use]
+pub fn instance_of(obj: &ZendObject, class: &str) -> bool {
+    ClassEntry::try_find(class).is_some_and(|ce| obj.instance_of(ce))
+}
@@ -17,6 +17,7 @@ pub mod error;
 pub mod exception;
 pub mod ffi;
 pub mod flags;
+pub mod gc;
 #[macro_use]
 pub mod macros;
 pub mod boxed;
@@ -30,10 +31,17 @@ pub mod describe;
 pub mod embed;
 #[cfg(feature = "enum")]
 pub mod enum_;
+#[cfg(any(docs, feature = "generator"))]
+#[cfg_attr(docs, doc(cfg(feature = "generator")))]
+pub mod generator;
 #[doc(hidden)]
 pub mod internal;
+pub mod ops;
 pub mod props;
 pub mod rc;
+#[cfg(feature = "serde")]
+#[cfg_attr(docs, doc(cfg(feature = "serde")))]
+pub mod serde;
 #[cfg(test)]
 pub mod test;
 pub mod types;
@@ -49,14 +57,17 @@ pub mod prelude {
     #[cfg_attr(docs, doc(cfg(feature = "closure")))]
     pub use crate::closure::Closure;
     pub use crate::exception::{PhpException, PhpResult};
+    #[cfg(any(docs, feature = "generator"))]
+    #[cfg_attr(docs, doc(cfg(feature = "generator")))]
+    pub use crate::generator::Generator;
     #[cfg(feature = "enum")]
     pub use crate::php_enum;
     pub use crate::php_print;
     pub use crate::php_println;
     pub use crate::types::ZendCallable;
     pub use crate::{
-        php_class, php_const, php_extern, php_function, php_impl, php_module, wrap_constant,
-        wrap_function, zend_fastcall, ZvalConvert,
+        php_class, php_const, php_extern, php_function, php_impl, php_interface, php_module,
+        wrap_constant, wrap_function, zend_fastcall, GcTraverse, ZvalConvert,
     };
 }
 
@@ -72,6 +83,6 @@ pub const PHP_ZTS: bool = cfg!(php_zts);
 #[cfg(feature = "enum")]
 pub use ext_php_rs_derive::php_enum;
 pub use ext_php_rs_derive::{
-    php_class, php_const, php_extern, php_function, php_impl, php_module, wrap_constant,
-    wrap_function, zend_fastcall, ZvalConvert,
+    php_class, php_const, php_extern, php_function, php_impl, php_interface, php_module,
+    wrap_constant, wrap_function, zend_fastcall, GcTraverse, ZvalConvert,
 };
@@ -333,6 +333,36 @@ macro_rules! class_derives {
     };
 }
 
+/// Expands to [`PropertyFlags::Readonly`], or to a compile error when building
+/// against a PHP version without readonly properties. Used by the
+/// [`php_class`] macro.
+///
+/// [`PropertyFlags::Readonly`]: crate::flags::PropertyFlags
+/// [`php_class`]: crate::php_class
+#[cfg(php81)]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! readonly_property_flag {
+    () => {
+        $crate::flags::PropertyFlags::Readonly
+    };
+}
+
+/// Expands to [`PropertyFlags::Readonly`], or to a compile error when building
+/// against a PHP version without readonly properties. Used by the
+/// [`php_class`] macro.
+///
+/// [`PropertyFlags::Readonly`]: crate::flags::PropertyFlags
+/// [`php_class`]: crate::php_class
+#[cfg(not(php81))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! readonly_property_flag {
+    () => {
+        ::std::compile_error!("Readonly properties require PHP 8.1 or later.")
+    };
+}
+
 /// Derives `From<T> for Zval` and `IntoZval` for a given type.
 macro_rules! into_zval {
     ($type: ty, $fn: ident, $dt: ident) => {
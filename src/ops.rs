@@ -0,0 +1,267 @@
+//! Traits for overloading PHP operators on classes.
+//!
+//! Implementing one of these traits on a type exported with
+//! [`macro@php_class`] installs the matching object handlers on the class, the
+//! same way extensions such as GMP overload comparison, casts and arithmetic
+//! on their objects, and `ArrayObject` overloads `count()`, array access and
+//! iteration.
+//!
+//! [`macro@php_class`]: crate::php_class
+
+use std::cmp::Ordering;
+
+use crate::{
+    class::RegisteredClass,
+    convert::IntoZval,
+    exception::PhpResult,
+    ffi::{
+        ZEND_ADD, ZEND_BW_AND, ZEND_BW_NOT, ZEND_BW_OR, ZEND_BW_XOR, ZEND_CONCAT, ZEND_DIV,
+        ZEND_MOD, ZEND_MUL, ZEND_POW, ZEND_SL, ZEND_SR, ZEND_SUB,
+    },
+    flags::DataType,
+    types::Zval,
+};
+
+/// Implemented on classes which can be compared in PHP, e.g. with `==`, `<`
+/// and `<=>`.
+pub trait PhpCompare: RegisteredClass {
+    /// Compares the object with another PHP value.
+    ///
+    /// The object may be either operand of the comparison, if it is the right
+    /// operand the returned ordering is reversed before it is given to PHP.
+    ///
+    /// # Parameters
+    ///
+    /// * `other` - The other operand of the comparison.
+    ///
+    /// # Returns
+    ///
+    /// The ordering of the object relative to `other`, or [`None`] to fall
+    /// back to the default PHP comparison of objects.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the comparison failed. The error is thrown as an
+    /// exception.
+    fn compare(&self, other: &Zval) -> PhpResult<Option<Ordering>>;
+}
+
+/// Implemented on classes which can be cast to scalar types in PHP, e.g. with
+/// `(string)`, `(int)`, `(float)` and `(bool)`.
+pub trait PhpCast: RegisteredClass {
+    /// Casts the object into the given type.
+    ///
+    /// # Parameters
+    ///
+    /// * `ty` - The type to cast into, one of [`DataType::String`],
+    ///   [`DataType::Long`], [`DataType::Double`] or [`DataType::Bool`].
+    ///
+    /// # Returns
+    ///
+    /// The value of the given type, or [`None`] if the object cannot be cast
+    /// into the type, in which case the default PHP behaviour is used.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the cast failed, or if the returned value is not of
+    /// the requested type. The error is thrown as an exception.
+    fn cast(&self, ty: DataType) -> PhpResult<Option<Zval>>;
+}
+
+/// Implemented on classes which overload the arithmetic and bitwise operators
+/// in PHP.
+pub trait PhpOperators: RegisteredClass {
+    /// Performs an operation where at least one operand is an object of the
+    /// class. Compound assignments such as `+=` and the `++` and `--`
+    /// operators are handled through the same operation.
+    ///
+    /// # Parameters
+    ///
+    /// * `op` - The operator being applied.
+    /// * `lhs` - The left operand.
+    /// * `rhs` - The right operand. `null` for [`Operator::BitwiseNot`].
+    ///
+    /// # Returns
+    ///
+    /// The result of the operation, or [`None`] if the operation is not
+    /// supported for the operands, in which case the default PHP behaviour is
+    /// used.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the operation failed. The error is thrown as an
+    /// exception.
+    fn operation(op: Operator, lhs: &Zval, rhs: &Zval) -> PhpResult<Option<Zval>>;
+}
+
+/// Implemented on classes which can be counted in PHP with `count()`.
+///
+/// Unlike implementing the `Countable` interface, the object is counted
+/// without calling a PHP method. The class is not an instance of `Countable`
+/// unless it also implements the interface.
+pub trait PhpCountable: RegisteredClass {
+    /// Returns the number of elements of the object.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the object could not be counted. The error is thrown
+    /// as an exception.
+    fn count(&self) -> PhpResult<i64>;
+}
+
+/// Implemented on classes which can be accessed like arrays in PHP, e.g. with
+/// `$obj[$key]`, `isset($obj[$key])` and `unset($obj[$key])`.
+///
+/// Unlike implementing the `ArrayAccess` interface, the elements are accessed
+/// without calling PHP methods. The class is not an instance of `ArrayAccess`
+/// unless it also implements the interface.
+pub trait PhpArrayAccess: RegisteredClass {
+    /// Returns the element at the given offset, e.g. `$obj[$offset]`.
+    ///
+    /// # Parameters
+    ///
+    /// * `offset` - The offset of the element.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the element could not be read. The error is thrown
+    /// as an exception.
+    fn offset_get(&self, offset: &Zval) -> PhpResult<Zval>;
+
+    /// Sets the element at the given offset, e.g. `$obj[$offset] = $value`.
+    ///
+    /// # Parameters
+    ///
+    /// * `offset` - The offset of the element, or [`None`] when appending an
+    ///   element with `$obj[] = $value`.
+    /// * `value` - The value of the element.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the element could not be set. The error is thrown
+    /// as an exception.
+    fn offset_set(&mut self, offset: Option<&Zval>, value: &Zval) -> PhpResult;
+
+    /// Returns whether an element exists at the given offset, e.g.
+    /// `isset($obj[$offset])`.
+    ///
+    /// `empty($obj[$offset])` additionally reads the element with
+    /// [`offset_get`](PhpArrayAccess::offset_get) if it exists.
+    ///
+    /// # Parameters
+    ///
+    /// * `offset` - The offset of the element.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the offset could not be checked. The error is thrown
+    /// as an exception.
+    fn offset_exists(&self, offset: &Zval) -> PhpResult<bool>;
+
+    /// Removes the element at the given offset, e.g. `unset($obj[$offset])`.
+    ///
+    /// # Parameters
+    ///
+    /// * `offset` - The offset of the element.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the element could not be removed. The error is
+    /// thrown as an exception.
+    fn offset_unset(&mut self, offset: &Zval) -> PhpResult;
+}
+
+/// Implemented on classes which can be iterated in PHP with `foreach`.
+///
+/// The class implements `IteratorAggregate`, its `getIterator()` method returns
+/// an iterator over the object. Iterating by reference is not supported.
+pub trait PhpIterable: RegisteredClass {
+    /// Type of the keys of the elements.
+    type Key: IntoZval;
+    /// Type of the values of the elements.
+    type Value: IntoZval;
+    /// Iterator over the elements. The iterator cannot borrow the object, as
+    /// the object may be modified from PHP while it is iterated.
+    type Iter: Iterator<Item = (Self::Key, Self::Value)> + 'static;
+
+    /// Returns an iterator over the elements of the object. Called whenever
+    /// PHP starts iterating over the object.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the object cannot be iterated. The error is thrown
+    /// as an exception.
+    fn get_iterator(&self) -> PhpResult<Self::Iter>;
+}
+
+/// Operators which can be overloaded with [`PhpOperators`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operator {
+    /// `+`
+    Add,
+    /// `-`
+    Sub,
+    /// `*`
+    Mul,
+    /// `/`
+    Div,
+    /// `%`
+    Mod,
+    /// `**`
+    Pow,
+    /// `.`
+    Concat,
+    /// `<<`
+    ShiftLeft,
+    /// `>>`
+    ShiftRight,
+    /// `|`
+    BitwiseOr,
+    /// `&`
+    BitwiseAnd,
+    /// `^`
+    BitwiseXor,
+    /// `~`
+    BitwiseNot,
+}
+
+impl Operator {
+    /// Returns the operator for the given Zend opcode, or [`None`] if the
+    /// opcode is not an overloadable operator.
+    pub(crate) fn from_opcode(opcode: u8) -> Option<Self> {
+        Some(match u32::from(opcode) {
+            ZEND_ADD => Self::Add,
+            ZEND_SUB => Self::Sub,
+            ZEND_MUL => Self::Mul,
+            ZEND_DIV => Self::Div,
+            ZEND_MOD => Self::Mod,
+            ZEND_POW => Self::Pow,
+            ZEND_CONCAT => Self::Concat,
+            ZEND_SL => Self::ShiftLeft,
+            ZEND_SR => Self::ShiftRight,
+            ZEND_BW_OR => Self::BitwiseOr,
+            ZEND_BW_AND => Self::BitwiseAnd,
+            ZEND_BW_XOR => Self::BitwiseXor,
+            ZEND_BW_NOT => Self::BitwiseNot,
+            _ => return None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_operator_from_opcode() {
+        let opcode = |opcode: u32| u8::try_from(opcode).expect("Opcode out of range");
+
+        assert_eq!(Operator::from_opcode(opcode(ZEND_ADD)), Some(Operator::Add));
+        assert_eq!(Operator::from_opcode(opcode(ZEND_POW)), Some(Operator::Pow));
+        assert_eq!(
+            Operator::from_opcode(opcode(ZEND_BW_NOT)),
+            Some(Operator::BitwiseNot)
+        );
+        assert_eq!(Operator::from_opcode(0), None);
+    }
+}
@@ -0,0 +1,393 @@
+//! Deserializing Rust values from [`Zval`]s.
+
+use serde::de::{
+    self,
+    value::{BorrowedStrDeserializer, StringDeserializer},
+    Error as _, IntoDeserializer, Unexpected, Visitor,
+};
+
+use crate::{
+    flags::DataType,
+    types::{ArrayKey, ZendHashTable, Zval},
+};
+
+use super::{Error, Result};
+
+/// A [`serde::Deserializer`] reading from a [`Zval`]. See the [module
+/// documentation](crate::serde) for how values are represented in PHP.
+#[derive(Debug, Clone, Copy)]
+pub struct Deserializer<'de> {
+    zval: &'de Zval,
+}
+
+impl<'de> Deserializer<'de> {
+    /// Creates a deserializer reading from the given zval. References are
+    /// followed.
+    #[must_use]
+    pub fn new(zval: &'de Zval) -> Self {
+        Self {
+            zval: zval.dereference(),
+        }
+    }
+
+    /// Returns the value as it is seen by [`de::Error::invalid_type`].
+    fn unexpected(self) -> Unexpected<'de> {
+        let zval = self.zval;
+        match zval.get_type() {
+            DataType::Null | DataType::Undef => Unexpected::Unit,
+            DataType::False => Unexpected::Bool(false),
+            DataType::True => Unexpected::Bool(true),
+            DataType::Long => Unexpected::Signed(zval.long().unwrap_or_default()),
+            DataType::Double => Unexpected::Float(zval.double().unwrap_or_default()),
+            DataType::String => match zval.str() {
+                Some(s) => Unexpected::Str(s),
+                None => Unexpected::Bytes(zval.zend_str().map_or(&[], |s| s.as_bytes())),
+            },
+            DataType::Array => Unexpected::Seq,
+            DataType::Object(_) => Unexpected::Map,
+            _ => Unexpected::Other("unsupported PHP value"),
+        }
+    }
+
+    fn invalid_type(self, exp: &dyn de::Expected) -> Error {
+        Error::invalid_type(self.unexpected(), exp)
+    }
+
+    /// Deserializes an array or object through a map visitor.
+    fn visit_map<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        if let Some(array) = self.zval.array() {
+            return visitor.visit_map(MapAccess::new(array.iter()));
+        }
+        if let Some(object) = self.zval.object() {
+            let properties = object.get_properties()?;
+            // Mangled names of private and protected properties start with a
+            // NUL byte, and unset typed properties are undefined.
+            let properties = properties.iter().filter(|(key, val)| {
+                !matches!(key, ArrayKey::String(key) if key.starts_with('\0'))
+                    && !matches!(val.dereference().get_type(), DataType::Undef)
+            });
+            return visitor.visit_map(MapAccess::new(properties));
+        }
+        Err(self.invalid_type(&visitor))
+    }
+}
+
+impl<'de> de::Deserializer<'de> for Deserializer<'de> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        let zval = self.zval;
+        match zval.get_type() {
+            DataType::Null => visitor.visit_unit(),
+            DataType::False => visitor.visit_bool(false),
+            DataType::True => visitor.visit_bool(true),
+            DataType::Long => visitor.visit_i64(zval.long().unwrap_or_default()),
+            DataType::Double => visitor.visit_f64(zval.double().unwrap_or_default()),
+            DataType::String => match zval.str() {
+                Some(s) => visitor.visit_borrowed_str(s),
+                None => self.deserialize_bytes(visitor),
+            },
+            DataType::Array => match zval.array() {
+                Some(array) if array.has_sequential_keys() => {
+                    visitor.visit_seq(SeqAccess::new(array))
+                }
+                _ => self.visit_map(visitor),
+            },
+            DataType::Object(_) => self.visit_map(visitor),
+            ty => Err(Error::custom(format!("cannot deserialize a PHP {ty}"))),
+        }
+    }
+
+    fn deserialize_bytes<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        match self.zval.zend_str() {
+            Some(s) => visitor.visit_borrowed_bytes(s.as_bytes()),
+            None => self.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_byte_buf<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_bytes(visitor)
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        if self.zval.is_null() {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        match self.zval.array() {
+            Some(array) => visitor.visit_seq(SeqAccess::new(array)),
+            None => Err(self.invalid_type(&visitor)),
+        }
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(self, _len: usize, visitor: V) -> Result<V::Value> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_map<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.visit_map(visitor)
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        self.visit_map(visitor)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        if let Some(variant) = self.zval.str() {
+            return visitor.visit_enum(BorrowedStrDeserializer::<Error>::new(variant));
+        }
+        if let Some(array) = self.zval.array() {
+            if array.len() == 1 {
+                if let Some((key, value)) = array.iter().next() {
+                    return visitor.visit_enum(EnumAccess { key, value });
+                }
+            }
+        }
+        Err(Error::invalid_type(
+            self.unexpected(),
+            &"a string or an array with a single element",
+        ))
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_unit()
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        unit unit_struct identifier
+    }
+}
+
+/// Gives access to the elements of an array.
+struct SeqAccess<'de> {
+    array: &'de ZendHashTable,
+    values: Box<dyn Iterator<Item = &'de Zval> + 'de>,
+    index: usize,
+}
+
+impl<'de> SeqAccess<'de> {
+    fn new(array: &'de ZendHashTable) -> Self {
+        Self {
+            array,
+            values: Box::new(array.values()),
+            index: 0,
+        }
+    }
+}
+
+impl<'de> de::SeqAccess<'de> for SeqAccess<'de> {
+    type Error = Error;
+
+    fn next_element_seed<T: de::DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>> {
+        let Some(value) = self.values.next() else {
+            return Ok(None);
+        };
+        let index = self.index;
+        self.index += 1;
+        seed.deserialize(Deserializer::new(value))
+            .map(Some)
+            .map_err(|e| e.at_index(index))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.array.len().saturating_sub(self.index))
+    }
+}
+
+/// Gives access to the entries of an array or the properties of an object.
+struct MapAccess<'de, I> {
+    entries: I,
+    value: Option<(ArrayKey<'de>, &'de Zval)>,
+}
+
+impl<'de, I: Iterator<Item = (ArrayKey<'de>, &'de Zval)>> MapAccess<'de, I> {
+    fn new(entries: I) -> Self {
+        Self {
+            entries,
+            value: None,
+        }
+    }
+}
+
+impl<'de, I: Iterator<Item = (ArrayKey<'de>, &'de Zval)>> de::MapAccess<'de> for MapAccess<'de, I> {
+    type Error = Error;
+
+    fn next_key_seed<K: de::DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>> {
+        let Some((key, value)) = self.entries.next() else {
+            return Ok(None);
+        };
+        let result = seed
+            .deserialize(KeyDeserializer { key: key.clone() })
+            .map(Some)
+            .map_err(|e| e.at(&key));
+        self.value = Some((key, value));
+        result
+    }
+
+    fn next_value_seed<V: de::DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value> {
+        let (key, value) = self
+            .value
+            .take()
+            .ok_or_else(|| Error::custom("next_value_seed called before next_key_seed"))?;
+        seed.deserialize(Deserializer::new(value))
+            .map_err(|e| e.at(&key))
+    }
+}
+
+/// Deserializes the key of an array entry or the name of a property.
+struct KeyDeserializer<'de> {
+    key: ArrayKey<'de>,
+}
+
+impl<'de> de::Deserializer<'de> for KeyDeserializer<'de> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        match self.key {
+            ArrayKey::Long(key) => visitor.visit_i64(key),
+            ArrayKey::String(key) => visitor.visit_string(key),
+            ArrayKey::Str(key) => visitor.visit_borrowed_str(key),
+        }
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        match self.key {
+            ArrayKey::Long(key) => visitor.visit_string(key.to_string()),
+            _ => self.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_identifier<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        visitor.visit_enum(key_variant(self.key))
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char
+        bytes byte_buf option unit unit_struct seq tuple tuple_struct map struct
+        ignored_any
+    }
+}
+
+/// Deserializes an array key naming an enum variant.
+fn key_variant(key: ArrayKey<'_>) -> StringDeserializer<Error> {
+    match key {
+        ArrayKey::Long(key) => key.to_string(),
+        ArrayKey::String(key) => key,
+        ArrayKey::Str(key) => key.to_owned(),
+    }
+    .into_deserializer()
+}
+
+/// Gives access to an enum variant represented by an array with a single
+/// element, whose key is the name of the variant.
+struct EnumAccess<'de> {
+    key: ArrayKey<'de>,
+    value: &'de Zval,
+}
+
+impl<'de> de::EnumAccess<'de> for EnumAccess<'de> {
+    type Error = Error;
+    type Variant = VariantAccess<'de>;
+
+    fn variant_seed<V: de::DeserializeSeed<'de>>(
+        self,
+        seed: V,
+    ) -> Result<(V::Value, VariantAccess<'de>)> {
+        let variant = seed
+            .deserialize(key_variant(self.key.clone()))
+            .map_err(|e| e.at(&self.key))?;
+        Ok((
+            variant,
+            VariantAccess {
+                key: self.key,
+                value: Deserializer::new(self.value),
+            },
+        ))
+    }
+}
+
+/// Gives access to the value of an enum variant.
+struct VariantAccess<'de> {
+    key: ArrayKey<'de>,
+    value: Deserializer<'de>,
+}
+
+impl<'de> de::VariantAccess<'de> for VariantAccess<'de> {
+    type Error = Error;
+
+    fn unit_variant(self) -> Result<()> {
+        de::Deserialize::deserialize(self.value).map_err(|e: Error| e.at(&self.key))
+    }
+
+    fn newtype_variant_seed<T: de::DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value> {
+        seed.deserialize(self.value).map_err(|e| e.at(&self.key))
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, _len: usize, visitor: V) -> Result<V::Value> {
+        de::Deserializer::deserialize_seq(self.value, visitor).map_err(|e| e.at(&self.key))
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        de::Deserializer::deserialize_map(self.value, visitor).map_err(|e| e.at(&self.key))
+    }
+}
@@ -0,0 +1,200 @@
+//! Conversions between Rust values implementing the [`serde`] traits and
+//! [`Zval`]s.
+//!
+//! Values are mapped onto PHP types as follows:
+//!
+//! | Rust                                     | PHP                                      |
+//! | ---------------------------------------- | ---------------------------------------- |
+//! | `bool`                                   | `bool`                                   |
+//! | integers                                 | `int`                                    |
+//! | `f32`, `f64`                             | `float`                                  |
+//! | `char`, `String`, `&str`                 | `string`                                 |
+//! | bytes (e.g. `serde_bytes`)               | binary `string`                          |
+//! | `None`, `()`, unit structs               | `null`                                   |
+//! | sequences, tuples                        | list `array`                             |
+//! | maps                                     | associative `array`                      |
+//! | structs                                  | associative `array` or `stdClass`        |
+//! | unit variants                            | `string` holding the name of the variant |
+//! | other variants                           | `array` mapping the variant to its value |
+//!
+//! Errors carry the path of the value which failed to convert, e.g.
+//! `$.items[3].id`.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! # #![cfg_attr(windows, feature(abi_vectorcall))]
+//! use ext_php_rs::{prelude::*, serde::{from_zval, to_zval}, types::Zval};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Item {
+//!     id: u32,
+//!     tags: Vec<String>,
+//! }
+//!
+//! #[php_function]
+//! pub fn first_item(items: &Zval) -> PhpResult<Zval> {
+//!     let items: Vec<Item> = from_zval(items)?;
+//!     Ok(to_zval(&items.first())?)
+//! }
+//! ```
+
+mod de;
+mod ser;
+
+use std::fmt::{self, Display, Write as _};
+
+use crate::types::{ArrayKey, Zval};
+
+pub use de::Deserializer;
+pub use ser::Serializer;
+
+/// Serializes a Rust value into a [`Zval`]. Structs are serialized into
+/// associative arrays, see [`Serializer::structs_as_objects`] to serialize
+/// them into `stdClass` objects.
+///
+/// # Parameters
+///
+/// * `value` - The value to serialize.
+///
+/// # Errors
+///
+/// Returns an error if the value cannot be represented in PHP, e.g. an integer
+/// which does not fit into a PHP `int`, or if its [`Serialize`] implementation
+/// fails.
+///
+/// [`Serialize`]: ::serde::Serialize
+pub fn to_zval<T>(value: &T) -> Result<Zval>
+where
+    T: ::serde::Serialize + ?Sized,
+{
+    value.serialize(Serializer::new())
+}
+
+/// Deserializes a Rust value from a [`Zval`]. Arrays and objects can both be
+/// deserialized into structs and maps.
+///
+/// # Parameters
+///
+/// * `zval` - The value to deserialize.
+///
+/// # Errors
+///
+/// Returns an error if the value does not match the type being deserialized.
+pub fn from_zval<'de, T>(zval: &'de Zval) -> Result<T>
+where
+    T: ::serde::Deserialize<'de>,
+{
+    T::deserialize(Deserializer::new(zval))
+}
+
+/// Result type returned by the conversions.
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// An error which occurred while serializing or deserializing a [`Zval`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error {
+    message: String,
+    /// Path of the value which failed to convert, innermost segment first.
+    path: Vec<PathSegment>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum PathSegment {
+    Key(String),
+    Index(i64),
+}
+
+impl Error {
+    /// Returns the message of the error, without the path.
+    #[must_use]
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Returns the path of the value which failed to convert, e.g.
+    /// `$.items[3].id`. The path of the value passed to [`to_zval`] or
+    /// [`from_zval`] is `$`.
+    #[must_use]
+    pub fn path(&self) -> String {
+        let mut path = String::from("$");
+        for segment in self.path.iter().rev() {
+            // Writing to a string cannot fail.
+            let _ = match segment {
+                PathSegment::Key(key) => write!(path, ".{key}"),
+                PathSegment::Index(index) => write!(path, "[{index}]"),
+            };
+        }
+        path
+    }
+
+    /// Prepends the key of the value which failed to convert to the path.
+    pub(crate) fn at_key(mut self, key: impl Into<String>) -> Self {
+        self.path.push(PathSegment::Key(key.into()));
+        self
+    }
+
+    /// Prepends the index of the value which failed to convert to the path.
+    pub(crate) fn at_index(mut self, index: impl TryInto<i64>) -> Self {
+        self.path
+            .push(PathSegment::Index(index.try_into().unwrap_or(i64::MAX)));
+        self
+    }
+
+    /// Prepends the array key of the value which failed to convert to the
+    /// path.
+    pub(crate) fn at(self, key: &ArrayKey<'_>) -> Self {
+        match key {
+            ArrayKey::Long(index) => self.at_index(*index),
+            ArrayKey::String(key) => self.at_key(key.as_str()),
+            ArrayKey::Str(key) => self.at_key(*key),
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path(), self.message)
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl ::serde::ser::Error for Error {
+    fn custom<T: Display>(msg: T) -> Self {
+        Self {
+            message: msg.to_string(),
+            path: vec![],
+        }
+    }
+}
+
+impl ::serde::de::Error for Error {
+    fn custom<T: Display>(msg: T) -> Self {
+        Self {
+            message: msg.to_string(),
+            path: vec![],
+        }
+    }
+}
+
+impl From<crate::error::Error> for Error {
+    fn from(err: crate::error::Error) -> Self {
+        <Self as ::serde::ser::Error>::custom(err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_error_path() {
+        let err = <super::Error as ::serde::de::Error>::custom("invalid type")
+            .at_key("id")
+            .at_index(3)
+            .at_key("items");
+        assert_eq!(err.path(), "$.items[3].id");
+        assert_eq!(err.message(), "invalid type");
+        assert_eq!(err.to_string(), "$.items[3].id: invalid type");
+    }
+}
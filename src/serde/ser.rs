@@ -0,0 +1,605 @@
+//! Serializing Rust values into [`Zval`]s.
+
+use serde::ser::{self, Error as _, Impossible, Serialize};
+
+use crate::{
+    boxed::ZBox,
+    convert::IntoZval,
+    types::{ArrayKey, ZendHashTable, ZendLong, ZendObject, ZendStr, Zval},
+};
+
+use super::{Error, Result};
+
+/// A [`serde::Serializer`] producing [`Zval`]s. See the [module
+/// documentation](crate::serde) for how values are represented in PHP.
+#[must_use]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Serializer {
+    objects: bool,
+}
+
+impl Serializer {
+    /// Creates a serializer which serializes structs into associative arrays.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Serializes structs into `stdClass` objects instead of associative
+    /// arrays.
+    pub fn structs_as_objects(mut self) -> Self {
+        self.objects = true;
+        self
+    }
+}
+
+/// Converts a value into a zval.
+fn zval(value: impl IntoZval) -> Result<Zval> {
+    Ok(value.into_zval(false)?)
+}
+
+/// Converts an integer into a zval, failing if it does not fit into a PHP
+/// `int`.
+fn long<T>(value: T) -> Result<Zval>
+where
+    T: TryInto<ZendLong> + Copy + std::fmt::Display,
+{
+    let long = value
+        .try_into()
+        .map_err(|_| Error::custom(format!("integer {value} does not fit into a PHP int")))?;
+    zval(long)
+}
+
+/// Wraps the value of an enum variant into an array keyed by the name of the
+/// variant.
+fn variant(name: &'static str, value: Zval) -> Result<Zval> {
+    let mut array = ZendHashTable::with_capacity(1);
+    array.insert(ArrayKey::Str(name), value)?;
+    zval(array)
+}
+
+impl ser::Serializer for Serializer {
+    type Ok = Zval;
+    type Error = Error;
+
+    type SerializeSeq = SerializeSeq;
+    type SerializeTuple = SerializeSeq;
+    type SerializeTupleStruct = SerializeSeq;
+    type SerializeTupleVariant = SerializeVariant<SerializeSeq>;
+    type SerializeMap = SerializeMap;
+    type SerializeStruct = SerializeStruct;
+    type SerializeStructVariant = SerializeVariant<SerializeStruct>;
+
+    fn serialize_bool(self, v: bool) -> Result<Zval> {
+        zval(v)
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Zval> {
+        long(v)
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Zval> {
+        long(v)
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Zval> {
+        long(v)
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Zval> {
+        long(v)
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<Zval> {
+        long(v)
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Zval> {
+        long(v)
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Zval> {
+        long(v)
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Zval> {
+        long(v)
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Zval> {
+        long(v)
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<Zval> {
+        long(v)
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Zval> {
+        zval(v)
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Zval> {
+        zval(v)
+    }
+
+    fn serialize_char(self, v: char) -> Result<Zval> {
+        zval(v.to_string())
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Zval> {
+        zval(v)
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Zval> {
+        let mut zv = Zval::new();
+        zv.set_zend_string(ZendStr::new(v, false));
+        Ok(zv)
+    }
+
+    fn serialize_none(self) -> Result<Zval> {
+        Ok(Zval::null())
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Zval> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Zval> {
+        Ok(Zval::null())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Zval> {
+        Ok(Zval::null())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Zval> {
+        zval(variant)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Zval> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Zval> {
+        let value = value.serialize(self).map_err(|e| e.at_key(variant))?;
+        self::variant(variant, value)
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<SerializeSeq> {
+        Ok(SerializeSeq::new(self, len))
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<SerializeSeq> {
+        Ok(SerializeSeq::new(self, Some(len)))
+    }
+
+    fn serialize_tuple_struct(self, _name: &'static str, len: usize) -> Result<SerializeSeq> {
+        Ok(SerializeSeq::new(self, Some(len)))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<SerializeVariant<SerializeSeq>> {
+        Ok(SerializeVariant {
+            variant,
+            inner: SerializeSeq::new(self, Some(len)),
+        })
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<SerializeMap> {
+        Ok(SerializeMap {
+            ser: self,
+            array: ZendHashTable::with_capacity(capacity(len)),
+            key: None,
+        })
+    }
+
+    fn serialize_struct(self, _name: &'static str, len: usize) -> Result<SerializeStruct> {
+        Ok(SerializeStruct::new(self, len))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<SerializeVariant<SerializeStruct>> {
+        Ok(SerializeVariant {
+            variant,
+            inner: SerializeStruct::new(self, len),
+        })
+    }
+}
+
+/// Returns the initial capacity of an array holding the given number of
+/// elements.
+fn capacity(len: Option<usize>) -> u32 {
+    len.and_then(|len| len.try_into().ok()).unwrap_or(0)
+}
+
+/// Serializes sequences and tuples into list arrays.
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct SerializeSeq {
+    ser: Serializer,
+    array: ZBox<ZendHashTable>,
+}
+
+impl SerializeSeq {
+    fn new(ser: Serializer, len: Option<usize>) -> Self {
+        Self {
+            ser,
+            array: ZendHashTable::with_capacity(capacity(len)),
+        }
+    }
+
+    fn push<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        let value = value
+            .serialize(self.ser)
+            .map_err(|e| e.at_index(self.array.len()))?;
+        Ok(self.array.push(value)?)
+    }
+}
+
+impl ser::SerializeSeq for SerializeSeq {
+    type Ok = Zval;
+    type Error = Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Zval> {
+        zval(self.array)
+    }
+}
+
+impl ser::SerializeTuple for SerializeSeq {
+    type Ok = Zval;
+    type Error = Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Zval> {
+        zval(self.array)
+    }
+}
+
+impl ser::SerializeTupleStruct for SerializeSeq {
+    type Ok = Zval;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Zval> {
+        zval(self.array)
+    }
+}
+
+/// Serializes maps into associative arrays.
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct SerializeMap {
+    ser: Serializer,
+    array: ZBox<ZendHashTable>,
+    key: Option<ArrayKey<'static>>,
+}
+
+impl ser::SerializeMap for SerializeMap {
+    type Ok = Zval;
+    type Error = Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<()> {
+        self.key = Some(key.serialize(MapKeySerializer)?);
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        let key = self
+            .key
+            .take()
+            .ok_or_else(|| Error::custom("serialize_value called before serialize_key"))?;
+        let value = value.serialize(self.ser).map_err(|e| e.at(&key))?;
+        Ok(self.array.insert(key, value)?)
+    }
+
+    fn end(self) -> Result<Zval> {
+        zval(self.array)
+    }
+}
+
+/// Serializes structs into associative arrays or `stdClass` objects.
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct SerializeStruct {
+    ser: Serializer,
+    target: StructTarget,
+}
+
+#[derive(Debug)]
+enum StructTarget {
+    Array(ZBox<ZendHashTable>),
+    Object(ZBox<ZendObject>),
+}
+
+impl SerializeStruct {
+    fn new(ser: Serializer, len: usize) -> Self {
+        let target = if ser.objects {
+            StructTarget::Object(ZendObject::new_stdclass())
+        } else {
+            StructTarget::Array(ZendHashTable::with_capacity(capacity(Some(len))))
+        };
+        Self { ser, target }
+    }
+}
+
+impl ser::SerializeStruct for SerializeStruct {
+    type Ok = Zval;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        let value = value.serialize(self.ser).map_err(|e| e.at_key(key))?;
+        match &mut self.target {
+            StructTarget::Array(array) => array.insert(ArrayKey::Str(key), value)?,
+            StructTarget::Object(object) => object.set_property(key, value)?,
+        }
+        Ok(())
+    }
+
+    fn end(self) -> Result<Zval> {
+        match self.target {
+            StructTarget::Array(array) => zval(array),
+            StructTarget::Object(object) => zval(object),
+        }
+    }
+}
+
+/// Serializes tuple and struct variants, wrapping their value into an array
+/// keyed by the name of the variant.
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct SerializeVariant<S> {
+    variant: &'static str,
+    inner: S,
+}
+
+impl ser::SerializeTupleVariant for SerializeVariant<SerializeSeq> {
+    type Ok = Zval;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        self.inner.push(value).map_err(|e| e.at_key(self.variant))
+    }
+
+    fn end(self) -> Result<Zval> {
+        variant(self.variant, ser::SerializeSeq::end(self.inner)?)
+    }
+}
+
+impl ser::SerializeStructVariant for SerializeVariant<SerializeStruct> {
+    type Ok = Zval;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        ser::SerializeStruct::serialize_field(&mut self.inner, key, value)
+            .map_err(|e| e.at_key(self.variant))
+    }
+
+    fn end(self) -> Result<Zval> {
+        variant(self.variant, ser::SerializeStruct::end(self.inner)?)
+    }
+}
+
+/// Serializes the keys of maps, which must be strings or integers in PHP.
+struct MapKeySerializer;
+
+/// Returns the error for map keys which are not strings or integers.
+fn invalid_key() -> Error {
+    Error::custom("map keys must be strings or integers")
+}
+
+/// Converts an integer map key into an array key.
+fn long_key<T>(value: T) -> Result<ArrayKey<'static>>
+where
+    T: TryInto<i64> + Copy + std::fmt::Display,
+{
+    value
+        .try_into()
+        .map(ArrayKey::Long)
+        .map_err(|_| Error::custom(format!("map key {value} does not fit into a PHP int")))
+}
+
+impl ser::Serializer for MapKeySerializer {
+    type Ok = ArrayKey<'static>;
+    type Error = Error;
+
+    type SerializeSeq = Impossible<ArrayKey<'static>, Error>;
+    type SerializeTuple = Impossible<ArrayKey<'static>, Error>;
+    type SerializeTupleStruct = Impossible<ArrayKey<'static>, Error>;
+    type SerializeTupleVariant = Impossible<ArrayKey<'static>, Error>;
+    type SerializeMap = Impossible<ArrayKey<'static>, Error>;
+    type SerializeStruct = Impossible<ArrayKey<'static>, Error>;
+    type SerializeStructVariant = Impossible<ArrayKey<'static>, Error>;
+
+    fn serialize_bool(self, v: bool) -> Result<Self::Ok> {
+        Ok(ArrayKey::Long(v.into()))
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Self::Ok> {
+        long_key(v)
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Self::Ok> {
+        long_key(v)
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Self::Ok> {
+        long_key(v)
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Self::Ok> {
+        long_key(v)
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<Self::Ok> {
+        long_key(v)
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Self::Ok> {
+        long_key(v)
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Self::Ok> {
+        long_key(v)
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Self::Ok> {
+        long_key(v)
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Self::Ok> {
+        long_key(v)
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<Self::Ok> {
+        long_key(v)
+    }
+
+    fn serialize_f32(self, _v: f32) -> Result<Self::Ok> {
+        Err(invalid_key())
+    }
+
+    fn serialize_f64(self, _v: f64) -> Result<Self::Ok> {
+        Err(invalid_key())
+    }
+
+    fn serialize_char(self, v: char) -> Result<Self::Ok> {
+        Ok(ArrayKey::String(v.to_string()))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Self::Ok> {
+        Ok(ArrayKey::String(v.to_owned()))
+    }
+
+    fn serialize_bytes(self, _v: &[u8]) -> Result<Self::Ok> {
+        Err(invalid_key())
+    }
+
+    fn serialize_none(self) -> Result<Self::Ok> {
+        Err(invalid_key())
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, _value: &T) -> Result<Self::Ok> {
+        Err(invalid_key())
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok> {
+        Err(invalid_key())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Self::Ok> {
+        Err(invalid_key())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Self::Ok> {
+        Ok(ArrayKey::Str(variant))
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<Self::Ok> {
+        Err(invalid_key())
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq> {
+        Err(invalid_key())
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple> {
+        Err(invalid_key())
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct> {
+        Err(invalid_key())
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant> {
+        Err(invalid_key())
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap> {
+        Err(invalid_key())
+    }
+
+    fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<Self::SerializeStruct> {
+        Err(invalid_key())
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant> {
+        Err(invalid_key())
+    }
+}
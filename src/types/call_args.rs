@@ -0,0 +1,176 @@
+//! Builder for the arguments passed when calling PHP functions and methods.
+
+use std::ptr;
+
+use crate::{
+    boxed::ZBox,
+    convert::IntoZval,
+    error::{Error, Result},
+    ffi::HashTable,
+    types::{ArrayKey, ZendHashTable, Zval},
+};
+
+/// Arguments passed when calling a PHP callable, function or method, e.g.
+/// through [`ZendCallable::try_call_with`].
+///
+/// Unlike the list of values accepted by [`ZendCallable::try_call`], the
+/// arguments can be passed by name or by reference, and arrays can be unpacked
+/// into the arguments like `...$args` in PHP. Positional arguments must be
+/// given before named arguments.
+///
+/// # Example
+///
+/// ```no_run
+/// use ext_php_rs::types::{CallArgs, ZendCallable, Zval};
+///
+/// let mut matches = Zval::new();
+/// let preg_match = ZendCallable::try_from_name("preg_match").unwrap();
+/// let args = CallArgs::new()
+///     .arg("/(\\d+)/")
+///     .unwrap()
+///     .arg("abc 123")
+///     .unwrap()
+///     .by_ref(&mut matches)
+///     .unwrap();
+/// preg_match.try_call_with(args).unwrap();
+/// assert!(matches.is_array());
+///
+/// let json_encode = ZendCallable::try_from_name("json_encode").unwrap();
+/// let args = CallArgs::new()
+///     .arg(vec![1, 2])
+///     .unwrap()
+///     .named("flags", 128)
+///     .unwrap();
+/// let json = json_encode.try_call_with(args).unwrap();
+/// ```
+///
+/// [`ZendCallable::try_call_with`]: crate::types::ZendCallable::try_call_with
+/// [`ZendCallable::try_call`]: crate::types::ZendCallable::try_call
+#[must_use]
+#[derive(Debug, Default)]
+pub struct CallArgs<'a> {
+    params: Vec<Zval>,
+    named: Option<ZBox<ZendHashTable>>,
+    refs: Vec<(usize, &'a mut Zval)>,
+}
+
+impl<'a> CallArgs<'a> {
+    /// Creates an empty list of arguments.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a positional argument.
+    ///
+    /// # Parameters
+    ///
+    /// * `val` - The value of the argument.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::PositionalAfterNamedArgument`] - If a named argument has
+    ///   already been added.
+    /// * If the value could not be converted into a [`Zval`].
+    pub fn arg(mut self, val: impl IntoZval) -> Result<Self> {
+        self.check_positional()?;
+        self.params.push(val.into_zval(false)?);
+        Ok(self)
+    }
+
+    /// Adds a positional argument passed by reference. The value is updated
+    /// with the value of the reference after the call, so changes made by the
+    /// callee to parameters declared by reference, e.g. `function f(&$x)`, are
+    /// visible to the caller.
+    ///
+    /// # Parameters
+    ///
+    /// * `val` - The value of the argument.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::PositionalAfterNamedArgument`] - If a named argument has
+    ///   already been added.
+    pub fn by_ref(mut self, val: &'a mut Zval) -> Result<Self> {
+        self.check_positional()?;
+        let mut param = Zval::new();
+        param.set_reference(val.shallow_clone());
+        self.refs.push((self.params.len(), val));
+        self.params.push(param);
+        Ok(self)
+    }
+
+    /// Adds a named argument, like `f(name: $val)` in PHP. Adding an argument
+    /// with the same name twice replaces the previous value.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - The name of the parameter.
+    /// * `val` - The value of the argument.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value could not be converted into a [`Zval`].
+    pub fn named(mut self, name: &str, val: impl IntoZval) -> Result<Self> {
+        self.named
+            .get_or_insert_with(ZendHashTable::new)
+            .insert(ArrayKey::Str(name), val)?;
+        Ok(self)
+    }
+
+    /// Unpacks an array into the arguments, like `f(...$args)` in PHP.
+    /// Elements with integer keys are added as positional arguments and
+    /// elements with string keys as named arguments.
+    ///
+    /// # Parameters
+    ///
+    /// * `args` - The array to unpack.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::PositionalAfterNamedArgument`] - If an element with an
+    ///   integer key follows a named argument.
+    /// * If a value could not be copied into the arguments.
+    pub fn spread(mut self, args: &ZendHashTable) -> Result<Self> {
+        for (key, val) in args {
+            let val = val.dereference().shallow_clone();
+            self = match key {
+                ArrayKey::Long(_) => self.arg(val)?,
+                ArrayKey::String(name) => self.named(&name, val)?,
+                ArrayKey::Str(name) => self.named(name, val)?,
+            };
+        }
+        Ok(self)
+    }
+
+    fn check_positional(&self) -> Result<()> {
+        if self.named.is_some() {
+            return Err(Error::PositionalAfterNamedArgument);
+        }
+        Ok(())
+    }
+
+    /// Returns the number of positional arguments.
+    pub(crate) fn param_count(&self) -> usize {
+        self.params.len()
+    }
+
+    /// Returns the positional arguments, as passed to the Zend call functions.
+    pub(crate) fn params(&mut self) -> *mut Zval {
+        self.params.as_mut_ptr()
+    }
+
+    /// Returns the named arguments, or a null pointer if there are none.
+    pub(crate) fn named_params(&mut self) -> *mut HashTable {
+        self.named
+            .as_deref_mut()
+            .map_or(ptr::null_mut(), ptr::from_mut)
+    }
+
+    /// Updates the values passed by reference with the values of their
+    /// references after a call.
+    pub(crate) fn write_back(self) {
+        for (i, val) in self.refs {
+            *val = self.params[i].dereference().shallow_clone();
+        }
+    }
+}
@@ -1,16 +1,19 @@
 //! Types related to callables in PHP (anonymous functions, functions, etc).
 
-use std::{convert::TryFrom, ops::Deref, ptr};
+use std::{convert::TryFrom, fmt::Debug, mem, ops::Deref, ptr};
 
 use crate::{
     convert::{FromZval, IntoZvalDyn},
     error::{Error, Result},
-    ffi::_call_user_function_impl,
+    ffi::{
+        _call_user_function_impl, zend_call_function, zend_fcall_info, zend_fcall_info_cache,
+        zend_is_callable_ex, zend_release_fcall_info_cache, ZEND_ACC_CALL_VIA_TRAMPOLINE,
+    },
     flags::DataType,
     zend::ExecutorGlobals,
 };
 
-use super::Zval;
+use super::{CallArgs, Zval};
 
 /// Acts as a wrapper around a callable [`Zval`]. Allows the owner to call the
 /// [`Zval`] as if it was a PHP function through the [`try_call`] method.
@@ -150,6 +153,94 @@ impl<'a> ZendCallable<'a> {
     }
 }
 
+impl<'a> ZendCallable<'a> {
+    /// Prepares the callable for repeated calls, resolving the function to
+    /// call only once. See [`PreparedCall`].
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::Callable`] - If the callable could not be resolved.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use ext_php_rs::types::ZendCallable;
+    ///
+    /// let strlen = ZendCallable::try_from_name("strlen").unwrap();
+    /// let mut strlen = strlen.prepare().unwrap();
+    /// for word in ["a", "bb", "ccc"] {
+    ///     let len = strlen.call(&[&word]).unwrap();
+    ///     assert_eq!(len.long(), Some(word.len() as i64));
+    /// }
+    /// ```
+    pub fn prepare(self) -> Result<PreparedCall<'a>> {
+        PreparedCall::new(self.0)
+    }
+
+    /// Attempts to call the callable with arguments built with [`CallArgs`],
+    /// which may be passed by name or by reference.
+    ///
+    /// # Parameters
+    ///
+    /// * `args` - The arguments to call the function with.
+    ///
+    /// # Returns
+    ///
+    /// Returns the result wrapped in [`Ok`] upon success.
+    ///
+    /// # Errors
+    ///
+    /// * If calling the callable fails, or an exception is thrown, an [`Err`]
+    ///   is returned.
+    /// * If the number of parameters exceeds `u32::MAX`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use ext_php_rs::types::{CallArgs, ZendCallable};
+    ///
+    /// let str_pad = ZendCallable::try_from_name("str_pad").unwrap();
+    /// let args = CallArgs::new()
+    ///     .arg("5")
+    ///     .unwrap()
+    ///     .named("length", 3)
+    ///     .unwrap()
+    ///     .named("pad_string", "0")
+    ///     .unwrap()
+    ///     .named("pad_type", 0)
+    ///     .unwrap();
+    /// let result = str_pad.try_call_with(args).unwrap();
+    /// assert_eq!(result.str(), Some("005"));
+    /// ```
+    pub fn try_call_with(&self, mut args: CallArgs<'_>) -> Result<Zval> {
+        if !self.0.is_callable() {
+            return Err(Error::Callable);
+        }
+
+        let mut retval = Zval::new();
+        let result = unsafe {
+            #[allow(clippy::used_underscore_items)]
+            _call_user_function_impl(
+                ptr::null_mut(),
+                ptr::from_ref(self.0.as_ref()).cast_mut(),
+                &raw mut retval,
+                args.param_count().try_into()?,
+                args.params(),
+                args.named_params(),
+            )
+        };
+        args.write_back();
+
+        if result < 0 {
+            Err(Error::Callable)
+        } else if let Some(e) = ExecutorGlobals::take_exception() {
+            Err(Error::Exception(e))
+        } else {
+            Ok(retval)
+        }
+    }
+}
+
 impl<'a> FromZval<'a> for ZendCallable<'a> {
     const TYPE: DataType = DataType::Callable;
 
@@ -166,6 +257,128 @@ impl TryFrom<Zval> for ZendCallable<'_> {
     }
 }
 
+/// A callable prepared for repeated calls, e.g. a callback invoked for every
+/// element of an array. Created with [`ZendCallable::prepare`].
+///
+/// [`ZendCallable::try_call`] checks that the value is callable and looks up
+/// the function on every call. A prepared call resolves the function once,
+/// when it is created, and reuses the buffer holding the parameters between
+/// calls.
+pub struct PreparedCall<'a> {
+    callable: OwnedZval<'a>,
+    fcc: zend_fcall_info_cache,
+    /// Whether the function is a temporary function calling `__call` or
+    /// `__callStatic`, which is freed by PHP after each call.
+    trampoline: bool,
+    params: Vec<Zval>,
+}
+
+impl<'a> PreparedCall<'a> {
+    fn new(callable: OwnedZval<'a>) -> Result<Self> {
+        let mut call = Self {
+            callable,
+            // SAFETY: The cache is a plain struct of pointers, which is filled
+            // in by `resolve`.
+            fcc: unsafe { mem::zeroed() },
+            trampoline: false,
+            params: vec![],
+        };
+        call.resolve()?;
+        Ok(call)
+    }
+
+    /// Resolves the function to call into the call info cache.
+    fn resolve(&mut self) -> Result<()> {
+        let callable = unsafe {
+            zend_is_callable_ex(
+                ptr::from_ref(self.callable.as_ref()).cast_mut(),
+                ptr::null_mut(),
+                0,
+                ptr::null_mut(),
+                &raw mut self.fcc,
+                ptr::null_mut(),
+            )
+        };
+        let Some(func) = (unsafe { self.fcc.function_handler.as_ref() }).filter(|_| callable)
+        else {
+            self.fcc.function_handler = ptr::null_mut();
+            return Err(Error::Callable);
+        };
+        self.trampoline = unsafe { func.common.fn_flags } & ZEND_ACC_CALL_VIA_TRAMPOLINE != 0;
+        Ok(())
+    }
+
+    /// Calls the prepared callable with a list of arguments.
+    ///
+    /// # Parameters
+    ///
+    /// * `params` - A list of parameters to call the function with.
+    ///
+    /// # Returns
+    ///
+    /// Returns the result wrapped in [`Ok`] upon success.
+    ///
+    /// # Errors
+    ///
+    /// * If calling the callable fails, or an exception is thrown, an [`Err`]
+    ///   is returned.
+    /// * If a parameter could not be converted into a [`Zval`].
+    /// * If the number of parameters exceeds `u32::MAX`.
+    pub fn call(&mut self, params: &[&dyn IntoZvalDyn]) -> Result<Zval> {
+        if self.fcc.function_handler.is_null() {
+            self.resolve()?;
+        }
+
+        self.params.clear();
+        for param in params {
+            self.params.push(param.as_zval(false)?);
+        }
+
+        let mut retval = Zval::new();
+        let mut fci = zend_fcall_info {
+            size: mem::size_of::<zend_fcall_info>(),
+            // The function is taken from the cache.
+            function_name: Zval::new(),
+            retval: &raw mut retval,
+            params: self.params.as_mut_ptr(),
+            object: self.fcc.object,
+            param_count: self.params.len().try_into()?,
+            named_params: ptr::null_mut(),
+        };
+        let result = unsafe { zend_call_function(&raw mut fci, &raw mut self.fcc) };
+        if self.trampoline {
+            // The trampoline has been freed, so it is resolved again on the next
+            // call.
+            self.fcc.function_handler = ptr::null_mut();
+        }
+
+        if result < 0 {
+            Err(Error::Callable)
+        } else if let Some(e) = ExecutorGlobals::take_exception() {
+            Err(Error::Exception(e))
+        } else {
+            Ok(retval)
+        }
+    }
+}
+
+impl Debug for PreparedCall<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PreparedCall")
+            .field("callable", &self.callable)
+            .field("trampoline", &self.trampoline)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Drop for PreparedCall<'_> {
+    fn drop(&mut self) {
+        if !self.fcc.function_handler.is_null() {
+            unsafe { zend_release_fcall_info_cache(&raw mut self.fcc) };
+        }
+    }
+}
+
 /// A container for a zval. Either contains a reference to a zval or an owned
 /// zval.
 #[derive(Debug)]
@@ -9,6 +9,8 @@ use std::{
     ptr::{self, NonNull},
 };
 
+#[cfg(php81)]
+use crate::flags::PropertyFlags;
 use crate::{
     boxed::{ZBox, ZBoxable},
     class::RegisteredClass,
@@ -16,9 +18,10 @@ use crate::{
     error::{Error, Result},
     ffi::{
         ext_php_rs_zend_object_alloc, ext_php_rs_zend_object_release, object_properties_init,
-        zend_object, zend_object_std_init, zend_objects_clone_members,
+        zend_object, zend_object_std_init, zend_objects_clone_members, zend_property_info,
+        zend_ref_del_type_source,
     },
-    flags::DataType,
+    flags::{DataType, ZvalTypeFlags},
     types::{ZendObject, Zval},
     zend::ClassEntry,
 };
@@ -29,6 +32,9 @@ use crate::{
 pub struct ZendClassObject<T> {
     /// The object stored inside the class object.
     pub obj: Option<T>,
+    /// Whether the declared property slots hold values of Rust properties
+    /// handed to PHP for indirect modification, e.g. `$obj->items[] = 1`.
+    pub(crate) indirect: bool,
     /// The standard zend object.
     pub std: ZendObject,
 }
@@ -122,6 +128,7 @@ impl<T: RegisteredClass> ZendClassObject<T> {
         // As the data in `obj.obj` is uninitialized, we don't want to drop
         // the data, but directly override it.
         ptr::write(&raw mut obj.obj, val);
+        ptr::write(&raw mut obj.indirect, false);
 
         obj.std.handlers = meta.handlers();
         ZBox::from_raw(obj)
@@ -154,7 +161,9 @@ impl<T: RegisteredClass> ZendClassObject<T> {
     /// * If the std offset over/underflows `isize`.
     #[must_use]
     pub fn from_zend_obj(std: &zend_object) -> Option<&Self> {
-        Some(Self::internal_from_zend_obj(std)?)
+        let obj = Self::internal_from_zend_obj(std)?;
+        obj.write_back_indirect();
+        Some(obj)
     }
 
     /// Returns a mutable reference to the [`ZendClassObject`] of a given zend
@@ -170,13 +179,18 @@ impl<T: RegisteredClass> ZendClassObject<T> {
     /// * If the std offset over/underflows `isize`.
     #[allow(clippy::needless_pass_by_ref_mut)]
     pub fn from_zend_obj_mut(std: &mut zend_object) -> Option<&mut Self> {
-        Self::internal_from_zend_obj(std)
+        let obj = Self::internal_from_zend_obj(std)?;
+        obj.write_back_indirect();
+        Some(obj)
     }
 
+    /// Returns the [`ZendClassObject`] of a given zend object without writing
+    /// back indirectly modified properties, for handlers which must not call
+    /// into the Rust value, such as the destructor.
     // TODO: Verify if this is safe to use, as it allows mutating the
     // hashtable while only having a reference to it. #461
     #[allow(clippy::mut_from_ref)]
-    fn internal_from_zend_obj(std: &zend_object) -> Option<&mut Self> {
+    pub(crate) fn internal_from_zend_obj(std: &zend_object) -> Option<&mut Self> {
         let std = ptr::from_ref(std).cast::<c_char>();
         let ptr = unsafe {
             let offset = isize::try_from(Self::std_offset()).expect("Offset overflow");
@@ -191,6 +205,104 @@ impl<T: RegisteredClass> ZendClassObject<T> {
         }
     }
 
+    /// Returns the declared slot of the Rust property `name`, which holds the
+    /// value of the property while PHP modifies it indirectly. The value is
+    /// written back into the Rust value the next time the object is
+    /// accessed.
+    ///
+    /// Returns [`None`] if the property has no slot.
+    pub(crate) fn indirect_slot(&mut self, name: &str) -> Option<&mut Zval> {
+        if !self.indirect {
+            // Until then, the slots hold the declared defaults of the properties.
+            for name in Self::indirect_properties() {
+                drop(self.take_slot(name));
+            }
+            self.indirect = true;
+        }
+        let info = self.property_info(name)?;
+        self.slot(info)
+    }
+
+    /// Writes the values of indirectly modified properties back from their
+    /// slots into the Rust value. PHP references to the slots are detached.
+    ///
+    /// Values which cannot be converted into the type of their property throw
+    /// an exception, and the property keeps its previous value.
+    fn write_back_indirect(&mut self) {
+        if !mem::take(&mut self.indirect) {
+            return;
+        }
+        let props = T::get_metadata().get_properties();
+        for name in Self::indirect_properties() {
+            let (Some(value), Some(obj)) = (self.take_slot(name), self.obj.as_mut()) else {
+                continue;
+            };
+            if let Err(e) = props[name].prop.set(obj, value.dereference()) {
+                let _ = e.throw();
+            }
+        }
+    }
+
+    /// Returns the names of the Rust properties which can be modified
+    /// indirectly. Readonly properties use their slot to track whether they
+    /// have been initialized.
+    fn indirect_properties() -> impl Iterator<Item = &'static str> {
+        T::get_metadata()
+            .get_properties()
+            .iter()
+            .filter(|(_, prop_info)| {
+                #[cfg(php81)]
+                if prop_info.flags.contains(PropertyFlags::Readonly) {
+                    return false;
+                }
+                #[cfg(not(php81))]
+                let _ = prop_info;
+                true
+            })
+            .map(|(&name, _)| name)
+    }
+
+    /// Returns the declaration of the property `name` in the class of the
+    /// object.
+    fn property_info(&self, name: &str) -> Option<&'static zend_property_info> {
+        // SAFETY: The class entry of an object outlives the object, and its
+        // property table holds pointers to property declarations.
+        unsafe {
+            let ce = self.std.ce.as_ref()?;
+            ce.properties_info
+                .get(name)?
+                .ptr::<zend_property_info>()?
+                .as_ref()
+        }
+    }
+
+    /// Returns the slot of the declared property `info` in the object.
+    fn slot(&mut self, info: &zend_property_info) -> Option<&mut Zval> {
+        let offset = usize::try_from(info.offset).ok()?;
+        // SAFETY: Declared properties are stored at their offset from the start
+        // of the standard object.
+        unsafe { (&raw mut self.std).byte_add(offset).cast::<Zval>().as_mut() }
+    }
+
+    /// Takes the value out of the slot of the property `name`, leaving the
+    /// slot uninitialized. Returns [`None`] if the slot holds no value.
+    fn take_slot(&mut self, name: &str) -> Option<Zval> {
+        let info = self.property_info(name)?;
+        let slot = self.slot(info)?;
+        if slot.get_type() == DataType::Undef {
+            return None;
+        }
+        let value = mem::take(slot);
+        slot.u1.type_info = ZvalTypeFlags::Undef.bits();
+        // References to typed properties check assignments against the type of
+        // each property they are bound to.
+        if value.is_reference() && info.type_.type_mask != 0 {
+            // SAFETY: The reference is bound to the typed property slot.
+            unsafe { zend_ref_del_type_source(&raw mut (*value.value.ref_).sources, info) };
+        }
+        Some(value)
+    }
+
     /// Returns a mutable reference to the underlying Zend object.
     pub fn get_mut_zend_obj(&mut self) -> &mut zend_object {
         &mut self.std
@@ -278,7 +390,10 @@ impl<T: RegisteredClass + Clone> Clone for ZBox<ZendClassObject<T>> {
         // `ZendClassObject` pointer will contain a valid, initialized `obj`,
         // therefore we can dereference both safely.
         unsafe {
-            let mut new = ZendClassObject::new((***self).clone());
+            // Properties modified indirectly are written back before cloning.
+            let this = ZendClassObject::<T>::from_zend_obj(&self.std)
+                .expect("Invalid object pointer given");
+            let mut new = ZendClassObject::new((**this).clone());
             zend_objects_clone_members(&raw mut new.std, (&raw const self.std).cast_mut());
             new
         }
@@ -4,22 +4,26 @@
 //! these PHP types when required.
 
 mod array;
+mod call_args;
 mod callable;
 mod class_object;
 mod iterable;
 mod iterator;
 mod long;
 mod object;
+mod resource;
 mod string;
 mod zval;
 
 pub use array::{ArrayKey, ZendHashTable};
-pub use callable::ZendCallable;
+pub use call_args::CallArgs;
+pub use callable::{PreparedCall, ZendCallable};
 pub use class_object::ZendClassObject;
 pub use iterable::Iterable;
 pub use iterator::ZendIterator;
 pub use long::ZendLong;
 pub use object::{PropertyQuery, ZendObject};
+pub use resource::{RegisteredResource, Resource, ResourceRef, ResourceRefMut, ResourceType};
 pub use string::ZendStr;
 pub use zval::Zval;
 
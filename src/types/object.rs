@@ -15,7 +15,7 @@ use crate::{
     },
     flags::DataType,
     rc::PhpRc,
-    types::{ZendClassObject, ZendStr, Zval},
+    types::{CallArgs, ZendClassObject, ZendStr, Zval},
     zend::{ce, ClassEntry, ExecutorGlobals, ZendObjectHandlers},
 };
 
@@ -203,6 +203,48 @@ impl ZendObject {
         Ok(retval)
     }
 
+    /// Tries to call a method on the object with arguments built with
+    /// [`CallArgs`], which may be passed by name or by reference.
+    ///
+    /// # Returns
+    ///
+    /// Returns the return value of the method, or an error if the method
+    /// could not be found or called.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::Callable` - If the method could not be found.
+    /// * If the parameter count is bigger than `u32::MAX`.
+    pub fn try_call_method_with(&self, name: &str, mut args: CallArgs<'_>) -> Result<Zval> {
+        let mut retval = Zval::new();
+
+        unsafe {
+            let res = zend_hash_str_find_ptr_lc(
+                &raw const (*self.ce).function_table,
+                name.as_ptr().cast::<c_char>(),
+                name.len(),
+            )
+            .cast::<zend_function>();
+
+            if res.is_null() {
+                return Err(Error::Callable);
+            }
+
+            zend_call_known_function(
+                res,
+                ptr::from_ref(self).cast_mut(),
+                self.ce,
+                &raw mut retval,
+                args.param_count().try_into()?,
+                args.params(),
+                args.named_params(),
+            );
+        };
+        args.write_back();
+
+        Ok(retval)
+    }
+
     /// Attempts to read a property from the Object. Returns a result containing
     /// the value of the property if it exists and can be read, and an
     /// [`Error`] otherwise.
@@ -0,0 +1,379 @@
+//! Types for defining PHP resource types and passing resources between Rust
+//! and PHP.
+//!
+//! Resources are a legacy PHP type used to hold values of extensions, such as
+//! file handles or database connections, which are not exposed as objects.
+//! New extensions should prefer classes, but resources are still required to
+//! interoperate with APIs passing them.
+
+use std::{
+    cell::{Cell, UnsafeCell},
+    ffi::CString,
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+    os::raw::c_void,
+    ptr::NonNull,
+    sync::atomic::{AtomicI32, AtomicPtr, Ordering},
+};
+
+use crate::{
+    convert::{FromZval, IntoZval},
+    error::{Error, Result},
+    ffi::{
+        zend_list_close, zend_register_list_destructors_ex, zend_register_resource, zend_resource,
+    },
+    flags::DataType,
+    types::Zval,
+};
+
+/// Implemented on Rust types which are passed to PHP as resources.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use ext_php_rs::types::{RegisteredResource, ResourceType};
+///
+/// pub struct Connection {
+///     dsn: String,
+/// }
+///
+/// static CONNECTION: ResourceType<Connection> = ResourceType::new("db connection");
+///
+/// impl RegisteredResource for Connection {
+///     fn resource_type() -> &'static ResourceType<Self> {
+///         &CONNECTION
+///     }
+/// }
+/// ```
+pub trait RegisteredResource: Sized + 'static {
+    /// Returns a reference to the resource type of the Rust type.
+    fn resource_type() -> &'static ResourceType<Self>;
+}
+
+/// A PHP resource type holding values of type `T`.
+///
+/// The resource type must be registered with
+/// [`ModuleBuilder::resource`](crate::builders::ModuleBuilder::resource), or
+/// by calling [`ResourceType::register`] inside the module startup function.
+/// The Rust value of a resource is dropped when the resource is freed or
+/// closed by PHP, or when the last [`ResourceRef`] or [`ResourceRefMut`]
+/// borrowing it is dropped if it was still borrowed at that time.
+pub struct ResourceType<T> {
+    name: &'static str,
+    id: AtomicI32,
+
+    // `AtomicPtr` is used here because it is `Send + Sync`.
+    phantom: PhantomData<AtomicPtr<T>>,
+}
+
+impl<T> ResourceType<T> {
+    /// Creates a new resource type, which must be registered before use.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - The name of the resource type, as returned by
+    ///   `get_resource_type()`.
+    #[must_use]
+    pub const fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            id: AtomicI32::new(-1),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Returns the name of the resource type.
+    #[must_use]
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the ID assigned to the resource type by PHP, or [`None`] if the
+    /// resource type has not been registered.
+    #[must_use]
+    pub fn id(&self) -> Option<i32> {
+        let id = self.id.load(Ordering::SeqCst);
+        (id >= 0).then_some(id)
+    }
+}
+
+impl<T: RegisteredResource> ResourceType<T> {
+    /// Registers the resource type with PHP. This function should only be
+    /// called once inside your module startup function.
+    ///
+    /// # Parameters
+    ///
+    /// * `module_number` - The module number passed to the module startup
+    ///   function.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the name contains a NUL byte, or if PHP failed to
+    /// register the resource type.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resource type has already been registered.
+    pub fn register(&self, module_number: i32) -> Result<()> {
+        assert!(
+            self.id().is_none(),
+            "Resource type `{}` already registered.",
+            self.name
+        );
+
+        // PHP keeps a pointer to the name for as long as the type is registered.
+        let name = CString::new(self.name)?.into_raw();
+        let id = unsafe {
+            zend_register_list_destructors_ex(Some(Self::dtor), None, name, module_number)
+        };
+        if id < 0 {
+            // SAFETY: The name was created above and is not used by PHP.
+            drop(unsafe { CString::from_raw(name) });
+            return Err(Error::ResourceTypeRegistrationFailure);
+        }
+        self.id.store(id, Ordering::SeqCst);
+        Ok(())
+    }
+
+    /// Destructor called by PHP when a resource of the type is freed or
+    /// closed.
+    unsafe extern "C" fn dtor(res: *mut zend_resource) {
+        let Some(res) = res.as_mut() else {
+            return;
+        };
+        let Some(cell) = NonNull::new(res.ptr.cast::<ResourceCell<T>>()) else {
+            return;
+        };
+        // Values which are still borrowed are dropped when the last borrow ends.
+        cell.as_ref().closed.set(true);
+        ResourceCell::release(cell);
+    }
+
+    /// Returns the value of a resource if it is of this type and has not been
+    /// closed.
+    pub(crate) fn value(&self, res: *mut zend_resource) -> Option<NonNull<ResourceCell<T>>> {
+        // SAFETY: The pointer is either null or points to a valid resource.
+        let res = unsafe { res.as_ref() }?;
+        if self.id() != Some(res.type_) {
+            return None;
+        }
+        NonNull::new(res.ptr.cast::<ResourceCell<T>>())
+    }
+}
+
+/// The value of a resource, along with the state of its borrows.
+pub(crate) struct ResourceCell<T> {
+    value: UnsafeCell<T>,
+    /// Number of shared borrows, or `-1` while the value is mutably borrowed.
+    borrows: Cell<isize>,
+    /// Whether the resource has been freed or closed by PHP.
+    closed: Cell<bool>,
+}
+
+impl<T> ResourceCell<T> {
+    /// Borrows the value, or returns [`None`] if it is mutably borrowed.
+    pub(crate) fn borrow<'a>(cell: NonNull<Self>) -> Option<ResourceRef<'a, T>> {
+        // SAFETY: The cell is valid until it is closed and no longer borrowed.
+        let borrows = unsafe { &cell.as_ref().borrows };
+        if borrows.get() < 0 {
+            return None;
+        }
+        borrows.set(borrows.get() + 1);
+        Some(ResourceRef {
+            cell,
+            phantom: PhantomData,
+        })
+    }
+
+    /// Mutably borrows the value, or returns [`None`] if it is borrowed.
+    fn borrow_mut<'a>(cell: NonNull<Self>) -> Option<ResourceRefMut<'a, T>> {
+        // SAFETY: The cell is valid until it is closed and no longer borrowed.
+        let borrows = unsafe { &cell.as_ref().borrows };
+        if borrows.get() != 0 {
+            return None;
+        }
+        borrows.set(-1);
+        Some(ResourceRefMut {
+            cell,
+            phantom: PhantomData,
+        })
+    }
+
+    /// Drops the cell if the resource has been closed and the value is no
+    /// longer borrowed.
+    fn release(cell: NonNull<Self>) {
+        // SAFETY: The cell is valid until it is closed and no longer borrowed,
+        // which is checked before dropping it.
+        unsafe {
+            if cell.as_ref().closed.get() && cell.as_ref().borrows.get() == 0 {
+                drop(Box::from_raw(cell.as_ptr()));
+            }
+        }
+    }
+}
+
+/// A shared borrow of the value of a resource, returned by [`Resource::get`]
+/// and [`Zval::resource_as`].
+///
+/// The value stays alive while it is borrowed, even if the resource is closed
+/// or freed by PHP in the meantime.
+pub struct ResourceRef<'a, T> {
+    cell: NonNull<ResourceCell<T>>,
+    phantom: PhantomData<&'a T>,
+}
+
+impl<T> Deref for ResourceRef<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        // SAFETY: The value is not mutably borrowed while this borrow exists.
+        unsafe { &*self.cell.as_ref().value.get() }
+    }
+}
+
+impl<T> Drop for ResourceRef<'_, T> {
+    fn drop(&mut self) {
+        // SAFETY: The cell is kept alive by this borrow.
+        let borrows = unsafe { &self.cell.as_ref().borrows };
+        borrows.set(borrows.get() - 1);
+        ResourceCell::release(self.cell);
+    }
+}
+
+/// A mutable borrow of the value of a resource, returned by
+/// [`Resource::get_mut`].
+///
+/// The value stays alive while it is borrowed, even if the resource is closed
+/// or freed by PHP in the meantime.
+pub struct ResourceRefMut<'a, T> {
+    cell: NonNull<ResourceCell<T>>,
+    phantom: PhantomData<&'a mut T>,
+}
+
+impl<T> Deref for ResourceRefMut<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        // SAFETY: The value is exclusively borrowed by this borrow.
+        unsafe { &*self.cell.as_ref().value.get() }
+    }
+}
+
+impl<T> DerefMut for ResourceRefMut<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        // SAFETY: The value is exclusively borrowed by this borrow.
+        unsafe { &mut *self.cell.as_ref().value.get() }
+    }
+}
+
+impl<T> Drop for ResourceRefMut<'_, T> {
+    fn drop(&mut self) {
+        // SAFETY: The cell is kept alive by this borrow.
+        unsafe { self.cell.as_ref().borrows.set(0) };
+        ResourceCell::release(self.cell);
+    }
+}
+
+/// A reference to a PHP resource holding a value of type `T`.
+///
+/// Cloning the reference increments the reference count of the resource, and
+/// the value is dropped once all references from Rust and PHP are released.
+/// Like a [`RefCell`](std::cell::RefCell), the value can be borrowed either
+/// by any number of [`ResourceRef`]s or by a single [`ResourceRefMut`] at a
+/// time, across all references to the resource.
+pub struct Resource<T> {
+    zval: Zval,
+    phantom: PhantomData<T>,
+}
+
+impl<T: RegisteredResource> Resource<T> {
+    /// Creates a new PHP resource holding the given value.
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - The value held by the resource.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resource type of `T` has not been registered.
+    pub fn new(value: T) -> Self {
+        let id = T::resource_type().id().unwrap_or_else(|| {
+            panic!(
+                "Attempted to create a resource of type `{}` before it has been registered.",
+                T::resource_type().name()
+            )
+        });
+        let value = Box::into_raw(Box::new(ResourceCell {
+            value: UnsafeCell::new(value),
+            borrows: Cell::new(0),
+            closed: Cell::new(false),
+        }));
+        let mut zval = Zval::new();
+        // The resource is created with a reference count of one, which is owned
+        // by the zval.
+        zval.set_resource(unsafe { zend_register_resource(value.cast::<c_void>(), id) });
+        Self {
+            zval,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Borrows the value of the resource. Returns [`None`] if the resource has
+    /// been closed, or if the value is currently mutably borrowed.
+    #[must_use]
+    pub fn get(&self) -> Option<ResourceRef<'_, T>> {
+        ResourceCell::borrow(self.value()?)
+    }
+
+    /// Mutably borrows the value of the resource. Returns [`None`] if the
+    /// resource has been closed, or if the value is currently borrowed.
+    #[must_use]
+    pub fn get_mut(&self) -> Option<ResourceRefMut<'_, T>> {
+        ResourceCell::borrow_mut(self.value()?)
+    }
+
+    /// Closes the resource. The resource stays valid in PHP until all
+    /// references are released, but is no longer of type `T`. The value is
+    /// dropped immediately, or once it is no longer borrowed.
+    pub fn close(&mut self) {
+        if let Some(res) = self.zval.resource() {
+            unsafe { zend_list_close(res) };
+        }
+    }
+
+    fn value(&self) -> Option<NonNull<ResourceCell<T>>> {
+        T::resource_type().value(self.zval.resource()?)
+    }
+}
+
+impl<T> Clone for Resource<T> {
+    fn clone(&self) -> Self {
+        Self {
+            zval: self.zval.shallow_clone(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: RegisteredResource> FromZval<'_> for Resource<T> {
+    const TYPE: DataType = DataType::Resource;
+
+    fn from_zval(zval: &Zval) -> Option<Self> {
+        T::resource_type().value(zval.resource()?)?;
+        Some(Self {
+            zval: zval.shallow_clone(),
+            phantom: PhantomData,
+        })
+    }
+}
+
+impl<T: RegisteredResource> IntoZval for Resource<T> {
+    const TYPE: DataType = DataType::Resource;
+    const NULLABLE: bool = false;
+
+    #[inline]
+    fn set_zval(self, zv: &mut Zval, _: bool) -> Result<()> {
+        *zv = self.zval;
+        Ok(())
+    }
+}
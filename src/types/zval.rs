@@ -2,24 +2,30 @@
 //! contains is determined by a property inside the struct. The content of the
 //! Zval is stored in a union.
 
-use std::{convert::TryInto, ffi::c_void, fmt::Debug, ptr};
+use std::{alloc::Layout, convert::TryInto, ffi::c_void, fmt::Debug, ptr};
 
 use crate::types::iterable::Iterable;
 use crate::types::ZendIterator;
 use crate::{
+    alloc::emalloc,
     binary::Pack,
     binary_slice::PackSlice,
     boxed::ZBox,
     convert::{FromZval, FromZvalMut, IntoZval, IntoZvalDyn},
     error::{Error, Result},
     ffi::{
-        _zval_struct__bindgen_ty_1, _zval_struct__bindgen_ty_2, zend_is_callable,
-        zend_is_identical, zend_is_iterable, zend_resource, zend_value, zval, zval_ptr_dtor,
+        _zend_refcounted_h__bindgen_ty_1, _zval_struct__bindgen_ty_1, _zval_struct__bindgen_ty_2,
+        zend_is_callable, zend_is_identical, zend_is_iterable, zend_property_info_source_list,
+        zend_refcounted_h, zend_reference, zend_resource, zend_value, zval, zval_ptr_dtor,
+        GC_FLAGS_SHIFT, GC_NOT_COLLECTABLE, IS_REFERENCE,
     },
     flags::DataType,
     flags::ZvalTypeFlags,
     rc::PhpRc,
-    types::{ZendCallable, ZendHashTable, ZendLong, ZendObject, ZendStr},
+    types::{
+        resource::ResourceCell, RegisteredResource, ResourceRef, ZendCallable, ZendHashTable,
+        ZendLong, ZendObject, ZendStr,
+    },
 };
 
 /// A zend value. This is the primary storage container used throughout the Zend
@@ -202,6 +208,14 @@ impl Zval {
         }
     }
 
+    /// Borrows the value of the zval if it is a resource of type `T` which has
+    /// not been closed. Returns [`None`] as well if the value is currently
+    /// mutably borrowed.
+    #[must_use]
+    pub fn resource_as<T: RegisteredResource>(&self) -> Option<ResourceRef<'_, T>> {
+        ResourceCell::borrow(T::resource_type().value(self.resource()?)?)
+    }
+
     /// Returns an immutable reference to the underlying zval hashtable if the
     /// zval contains an array.
     #[must_use]
@@ -606,6 +620,36 @@ impl Zval {
         self.value.res = val;
     }
 
+    /// Sets the value of the zval as a new PHP reference holding the given
+    /// value, like `$a = &$b` in PHP.
+    ///
+    /// # Parameters
+    ///
+    /// * `val` - The value held by the reference.
+    pub fn set_reference(&mut self, val: Zval) {
+        // The Zend memory manager aligns allocations to 8 bytes.
+        #[allow(clippy::cast_ptr_alignment)]
+        let reference = emalloc(Layout::new::<zend_reference>()).cast::<zend_reference>();
+        // SAFETY: The memory was allocated above with the size of a reference.
+        unsafe {
+            reference.write(zend_reference {
+                gc: zend_refcounted_h {
+                    refcount: 1,
+                    #[allow(clippy::used_underscore_items)]
+                    u: _zend_refcounted_h__bindgen_ty_1 {
+                        type_info: IS_REFERENCE | (GC_NOT_COLLECTABLE << GC_FLAGS_SHIFT),
+                    },
+                },
+                val,
+                sources: zend_property_info_source_list {
+                    ptr: ptr::null_mut(),
+                },
+            });
+        }
+        self.change_type(ZvalTypeFlags::ReferenceEx);
+        self.value.ref_ = reference;
+    }
+
     /// Sets the value of the zval as a reference to an object.
     ///
     /// # Parameters
@@ -755,6 +799,12 @@ impl Debug for Zval {
             DataType::Iterable => field!(self.iterable()),
             // SAFETY: We are not accessing the pointer.
             DataType::Ptr => field!(unsafe { self.ptr::<c_void>() }),
+            // Only used in type declarations, never the type of a zval.
+            DataType::Union(_)
+            | DataType::Intersection(_)
+            | DataType::Static
+            | DataType::Self_
+            | DataType::Never => field!(Option::<()>::None),
         };
 
         dbg.finish()
@@ -791,6 +841,15 @@ impl<'a> FromZval<'a> for &'a Zval {
     }
 }
 
+/// The value is shallow cloned, see [`Zval::shallow_clone`].
+impl FromZval<'_> for Zval {
+    const TYPE: DataType = DataType::Mixed;
+
+    fn from_zval(zval: &Zval) -> Option<Self> {
+        Some(zval.shallow_clone())
+    }
+}
+
 impl<'a> FromZvalMut<'a> for &'a mut Zval {
     const TYPE: DataType = DataType::Mixed;
 
@@ -6,6 +6,7 @@ use crate::{
         _ZEND_SEND_MODE_SHIFT, _ZEND_TYPE_NULLABLE_BIT,
     },
     flags::DataType,
+    types::ZendStr,
 };
 
 /// Internal Zend type.
@@ -51,6 +52,15 @@ impl ZendType {
             DataType::Object(Some(class)) => {
                 Self::empty_from_class_type(class, pass_by_ref, is_variadic, allow_null)
             }
+            DataType::Self_ => {
+                Self::empty_from_class_type("self", pass_by_ref, is_variadic, allow_null)
+            }
+            DataType::Union(union) => {
+                Self::empty_from_union_type(union, pass_by_ref, is_variadic, allow_null)
+            }
+            DataType::Intersection(classes) => {
+                Self::empty_from_intersection_type(classes, pass_by_ref, is_variadic)
+            }
             type_ => Some(Self::empty_from_primitive_type(
                 type_,
                 pass_by_ref,
@@ -60,6 +70,50 @@ impl ZendType {
         }
     }
 
+    /// Attempts to create a zend type for a declared class property. Returns an
+    /// option containing the type.
+    ///
+    /// Returns [`None`] if PHP does not allow properties of the given data
+    /// type, e.g. `callable` or `void`.
+    ///
+    /// # Parameters
+    ///
+    /// * `type_` - Data type to create zend type for.
+    /// * `allow_null` - Whether the property should accept `null`.
+    #[must_use]
+    pub fn property_type(type_: DataType, allow_null: bool) -> Option<Self> {
+        match type_ {
+            DataType::Object(Some(class)) => Some(Self {
+                // Property types of internal classes reference a persistent
+                // `zend_string`, not a C string as with argument types.
+                ptr: ptr::from_mut(ZendStr::new_interned(class, true).into_raw()).cast::<c_void>(),
+                type_mask: crate::ffi::_ZEND_TYPE_NAME_BIT
+                    | if allow_null {
+                        _ZEND_TYPE_NULLABLE_BIT
+                    } else {
+                        0
+                    },
+            }),
+            // `mixed` already includes `null`.
+            DataType::Mixed => Some(Self::empty_from_primitive_type(type_, false, false, false)),
+            DataType::True | DataType::False => Some(Self::empty_from_primitive_type(
+                DataType::Bool,
+                false,
+                false,
+                allow_null,
+            )),
+            DataType::Long
+            | DataType::Double
+            | DataType::String
+            | DataType::Array
+            | DataType::Object(None)
+            | DataType::Bool => Some(Self::empty_from_primitive_type(
+                type_, false, false, allow_null,
+            )),
+            _ => None,
+        }
+    }
+
     /// Attempts to create a zend type for a class object type. Returns an
     /// option containing the type if successful.
     ///
@@ -101,6 +155,149 @@ impl ZendType {
         })
     }
 
+    /// Attempts to create a zend type for a union type. Returns an option
+    /// containing the type if successful.
+    ///
+    /// The classes of the union are passed to PHP as a single class name
+    /// separated by `|`, which PHP splits when registering the function.
+    ///
+    /// Returns [`None`] if a class name could not be converted into a C
+    /// string (i.e. contained NUL-bytes).
+    ///
+    /// # Parameters
+    ///
+    /// * `types` - Types of the union.
+    /// * `pass_by_ref` - Whether the type should be passed by reference.
+    /// * `is_variadic` - Whether the type is for a variadic argument.
+    /// * `allow_null` - Whether the type should allow null to be passed in
+    ///   place.
+    fn empty_from_union_type(
+        types: &[DataType],
+        pass_by_ref: bool,
+        is_variadic: bool,
+        allow_null: bool,
+    ) -> Option<Self> {
+        fn collect(types: &[DataType], mask: &mut u32, classes: &mut Vec<&'static str>) {
+            for type_ in types {
+                let class = match *type_ {
+                    DataType::Object(Some(class)) => class,
+                    DataType::Self_ => "self",
+                    DataType::Union(inner) => {
+                        collect(inner, mask, classes);
+                        continue;
+                    }
+                    // PHP does not support intersections inside unions for
+                    // internal functions, so they are declared as `object`.
+                    DataType::Intersection(_) => {
+                        *mask |=
+                            ZendType::type_init_code(DataType::Object(None), false, false, false);
+                        continue;
+                    }
+                    other => {
+                        *mask |= ZendType::type_init_code(other, false, false, false);
+                        continue;
+                    }
+                };
+                if !classes.contains(&class) {
+                    classes.push(class);
+                }
+            }
+        }
+
+        let mut mask = Self::arg_info_flags(pass_by_ref, is_variadic);
+        if allow_null {
+            mask |= _ZEND_TYPE_NULLABLE_BIT;
+        }
+        let mut classes = vec![];
+        collect(types, &mut mask, &mut classes);
+        if classes.is_empty() {
+            return Some(Self {
+                ptr: ptr::null_mut::<c_void>(),
+                type_mask: mask,
+            });
+        }
+
+        let mut ty = Self::empty_from_class_type(&classes.join("|"), false, false, false)?;
+        ty.type_mask |= mask;
+        Some(ty)
+    }
+
+    /// Attempts to create a zend type for an intersection type. Returns an
+    /// option containing the type.
+    ///
+    /// Intersection types are declared as `object` before PHP 8.3, which
+    /// cannot register them for internal functions.
+    ///
+    /// # Parameters
+    ///
+    /// * `classes` - Classes and interfaces of the intersection.
+    /// * `pass_by_ref` - Whether the type should be passed by reference.
+    /// * `is_variadic` - Whether the type is for a variadic argument.
+    #[cfg_attr(not(php83), allow(clippy::unnecessary_wraps))]
+    fn empty_from_intersection_type(
+        classes: &[&str],
+        pass_by_ref: bool,
+        is_variadic: bool,
+    ) -> Option<Self> {
+        cfg_if::cfg_if! {
+            if #[cfg(php83)] {
+                use crate::ffi::{
+                    __zend_malloc, zend_type_list, _ZEND_TYPE_INTERSECTION_BIT,
+                    _ZEND_TYPE_LIST_BIT, _ZEND_TYPE_NAME_BIT,
+                };
+
+                if classes.len() < 2 {
+                    let class = classes.first()?;
+                    return Self::empty_from_class_type(class, pass_by_ref, is_variadic, false);
+                }
+
+                // PHP frees the list with `free()` when the function is
+                // unregistered, so it must be allocated with `malloc()`.
+                let size = std::mem::size_of::<zend_type_list>()
+                    + (classes.len() - 1) * std::mem::size_of::<zend_type>();
+                let list = unsafe {
+                    cfg_if::cfg_if! {
+                        if #[cfg(php_debug)] {
+                            #[allow(clippy::used_underscore_items)]
+                            __zend_malloc(size, ptr::null_mut(), 0, ptr::null_mut(), 0)
+                        } else {
+                            #[allow(clippy::used_underscore_items)]
+                            __zend_malloc(size)
+                        }
+                    }
+                }
+                .cast::<zend_type_list>();
+
+                unsafe {
+                    (*list).num_types = u32::try_from(classes.len()).ok()?;
+                    let types = (&raw mut (*list).types).cast::<zend_type>();
+                    for (i, class) in classes.iter().enumerate() {
+                        types.add(i).write(zend_type {
+                            ptr: ptr::from_mut(ZendStr::new_interned(class, true).into_raw())
+                                .cast::<c_void>(),
+                            type_mask: _ZEND_TYPE_NAME_BIT,
+                        });
+                    }
+                }
+
+                Some(Self {
+                    ptr: list.cast::<c_void>(),
+                    type_mask: _ZEND_TYPE_LIST_BIT
+                        | _ZEND_TYPE_INTERSECTION_BIT
+                        | Self::arg_info_flags(pass_by_ref, is_variadic),
+                })
+            } else {
+                let _ = classes;
+                Some(Self::empty_from_primitive_type(
+                    DataType::Object(None),
+                    pass_by_ref,
+                    is_variadic,
+                    false,
+                ))
+            }
+        }
+    }
+
     /// Attempts to create a zend type for a primitive PHP type.
     ///
     /// # Parameters
@@ -120,7 +317,13 @@ impl ZendType {
         is_variadic: bool,
         allow_null: bool,
     ) -> Self {
-        assert!(!matches!(type_, DataType::Object(Some(_))));
+        assert!(!matches!(
+            type_,
+            DataType::Object(Some(_))
+                | DataType::Self_
+                | DataType::Union(_)
+                | DataType::Intersection(_)
+        ));
         Self {
             ptr: ptr::null_mut::<c_void>(),
             type_mask: Self::type_init_code(type_, pass_by_ref, is_variadic, allow_null),
@@ -1,9 +1,14 @@
 //! Builder and objects for creating classes in the PHP world.
 
-use crate::ffi::instanceof_function_slow;
+use crate::ffi::{
+    instanceof_function_slow, zend_read_static_property_ex, zend_update_static_property_ex,
+    ZEND_RESULT_CODE_SUCCESS,
+};
 use crate::types::{ZendIterator, Zval};
 use crate::{
     boxed::ZBox,
+    convert::{FromZval, IntoZval},
+    error::{Error, Result},
     ffi::zend_class_entry,
     flags::ClassFlags,
     types::{ZendObject, ZendStr},
@@ -132,6 +137,66 @@ impl ClassEntry {
     pub fn name(&self) -> Option<&str> {
         unsafe { self.name.as_ref().and_then(|s| s.as_str().ok()) }
     }
+
+    /// Attempts to read a static property of the class, e.g. `Foo::$count`.
+    /// Returns a result containing the value of the property if it exists and
+    /// can be converted into `T`, and an [`Error`] otherwise.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - The name of the static property, without the leading `$`.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidProperty`] - If the class has no static property with
+    ///   the given name.
+    /// * [`Error::ZvalConversion`] - If the value could not be converted into
+    ///   `T`.
+    pub fn get_static_property<'a, T>(&'a self, name: &str) -> Result<T>
+    where
+        T: FromZval<'a>,
+    {
+        let mut name = ZendStr::new(name, false);
+
+        let zv = unsafe {
+            zend_read_static_property_ex(ptr::from_ref(self).cast_mut(), &raw mut *name, true)
+                .as_ref()
+        }
+        .ok_or(Error::InvalidProperty)?
+        .dereference();
+
+        T::from_zval(zv).ok_or_else(|| Error::ZvalConversion(zv.get_type()))
+    }
+
+    /// Attempts to set a static property of the class, e.g. `Foo::$count`.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - The name of the static property, without the leading `$`.
+    /// * `value` - The value to set the property to.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidProperty`] - If the class has no static property with
+    ///   the given name, or the value is not valid for the property.
+    pub fn set_static_property(&self, name: &str, value: impl IntoZval) -> Result<()> {
+        let mut name = ZendStr::new(name, false);
+        let mut value = value.into_zval(false)?;
+
+        let result = unsafe {
+            zend_update_static_property_ex(
+                ptr::from_ref(self).cast_mut(),
+                &raw mut *name,
+                &raw mut value,
+            )
+        };
+
+        if result == ZEND_RESULT_CODE_SUCCESS {
+            Ok(())
+        } else {
+            Err(Error::InvalidProperty)
+        }
+    }
 }
 
 impl PartialEq for ClassEntry {
@@ -1,9 +1,11 @@
-use crate::ffi::{zend_execute_data, ZEND_MM_ALIGNMENT, ZEND_MM_ALIGNMENT_MASK};
+use crate::ffi::{
+    zend_execute_data, ZEND_CALL_HAS_EXTRA_NAMED_PARAMS, ZEND_MM_ALIGNMENT, ZEND_MM_ALIGNMENT_MASK,
+};
 
 use crate::{
     args::ArgParser,
     class::RegisteredClass,
-    types::{ZendClassObject, ZendObject, Zval},
+    types::{ZendClassObject, ZendHashTable, ZendObject, Zval},
 };
 
 use super::function::Function;
@@ -87,9 +89,10 @@ impl ExecuteData {
             args.push(arg);
         }
 
+        let named_params = self.extra_named_params_unchecked();
         let obj = self.This.object_mut();
 
-        (ArgParser::new(args), obj)
+        (ArgParser::new(args).named_params(named_params), obj)
     }
 
     /// Returns an [`ArgParser`] pre-loaded with the arguments contained inside
@@ -196,6 +199,29 @@ impl ExecuteData {
         self.This.object_mut()
     }
 
+    /// Returns the named arguments passed to the function which do not match a
+    /// parameter, or [`None`] if there are none.
+    ///
+    /// PHP only accepts unknown named arguments for variadic functions, e.g.
+    /// functions with a `#[php(named_rest)]` parameter.
+    #[must_use]
+    pub fn extra_named_params(&self) -> Option<&ZendHashTable> {
+        self.extra_named_params_unchecked()
+    }
+
+    /// Returns the extra named arguments with an unbounded lifetime, so they
+    /// can be returned alongside mutable references to the arguments.
+    fn extra_named_params_unchecked<'a>(&self) -> Option<&'a ZendHashTable> {
+        // SAFETY: The call info is stored in the type info of `This`, and the
+        // named arguments are valid for the duration of the call if the flag is set.
+        unsafe {
+            if self.This.u1.type_info & ZEND_CALL_HAS_EXTRA_NAMED_PARAMS == 0 {
+                return None;
+            }
+            self.extra_named_params.as_ref()
+        }
+    }
+
     /// Attempt to retrieve the function that is being called.
     #[must_use]
     pub fn function(&self) -> Option<&Function> {
@@ -10,7 +10,7 @@ use crate::{
         zend_hash_str_find_ptr_lc,
     },
     flags::FunctionType,
-    types::Zval,
+    types::{CallArgs, Zval},
 };
 
 use super::ClassEntry;
@@ -150,4 +150,37 @@ impl Function {
 
         Ok(retval)
     }
+
+    /// Attempts to call the function with arguments built with [`CallArgs`],
+    /// which may be passed by name or by reference.
+    ///
+    /// # Parameters
+    ///
+    /// * `args` - The arguments to call the function with.
+    ///
+    /// # Returns
+    ///
+    /// Returns the result wrapped in [`Ok`] upon success.
+    ///
+    /// # Errors
+    ///
+    /// * If the number of parameters is not a valid `u32` value.
+    pub fn try_call_with(&self, mut args: CallArgs<'_>) -> Result<Zval> {
+        let mut retval = Zval::new();
+
+        unsafe {
+            zend_call_known_function(
+                ptr::from_ref(self).cast_mut(),
+                ptr::null_mut(),
+                ptr::null_mut(),
+                &raw mut retval,
+                args.param_count().try_into()?,
+                args.params(),
+                args.named_params(),
+            );
+        };
+        args.write_back();
+
+        Ok(retval)
+    }
 }
@@ -1,15 +1,24 @@
-//! Types related to the PHP executor, sapi and process globals.
+//! Types related to the PHP executor, sapi, process and module globals.
 
 use parking_lot::{ArcRwLockReadGuard, ArcRwLockWriteGuard, RawRwLock, RwLock};
+use std::any::TypeId;
 use std::collections::HashMap;
-use std::ffi::CStr;
+use std::ffi::{c_void, CStr};
 use std::ops::{Deref, DerefMut};
+use std::ptr;
 use std::slice;
 use std::str;
-use std::sync::{Arc, LazyLock};
+#[cfg(php_zts)]
+use std::sync::atomic::AtomicI32;
+#[cfg(not(php_zts))]
+use std::sync::atomic::AtomicPtr;
+use std::sync::atomic::Ordering;
+use std::sync::{Arc, LazyLock, OnceLock};
 
 use crate::boxed::ZBox;
 use crate::exception::PhpResult;
+#[cfg(php_zts)]
+use crate::ffi::tsrm_get_ls_cache;
 #[cfg(php82)]
 use crate::ffi::zend_atomic_bool_store;
 use crate::ffi::{
@@ -823,6 +832,170 @@ impl FileGlobals {
     }
 }
 
+/// Implemented on the type holding the globals of the extension, registered
+/// with [`ModuleBuilder::globals`].
+///
+/// The globals are created with [`Default`] when the extension is loaded by
+/// PHP. When PHP is built with thread safety (ZTS), every thread has its own
+/// instance of the globals, otherwise a single instance is shared for the
+/// lifetime of the process. Values which should only live for a single request
+/// must be reset in the request startup function.
+///
+/// [`ModuleBuilder::globals`]: crate::builders::ModuleBuilder::globals
+pub trait ModuleGlobals: Default + 'static {
+    /// Returns a reference to the module globals.
+    ///
+    /// The module globals are guarded by a [`RwLock`]. There can be multiple
+    /// immutable references at one time but only ever one mutable reference.
+    /// Attempting to retrieve the globals while already holding the global
+    /// guard will lead to a deadlock. Dropping the globals guard will release
+    /// the lock.
+    ///
+    /// # Panics
+    ///
+    /// * If the type was not registered as the module globals.
+    /// * If the module globals have not been created yet.
+    fn get() -> GlobalReadGuard<Self> {
+        // SAFETY: The globals are valid until the module is shut down.
+        let globals = unsafe { module_globals::<Self>().as_ref() }
+            .expect("Module globals have not been created");
+
+        cfg_if::cfg_if! {
+            if #[cfg(php_zts)] {
+                let guard = lock::MODULE_GLOBALS_LOCK.with(RwLock::read_arc);
+            } else {
+                let guard = lock::MODULE_GLOBALS_LOCK.read_arc();
+            }
+        }
+
+        GlobalReadGuard { globals, guard }
+    }
+
+    /// Returns a mutable reference to the module globals.
+    ///
+    /// The module globals are guarded by a [`RwLock`]. There can be multiple
+    /// immutable references at one time but only ever one mutable reference.
+    /// Attempting to retrieve the globals while already holding the global
+    /// guard will lead to a deadlock. Dropping the globals guard will release
+    /// the lock.
+    ///
+    /// # Panics
+    ///
+    /// * If the type was not registered as the module globals.
+    /// * If the module globals have not been created yet.
+    fn get_mut() -> GlobalWriteGuard<Self> {
+        // SAFETY: The globals are valid until the module is shut down.
+        let globals = unsafe { module_globals::<Self>().as_mut() }
+            .expect("Module globals have not been created");
+
+        cfg_if::cfg_if! {
+            if #[cfg(php_zts)] {
+                let guard = lock::MODULE_GLOBALS_LOCK.with(RwLock::write_arc);
+            } else {
+                let guard = lock::MODULE_GLOBALS_LOCK.write_arc();
+            }
+        }
+
+        GlobalWriteGuard { globals, guard }
+    }
+}
+
+/// Type of the module globals registered with the module builder.
+static MODULE_GLOBALS_TYPE: OnceLock<TypeId> = OnceLock::new();
+
+/// Memory of the module globals, holding a pointer to the Rust value.
+#[cfg(not(php_zts))]
+static MODULE_GLOBALS: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+
+/// ID of the thread-local resource holding a pointer to the Rust value of the
+/// module globals, assigned by PHP.
+#[cfg(php_zts)]
+static MODULE_GLOBALS_ID: AtomicI32 = AtomicI32::new(0);
+
+/// Registers `T` as the type of the module globals, returning the constructor
+/// and destructor of the globals.
+///
+/// # Panics
+///
+/// Panics if another type has already been registered.
+pub(crate) fn register_module_globals<T: ModuleGlobals>() -> (
+    unsafe extern "C" fn(*mut c_void),
+    unsafe extern "C" fn(*mut c_void),
+) {
+    /// Creates the globals inside the memory allocated by PHP.
+    unsafe extern "C" fn ctor<T: ModuleGlobals>(globals: *mut c_void) {
+        globals
+            .cast::<*mut T>()
+            .write(Box::into_raw(Box::<T>::default()));
+    }
+
+    /// Drops the globals inside the memory allocated by PHP.
+    unsafe extern "C" fn dtor<T: ModuleGlobals>(globals: *mut c_void) {
+        let value = globals.cast::<*mut T>().replace(ptr::null_mut());
+        if !value.is_null() {
+            drop(Box::from_raw(value));
+        }
+    }
+
+    let ty = *MODULE_GLOBALS_TYPE.get_or_init(TypeId::of::<T>);
+    assert!(
+        ty == TypeId::of::<T>(),
+        "Module globals have already been registered with another type"
+    );
+
+    (ctor::<T>, dtor::<T>)
+}
+
+/// Returns the pointer to the memory of the module globals, which PHP passes
+/// to the constructor and destructor of the globals.
+#[cfg(not(php_zts))]
+pub(crate) fn module_globals_ptr() -> *mut c_void {
+    MODULE_GLOBALS.as_ptr().cast()
+}
+
+/// Returns the pointer to the ID of the module globals, which PHP sets when
+/// allocating the globals.
+#[cfg(php_zts)]
+pub(crate) fn module_globals_id_ptr() -> *mut i32 {
+    MODULE_GLOBALS_ID.as_ptr()
+}
+
+/// Returns the Rust value of the module globals of the current thread.
+///
+/// # Panics
+///
+/// Panics if `T` was not registered as the type of the module globals.
+fn module_globals<T: ModuleGlobals>() -> *mut T {
+    assert!(
+        MODULE_GLOBALS_TYPE.get() == Some(&TypeId::of::<T>()),
+        "Module globals have not been registered with this type"
+    );
+
+    cfg_if::cfg_if! {
+        if #[cfg(php_zts)] {
+            let id = MODULE_GLOBALS_ID.load(Ordering::SeqCst);
+            if id <= 0 {
+                return ptr::null_mut();
+            }
+            // SAFETY: Equivalent to the `TSRMG_BULK` macro. The thread-local
+            // resources are valid while the thread is running PHP.
+            #[allow(clippy::cast_sign_loss)]
+            let globals = unsafe {
+                (*tsrm_get_ls_cache().cast::<*mut *mut c_void>())
+                    .add(id as usize - 1)
+                    .read()
+            };
+            if globals.is_null() {
+                return ptr::null_mut();
+            }
+            // SAFETY: The memory holds the pointer written by the constructor.
+            unsafe { globals.cast::<*mut T>().read() }
+        } else {
+            MODULE_GLOBALS.load(Ordering::SeqCst).cast()
+        }
+    }
+}
+
 /// Executor globals rwlock.
 ///
 /// PHP provides no indication if the executor globals are being accessed so
@@ -840,6 +1013,8 @@ pub(crate) mod lock {
         LazyLock::new(|| Arc::new(RwLock::new(())));
     pub(crate) static FILE_GLOBALS_LOCK: LazyLock<Arc<RwLock<()>>> =
         LazyLock::new(|| Arc::new(RwLock::new(())));
+    pub(crate) static MODULE_GLOBALS_LOCK: LazyLock<Arc<RwLock<()>>> =
+        LazyLock::new(|| Arc::new(RwLock::new(())));
 }
 
 /// Executor globals rwlock.
@@ -856,6 +1031,7 @@ pub(crate) mod lock {
         pub(crate) static PROCESS_GLOBALS_LOCK: Arc<RwLock<()>> = Arc::new( const_rwlock(()) );
         pub(crate) static SAPI_GLOBALS_LOCK: Arc<RwLock<()>> = Arc::new( const_rwlock(()) );
         pub(crate) static FILE_GLOBALS_LOCK: Arc<RwLock<()>> = Arc::new( const_rwlock(()) );
+        pub(crate) static MODULE_GLOBALS_LOCK: Arc<RwLock<()>> = Arc::new( const_rwlock(()) );
     }
 }
 
@@ -1,16 +1,28 @@
-use std::{ffi::c_void, mem::MaybeUninit, os::raw::c_int, ptr};
+use std::{cmp::Ordering, ffi::c_void, mem::MaybeUninit, os::raw::c_int, ptr};
 
 use crate::{
     class::RegisteredClass,
-    exception::PhpResult,
+    exception::{PhpException, PhpResult},
     ffi::{
-        std_object_handlers, zend_is_true, zend_object_handlers, zend_object_std_dtor,
-        zend_std_get_properties, zend_std_has_property, zend_std_read_property,
-        zend_std_write_property,
+        std_object_handlers, zend_get_gc_buffer_create, zend_is_true, zend_long,
+        zend_object_handlers, zend_object_std_dtor, zend_objects_clone_members, zend_result,
+        zend_std_compare_objects, zend_std_get_properties, zend_std_get_property_ptr_ptr,
+        zend_std_has_property, zend_std_read_property, zend_std_unset_property,
+        zend_std_write_property, _IS_BOOL, BP_VAR_IS, BP_VAR_RW, BP_VAR_UNSET, BP_VAR_W, IS_DOUBLE,
+        IS_LONG, IS_STRING, ZEND_RESULT_CODE_FAILURE, ZEND_RESULT_CODE_SUCCESS,
     },
-    flags::ZvalTypeFlags,
+    flags::{DataType, ZvalTypeFlags},
+    gc::{GcBuffer, GcTraverse},
+    ops::{Operator, PhpArrayAccess, PhpCast, PhpCompare, PhpCountable, PhpOperators},
     types::{ZendClassObject, ZendHashTable, ZendObject, ZendStr, Zval},
+    zend::ce,
 };
+#[cfg(php81)]
+use crate::{flags::PropertyFlags, zend::ExecutorGlobals};
+
+/// Value returned by the `compare` handler for values which are not
+/// comparable.
+const ZEND_UNCOMPARABLE: c_int = 1;
 
 /// A set of functions associated with a PHP class.
 pub type ZendObjectHandlers = zend_object_handlers;
@@ -54,12 +66,16 @@ impl ZendObjectHandlers {
         (*ptr).write_property = Some(Self::write_property::<T>);
         (*ptr).get_properties = Some(Self::get_properties::<T>);
         (*ptr).has_property = Some(Self::has_property::<T>);
+        (*ptr).unset_property = Some(Self::unset_property::<T>);
+        (*ptr).get_property_ptr_ptr = Some(Self::get_property_ptr_ptr::<T>);
+        T::modify_handlers(&mut *ptr);
     }
 
     unsafe extern "C" fn free_obj<T: RegisteredClass>(object: *mut ZendObject) {
+        // The standard destructor releases values left in the property slots.
         let obj = object
-            .as_mut()
-            .and_then(|obj| ZendClassObject::<T>::from_zend_obj_mut(obj))
+            .as_ref()
+            .and_then(|obj| ZendClassObject::<T>::internal_from_zend_obj(obj))
             .expect("Invalid object pointer given for `free_obj`");
 
         // Manually drop the object as we don't want to free the underlying memory.
@@ -89,6 +105,13 @@ impl ZendObjectHandlers {
                 .as_mut()
                 .and_then(|obj| ZendClassObject::<T>::from_zend_obj_mut(obj))
                 .ok_or("Invalid object pointer given")?;
+            // Objects of PHP subclasses which did not call the parent constructor
+            // have no Rust value, their properties hold the declared defaults.
+            if obj.obj.is_none() {
+                return Ok(zend_std_read_property(
+                    object, member, type_, cache_slot, rv,
+                ));
+            }
             let prop_name = member
                 .as_ref()
                 .ok_or("Invalid property name pointer given")?;
@@ -103,6 +126,27 @@ impl ZendObjectHandlers {
             Ok(match prop {
                 Some(prop_info) => {
                     prop_info.prop.get(self_, rv_mut)?;
+                    // Fetches for writing, e.g. `$obj->prop[] = 1`, only get here
+                    // for properties without a slot, see `get_property_ptr_ptr`.
+                    // PHP modifies the returned copy, which only has an effect on
+                    // objects.
+                    if !rv_mut.is_object()
+                        && [BP_VAR_W, BP_VAR_RW, BP_VAR_UNSET]
+                            .into_iter()
+                            .any(|bp| c_int::try_from(bp).is_ok_and(|bp| bp == type_))
+                    {
+                        #[cfg(php81)]
+                        if prop_info.flags.contains(PropertyFlags::Readonly) {
+                            return Err(property_error::<T>(
+                                "Cannot modify readonly property",
+                                prop_name.as_str()?,
+                            ));
+                        }
+                        return Err(property_error::<T>(
+                            "Cannot indirectly modify property",
+                            prop_name.as_str()?,
+                        ));
+                    }
                     rv
                 }
                 None => zend_std_read_property(object, member, type_, cache_slot, rv),
@@ -138,21 +182,32 @@ impl ZendObjectHandlers {
                 .as_mut()
                 .and_then(|obj| ZendClassObject::<T>::from_zend_obj_mut(obj))
                 .ok_or("Invalid object pointer given")?;
+            if obj.obj.is_none() {
+                return Ok(zend_std_write_property(object, member, value, cache_slot));
+            }
             let prop_name = member
                 .as_ref()
                 .ok_or("Invalid property name pointer given")?;
-            let self_ = &mut *obj;
             let props = T::get_metadata().get_properties();
-            let prop = props.get(prop_name.as_str()?);
-            let value_mut = value.as_mut().ok_or("Invalid return zval given")?;
+            let Some(prop_info) = props.get(prop_name.as_str()?) else {
+                return Ok(zend_std_write_property(object, member, value, cache_slot));
+            };
 
-            Ok(match prop {
-                Some(prop_info) => {
-                    prop_info.prop.set(self_, value_mut)?;
-                    value
+            // The declared property slot tracks whether a readonly property has been
+            // initialized. PHP checks the slot and the calling scope, and marks the slot as
+            // initialized on the first write.
+            #[cfg(php81)]
+            if prop_info.flags.contains(PropertyFlags::Readonly) {
+                let rv = zend_std_write_property(object, member, value, cache_slot);
+                if ExecutorGlobals::has_exception() {
+                    return Ok(rv);
                 }
-                None => zend_std_write_property(object, member, value, cache_slot),
-            })
+            }
+
+            let self_ = &mut *obj;
+            let value_mut = value.as_mut().ok_or("Invalid return zval given")?;
+            prop_info.prop.set(self_, value_mut)?;
+            Ok(value)
         }
 
         match internal::<T>(object, member, value, cache_slot) {
@@ -178,6 +233,9 @@ impl ZendObjectHandlers {
                 .as_mut()
                 .and_then(|obj| ZendClassObject::<T>::from_zend_obj_mut(obj))
                 .ok_or("Invalid object pointer given")?;
+            if obj.obj.is_none() {
+                return Ok(());
+            }
             let self_ = &mut *obj;
             let struct_props = T::get_metadata().get_properties();
 
@@ -225,6 +283,14 @@ impl ZendObjectHandlers {
                 .as_mut()
                 .and_then(|obj| ZendClassObject::<T>::from_zend_obj_mut(obj))
                 .ok_or("Invalid object pointer given")?;
+            if obj.obj.is_none() {
+                return Ok(zend_std_has_property(
+                    object,
+                    member,
+                    has_set_exists,
+                    cache_slot,
+                ));
+            }
             let prop_name = member
                 .as_ref()
                 .ok_or("Invalid property name pointer given")?;
@@ -295,4 +361,488 @@ impl ZendObjectHandlers {
             }
         }
     }
+
+    unsafe extern "C" fn unset_property<T: RegisteredClass>(
+        object: *mut ZendObject,
+        member: *mut ZendStr,
+        cache_slot: *mut *mut c_void,
+    ) {
+        // TODO: Measure this
+        #[allow(clippy::inline_always)]
+        #[inline(always)]
+        unsafe fn internal<T: RegisteredClass>(
+            object: *mut ZendObject,
+            member: *mut ZendStr,
+            cache_slot: *mut *mut c_void,
+        ) -> PhpResult {
+            let obj = object
+                .as_mut()
+                .and_then(|obj| ZendClassObject::<T>::from_zend_obj_mut(obj))
+                .ok_or("Invalid object pointer given")?;
+            let prop_name = member
+                .as_ref()
+                .ok_or("Invalid property name pointer given")?;
+
+            // Rust properties are backed by the Rust value, so they cannot be unset.
+            if obj.obj.is_some()
+                && T::get_metadata()
+                    .get_properties()
+                    .contains_key(prop_name.as_str()?)
+            {
+                return Err(property_error::<T>(
+                    "Cannot unset property",
+                    prop_name.as_str()?,
+                ));
+            }
+
+            zend_std_unset_property(object, member, cache_slot);
+            Ok(())
+        }
+
+        if let Err(e) = internal::<T>(object, member, cache_slot) {
+            let _ = e.throw();
+        }
+    }
+
+    unsafe extern "C" fn get_property_ptr_ptr<T: RegisteredClass>(
+        object: *mut ZendObject,
+        member: *mut ZendStr,
+        type_: c_int,
+        cache_slot: *mut *mut c_void,
+    ) -> *mut Zval {
+        // TODO: Measure this
+        #[allow(clippy::inline_always)]
+        #[inline(always)]
+        unsafe fn internal<T: RegisteredClass>(
+            object: *mut ZendObject,
+            member: *mut ZendStr,
+            type_: c_int,
+            cache_slot: *mut *mut c_void,
+        ) -> PhpResult<*mut Zval> {
+            let obj = object
+                .as_mut()
+                .and_then(|obj| ZendClassObject::<T>::from_zend_obj_mut(obj))
+                .ok_or("Invalid object pointer given")?;
+            let prop_name = member
+                .as_ref()
+                .ok_or("Invalid property name pointer given")?;
+            let props = T::get_metadata().get_properties();
+            let prop = props.get(prop_name.as_str()?);
+            let (Some(prop_info), Some(_)) = (prop, &obj.obj) else {
+                return Ok(zend_std_get_property_ptr_ptr(
+                    object, member, type_, cache_slot,
+                ));
+            };
+            // Without a slot, PHP falls back to `read_property` and
+            // `write_property`. Readonly properties can only be modified
+            // through objects they hold.
+            #[cfg(php81)]
+            if prop_info.flags.contains(PropertyFlags::Readonly) {
+                return Ok(ptr::null_mut());
+            }
+
+            // The value is copied into the declared slot of the property, where
+            // PHP modifies it, e.g. `$obj->items[] = 1` or `$obj->count++`.
+            let self_ = &mut *obj;
+            let mut value = Zval::new();
+            prop_info.prop.get(self_, &mut value)?;
+            let Some(slot) = obj.indirect_slot(prop_name.as_str()?) else {
+                return Ok(ptr::null_mut());
+            };
+            *slot = value;
+            Ok(slot)
+        }
+
+        match internal::<T>(object, member, type_, cache_slot) {
+            Ok(slot) => slot,
+            Err(e) => {
+                let _ = e.throw();
+                ptr::null_mut()
+            }
+        }
+    }
+
+    /// Handler for comparisons, installed for classes implementing
+    /// [`PhpCompare`].
+    pub(crate) unsafe extern "C" fn compare<T: PhpCompare>(
+        op1: *mut Zval,
+        op2: *mut Zval,
+    ) -> c_int {
+        fn internal<T: PhpCompare>(op1: &Zval, op2: &Zval) -> PhpResult<Option<Ordering>> {
+            // Either operand may be the object of the class.
+            if let Some(this) = class_object::<T>(op1) {
+                this.compare(op2)
+            } else if let Some(this) = class_object::<T>(op2) {
+                Ok(this.compare(op1)?.map(Ordering::reverse))
+            } else {
+                Ok(None)
+            }
+        }
+
+        let (Some(lhs), Some(rhs)) = (op1.as_ref(), op2.as_ref()) else {
+            return ZEND_UNCOMPARABLE;
+        };
+        match internal::<T>(lhs, rhs) {
+            Ok(Some(Ordering::Less)) => -1,
+            Ok(Some(Ordering::Equal)) => 0,
+            Ok(Some(Ordering::Greater)) => 1,
+            Ok(None) => zend_std_compare_objects(op1, op2),
+            Err(e) => {
+                let _ = e.throw();
+                ZEND_UNCOMPARABLE
+            }
+        }
+    }
+
+    /// Handler for casts, installed for classes implementing [`PhpCast`].
+    pub(crate) unsafe extern "C" fn cast_object<T: PhpCast>(
+        readobj: *mut ZendObject,
+        retval: *mut Zval,
+        type_: c_int,
+    ) -> zend_result {
+        fn internal<T: PhpCast>(readobj: &ZendObject, type_: c_int) -> PhpResult<Option<Zval>> {
+            let Some(this) =
+                ZendClassObject::<T>::from_zend_obj(readobj).and_then(|obj| obj.obj.as_ref())
+            else {
+                return Ok(None);
+            };
+            let ty = match u32::try_from(type_) {
+                Ok(IS_STRING) => DataType::String,
+                Ok(IS_LONG) => DataType::Long,
+                Ok(IS_DOUBLE) => DataType::Double,
+                Ok(_IS_BOOL) => DataType::Bool,
+                _ => return Ok(None),
+            };
+            let Some(value) = this.cast(ty)? else {
+                return Ok(None);
+            };
+            let valid = match ty {
+                DataType::String => value.is_string(),
+                DataType::Long => value.is_long(),
+                DataType::Double => value.is_double(),
+                _ => value.is_bool(),
+            };
+            if !valid {
+                return Err(format!(
+                    "{}::cast() must return a value of type {ty}, {} returned",
+                    T::CLASS_NAME,
+                    value.get_type()
+                )
+                .into());
+            }
+
+            Ok(Some(value))
+        }
+
+        let Some(obj) = readobj.as_ref() else {
+            return ZEND_RESULT_CODE_FAILURE;
+        };
+        match internal::<T>(obj, type_) {
+            Ok(Some(value)) => {
+                // `retval` is not initialized.
+                ptr::write(retval, value);
+                ZEND_RESULT_CODE_SUCCESS
+            }
+            Ok(None) => match std_object_handlers.cast_object {
+                Some(cast_object) => cast_object(readobj, retval, type_),
+                None => ZEND_RESULT_CODE_FAILURE,
+            },
+            Err(e) => {
+                let _ = e.throw();
+                ZEND_RESULT_CODE_FAILURE
+            }
+        }
+    }
+
+    /// Handler for arithmetic and bitwise operators, installed for classes
+    /// implementing [`PhpOperators`].
+    pub(crate) unsafe extern "C" fn do_operation<T: PhpOperators>(
+        opcode: u8,
+        result: *mut Zval,
+        op1: *mut Zval,
+        op2: *mut Zval,
+    ) -> zend_result {
+        let Some(op) = Operator::from_opcode(opcode) else {
+            return ZEND_RESULT_CODE_FAILURE;
+        };
+        let Some(lhs) = op1.as_ref() else {
+            return ZEND_RESULT_CODE_FAILURE;
+        };
+        // Unary operators are given no right operand.
+        let null = Zval::new();
+        let rhs = op2.as_ref().unwrap_or(&null);
+
+        match T::operation(op, lhs, rhs) {
+            Ok(Some(value)) => {
+                if ptr::eq(result, op1) {
+                    // Compound assignment, the previous value of the left operand is
+                    // released.
+                    *result = value;
+                } else {
+                    // `result` is not initialized.
+                    ptr::write(result, value);
+                }
+                ZEND_RESULT_CODE_SUCCESS
+            }
+            Ok(None) => ZEND_RESULT_CODE_FAILURE,
+            Err(e) => {
+                let _ = e.throw();
+                ZEND_RESULT_CODE_FAILURE
+            }
+        }
+    }
+
+    /// Handler for `count()`, installed for classes implementing
+    /// [`PhpCountable`].
+    pub(crate) unsafe extern "C" fn count_elements<T: PhpCountable>(
+        object: *mut ZendObject,
+        count: *mut zend_long,
+    ) -> zend_result {
+        unsafe fn internal<T: PhpCountable>(object: *mut ZendObject) -> PhpResult<Option<i64>> {
+            let Some(this) = class_object_mut::<T>(object) else {
+                return Ok(None);
+            };
+            this.count().map(Some)
+        }
+
+        match internal::<T>(object) {
+            Ok(Some(value)) => {
+                *count = value;
+                ZEND_RESULT_CODE_SUCCESS
+            }
+            Ok(None) => ZEND_RESULT_CODE_FAILURE,
+            Err(e) => {
+                let _ = e.throw();
+                ZEND_RESULT_CODE_FAILURE
+            }
+        }
+    }
+
+    /// Handler for reading elements, installed for classes implementing
+    /// [`PhpArrayAccess`].
+    pub(crate) unsafe extern "C" fn read_dimension<T: PhpArrayAccess>(
+        object: *mut ZendObject,
+        offset: *mut Zval,
+        type_: c_int,
+        rv: *mut Zval,
+    ) -> *mut Zval {
+        unsafe fn internal<T: PhpArrayAccess>(
+            object: *mut ZendObject,
+            offset: *mut Zval,
+            type_: c_int,
+        ) -> PhpResult<Option<Zval>> {
+            let Some(this) = class_object_mut::<T>(object) else {
+                return Ok(None);
+            };
+            // Reading an appended element, e.g. `$obj[][] = 1`, reads the `null` offset.
+            let null = Zval::new();
+            let offset = offset.as_ref().unwrap_or(&null);
+            // `isset()` and `??` do not read missing elements.
+            if c_int::try_from(BP_VAR_IS).is_ok_and(|is| is == type_)
+                && !this.offset_exists(offset)?
+            {
+                return Ok(Some(Zval::new()));
+            }
+            this.offset_get(offset).map(Some)
+        }
+
+        match internal::<T>(object, offset, type_) {
+            Ok(Some(value)) => {
+                // `rv` is not initialized.
+                ptr::write(rv, value);
+                rv
+            }
+            Ok(None) => match std_object_handlers.read_dimension {
+                Some(read_dimension) => read_dimension(object, offset, type_, rv),
+                None => ptr::null_mut(),
+            },
+            Err(e) => {
+                let _ = e.throw();
+                ptr::null_mut()
+            }
+        }
+    }
+
+    /// Handler for writing elements, installed for classes implementing
+    /// [`PhpArrayAccess`].
+    pub(crate) unsafe extern "C" fn write_dimension<T: PhpArrayAccess>(
+        object: *mut ZendObject,
+        offset: *mut Zval,
+        value: *mut Zval,
+    ) {
+        unsafe fn internal<T: PhpArrayAccess>(
+            object: *mut ZendObject,
+            offset: *mut Zval,
+            value: *mut Zval,
+        ) -> PhpResult<bool> {
+            let Some(this) = class_object_mut::<T>(object) else {
+                return Ok(false);
+            };
+            let value = value.as_ref().ok_or("Invalid value pointer given")?;
+            this.offset_set(offset.as_ref(), value.dereference())?;
+            Ok(true)
+        }
+
+        match internal::<T>(object, offset, value) {
+            Ok(true) => {}
+            Ok(false) => {
+                if let Some(write_dimension) = std_object_handlers.write_dimension {
+                    write_dimension(object, offset, value);
+                }
+            }
+            Err(e) => {
+                let _ = e.throw();
+            }
+        }
+    }
+
+    /// Handler for `isset()` and `empty()` on elements, installed for classes
+    /// implementing [`PhpArrayAccess`].
+    pub(crate) unsafe extern "C" fn has_dimension<T: PhpArrayAccess>(
+        object: *mut ZendObject,
+        offset: *mut Zval,
+        check_empty: c_int,
+    ) -> c_int {
+        unsafe fn internal<T: PhpArrayAccess>(
+            object: *mut ZendObject,
+            offset: *mut Zval,
+            check_empty: c_int,
+        ) -> PhpResult<Option<bool>> {
+            let Some(this) = class_object_mut::<T>(object) else {
+                return Ok(None);
+            };
+            let offset = offset.as_ref().ok_or("Invalid offset pointer given")?;
+            if !this.offset_exists(offset)? {
+                return Ok(Some(false));
+            }
+            if check_empty == 0 {
+                return Ok(Some(true));
+            }
+            let mut value = this.offset_get(offset)?;
+            cfg_if::cfg_if! {
+                if #[cfg(php84)] {
+                    Ok(Some(zend_is_true(&raw mut value)))
+                } else {
+                    Ok(Some(zend_is_true(&raw mut value) == 1))
+                }
+            }
+        }
+
+        match internal::<T>(object, offset, check_empty) {
+            Ok(Some(exists)) => exists.into(),
+            Ok(None) => match std_object_handlers.has_dimension {
+                Some(has_dimension) => has_dimension(object, offset, check_empty),
+                None => 0,
+            },
+            Err(e) => {
+                let _ = e.throw();
+                0
+            }
+        }
+    }
+
+    /// Handler for `unset()` on elements, installed for classes implementing
+    /// [`PhpArrayAccess`].
+    pub(crate) unsafe extern "C" fn unset_dimension<T: PhpArrayAccess>(
+        object: *mut ZendObject,
+        offset: *mut Zval,
+    ) {
+        unsafe fn internal<T: PhpArrayAccess>(
+            object: *mut ZendObject,
+            offset: *mut Zval,
+        ) -> PhpResult<bool> {
+            let Some(this) = class_object_mut::<T>(object) else {
+                return Ok(false);
+            };
+            let offset = offset.as_ref().ok_or("Invalid offset pointer given")?;
+            this.offset_unset(offset)?;
+            Ok(true)
+        }
+
+        match internal::<T>(object, offset) {
+            Ok(true) => {}
+            Ok(false) => {
+                if let Some(unset_dimension) = std_object_handlers.unset_dimension {
+                    unset_dimension(object, offset);
+                }
+            }
+            Err(e) => {
+                let _ = e.throw();
+            }
+        }
+    }
+
+    /// Handler for `clone`, installed for classes implementing [`Clone`].
+    pub(crate) unsafe extern "C" fn clone_obj<T: RegisteredClass + Clone>(
+        object: *mut ZendObject,
+    ) -> *mut ZendObject {
+        let old = object
+            .as_mut()
+            .and_then(|obj| ZendClassObject::<T>::from_zend_obj_mut(obj))
+            .expect("Invalid object pointer given");
+
+        // The clone is of the same class as the object, which may be a PHP subclass.
+        let mut new = ZendClassObject::<T>::new_uninit((*object).ce.as_ref());
+        if let Some(val) = &old.obj {
+            new.initialize(val.clone());
+        }
+        // Copies the properties and calls `__clone()` on the new object.
+        zend_objects_clone_members(&raw mut new.std, object);
+
+        &raw mut new.into_raw().std
+    }
+
+    /// Handler reporting the values held by an object to the cycle collector,
+    /// installed for classes implementing [`GcTraverse`].
+    pub(crate) unsafe extern "C" fn get_gc<T: RegisteredClass + GcTraverse>(
+        object: *mut ZendObject,
+        table: *mut *mut Zval,
+        n: *mut c_int,
+    ) -> *mut ZendHashTable {
+        let buffer = &mut *zend_get_gc_buffer_create();
+        // Values left in the property slots are part of the properties.
+        if let Some(this) = object
+            .as_ref()
+            .and_then(|obj| ZendClassObject::<T>::internal_from_zend_obj(obj))
+            .and_then(|obj| obj.obj.as_ref())
+        {
+            this.gc_traverse(&mut GcBuffer::new(buffer));
+        }
+
+        *table = buffer.start;
+        *n = c_int::try_from(buffer.cur.offset_from(buffer.start))
+            .expect("Too many values in the GC buffer");
+        // The properties hold the values of the declared and dynamic properties.
+        zend_std_get_properties(object)
+    }
+}
+
+/// Returns the Rust value of the given zval if it is an initialized object of
+/// class `T`.
+fn class_object<T: RegisteredClass>(zv: &Zval) -> Option<&T> {
+    zv.object()
+        .and_then(ZendClassObject::<T>::from_zend_obj)
+        .and_then(|obj| obj.obj.as_ref())
+}
+
+/// Returns the Rust value of `object` if it is an initialized object of class
+/// `T`.
+///
+/// # Safety
+///
+/// `object` must point to a valid object of class `T`.
+unsafe fn class_object_mut<'a, T: RegisteredClass>(object: *mut ZendObject) -> Option<&'a mut T> {
+    object
+        .as_mut()
+        .and_then(|obj| ZendClassObject::<T>::from_zend_obj_mut(obj))
+        .and_then(|obj| obj.obj.as_mut())
+}
+
+/// Returns an `Error` exception for the property `name` of class `T`.
+fn property_error<T: RegisteredClass>(message: &str, name: &str) -> PhpException {
+    PhpException::new(
+        format!("{message} {}::${name}", T::CLASS_NAME),
+        0,
+        ce::error(),
+    )
 }
@@ -0,0 +1,286 @@
+//! Typed INI settings, whose values are parsed once when they change.
+
+use std::{
+    any::Any,
+    cell::RefCell,
+    collections::HashMap,
+    fmt::Debug,
+    os::raw::{c_int, c_void},
+    ptr,
+    time::Duration,
+};
+
+use crate::{
+    ffi::{
+        zend_ini_entry, zend_result, zend_string, ZEND_RESULT_CODE_FAILURE,
+        ZEND_RESULT_CODE_SUCCESS,
+    },
+    flags::IniEntryPermission,
+    types::ZendStr,
+    zend::IniEntryDef,
+};
+
+thread_local! {
+    /// Parsed values of the INI settings, keyed by the address of the setting.
+    ///
+    /// PHP keeps INI values per thread when built with thread safety (ZTS), and
+    /// calls the modify handler of every setting when a thread is started.
+    static INI_VALUES: RefCell<HashMap<usize, Box<dyn Any>>> = RefCell::new(HashMap::new());
+}
+
+/// Implemented on types which can be parsed from the value of an INI setting.
+pub trait IniValue: Clone + 'static {
+    /// Parses the value of an INI setting, returning [`None`] if the value is
+    /// invalid.
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - The value of the INI setting.
+    fn parse_ini(value: &str) -> Option<Self>;
+}
+
+/// Parsed like PHP boolean settings: `true`, `yes` and `on` are `true`, other
+/// values are `true` if they start with a non-zero integer.
+impl IniValue for bool {
+    fn parse_ini(value: &str) -> Option<Self> {
+        let value = value.trim();
+        if ["true", "yes", "on"]
+            .iter()
+            .any(|v| value.eq_ignore_ascii_case(v))
+        {
+            return Some(true);
+        }
+        let digits = value
+            .char_indices()
+            .find(|&(i, c)| !(c.is_ascii_digit() || (i == 0 && (c == '-' || c == '+'))))
+            .map_or(value, |(i, _)| &value[..i]);
+        Some(digits.parse::<i64>().is_ok_and(|v| v != 0))
+    }
+}
+
+/// Integers may have a `K`, `M` or `G` suffix, multiplying the value by 1024,
+/// 1024² or 1024³.
+impl IniValue for i64 {
+    fn parse_ini(value: &str) -> Option<Self> {
+        let value = value.trim();
+        if value.is_empty() {
+            return Some(0);
+        }
+        let (digits, factor) = match value.as_bytes()[value.len() - 1] {
+            b'k' | b'K' => (&value[..value.len() - 1], 1 << 10),
+            b'm' | b'M' => (&value[..value.len() - 1], 1 << 20),
+            b'g' | b'G' => (&value[..value.len() - 1], 1 << 30),
+            _ => (value, 1),
+        };
+        digits.trim_end().parse::<i64>().ok()?.checked_mul(factor)
+    }
+}
+
+impl IniValue for f64 {
+    fn parse_ini(value: &str) -> Option<Self> {
+        value.trim().parse().ok()
+    }
+}
+
+impl IniValue for String {
+    fn parse_ini(value: &str) -> Option<Self> {
+        Some(value.to_owned())
+    }
+}
+
+/// Durations are given in seconds, which may be fractional.
+impl IniValue for Duration {
+    fn parse_ini(value: &str) -> Option<Self> {
+        Duration::try_from_secs_f64(value.trim().parse().ok()?).ok()
+    }
+}
+
+/// An INI setting holding a value of type `T`.
+///
+/// INI settings are declared as statics and added to the extension with
+/// [`ModuleBuilder::ini_setting`]. The value is parsed when the setting is
+/// changed, e.g. by the `php.ini` file or `ini_set()`, and values which cannot
+/// be parsed are rejected. The settings are registered when the extension is
+/// started, unregistered when it is shut down and shown by `phpinfo()`.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use ext_php_rs::{flags::IniEntryPermission, zend::IniSetting};
+///
+/// static MAX_CONNECTIONS: IniSetting<i64> =
+///     IniSetting::new("my_ext.max_connections", "10", IniEntryPermission::All)
+///         .on_modify(|value| *value > 0);
+///
+/// fn max_connections() -> i64 {
+///     MAX_CONNECTIONS.get()
+/// }
+/// ```
+///
+/// [`ModuleBuilder::ini_setting`]: crate::builders::ModuleBuilder::ini_setting
+pub struct IniSetting<T: IniValue> {
+    name: &'static str,
+    default: &'static str,
+    permission: IniEntryPermission,
+    on_modify: Option<fn(&T) -> bool>,
+}
+
+impl<T: IniValue> IniSetting<T> {
+    /// Creates a new INI setting.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - The name of the setting, usually prefixed by the name of the
+    ///   extension.
+    /// * `default` - The default value of the setting.
+    /// * `permission` - Where the setting may be changed.
+    #[must_use]
+    pub const fn new(
+        name: &'static str,
+        default: &'static str,
+        permission: IniEntryPermission,
+    ) -> Self {
+        Self {
+            name,
+            default,
+            permission,
+            on_modify: None,
+        }
+    }
+
+    /// Sets a function validating new values of the setting. Values for which
+    /// the function returns false are rejected.
+    ///
+    /// # Parameters
+    ///
+    /// * `on_modify` - The function validating new values.
+    #[must_use]
+    pub const fn on_modify(mut self, on_modify: fn(&T) -> bool) -> Self {
+        self.on_modify = Some(on_modify);
+        self
+    }
+
+    /// Returns the name of the setting.
+    #[must_use]
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the current value of the setting.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the setting has not been registered and the default value
+    /// cannot be parsed.
+    #[must_use]
+    pub fn get(&self) -> T {
+        INI_VALUES
+            .with_borrow(|values| {
+                values
+                    .get(&self.key())
+                    .and_then(|value| value.downcast_ref::<T>())
+                    .cloned()
+            })
+            .unwrap_or_else(|| {
+                T::parse_ini(self.default).unwrap_or_else(|| {
+                    panic!("Invalid default value for INI setting `{}`", self.name)
+                })
+            })
+    }
+
+    /// Returns the key of the setting in the parsed values.
+    fn key(&self) -> usize {
+        ptr::from_ref(self) as usize
+    }
+
+    /// Modify handler of the setting, parsing and validating new values.
+    unsafe extern "C" fn modify_handler(
+        _: *mut zend_ini_entry,
+        new_value: *mut zend_string,
+        mh_arg1: *mut c_void,
+        _: *mut c_void,
+        _: *mut c_void,
+        _: c_int,
+    ) -> zend_result {
+        let Some(this) = mh_arg1.cast::<Self>().as_ref() else {
+            return ZEND_RESULT_CODE_FAILURE;
+        };
+        let value = match new_value.cast::<ZendStr>().as_ref() {
+            Some(value) => value.as_str().ok().and_then(T::parse_ini),
+            None => T::parse_ini(""),
+        };
+        let Some(value) = value else {
+            return ZEND_RESULT_CODE_FAILURE;
+        };
+        if this.on_modify.is_some_and(|on_modify| !on_modify(&value)) {
+            return ZEND_RESULT_CODE_FAILURE;
+        }
+        INI_VALUES.with_borrow_mut(|values| values.insert(this.key(), Box::new(value)));
+        ZEND_RESULT_CODE_SUCCESS
+    }
+}
+
+impl<T: IniValue> Debug for IniSetting<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("IniSetting")
+            .field("name", &self.name)
+            .field("default", &self.default)
+            .field("permission", &self.permission.bits())
+            .finish_non_exhaustive()
+    }
+}
+
+/// An INI setting of any type, which can be registered with PHP.
+pub(crate) trait IniSettingEntry: Debug + Sync {
+    /// Returns the definition of the INI entry of the setting.
+    fn entry(&'static self) -> IniEntryDef;
+}
+
+impl<T: IniValue> IniSettingEntry for IniSetting<T> {
+    fn entry(&'static self) -> IniEntryDef {
+        let mut entry = IniEntryDef::new(
+            self.name.to_owned(),
+            self.default.to_owned(),
+            &self.permission,
+        );
+        entry.on_modify = Some(Self::modify_handler);
+        entry.mh_arg1 = ptr::from_ref(self).cast_mut().cast();
+        entry
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_bool() {
+        for value in ["1", "On", "yes", "TRUE", " 2 "] {
+            assert_eq!(bool::parse_ini(value), Some(true), "{value}");
+        }
+        for value in ["", "0", "off", "no", "false", "none"] {
+            assert_eq!(bool::parse_ini(value), Some(false), "{value}");
+        }
+    }
+
+    #[test]
+    fn test_parse_i64() {
+        assert_eq!(i64::parse_ini(""), Some(0));
+        assert_eq!(i64::parse_ini("-12"), Some(-12));
+        assert_eq!(i64::parse_ini("8K"), Some(8 * 1024));
+        assert_eq!(i64::parse_ini("128M"), Some(128 * 1024 * 1024));
+        assert_eq!(i64::parse_ini("1g"), Some(1024 * 1024 * 1024));
+        assert_eq!(i64::parse_ini("abc"), None);
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(Duration::parse_ini("30"), Some(Duration::from_secs(30)));
+        assert_eq!(
+            Duration::parse_ini("1.5"),
+            Some(Duration::from_millis(1500))
+        );
+        assert_eq!(Duration::parse_ini("-1"), None);
+        assert_eq!(Duration::parse_ini("soon"), None);
+    }
+}
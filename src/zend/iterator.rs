@@ -0,0 +1,171 @@
+//! Iterators over objects of classes implementing [`PhpIterable`], used by
+//! PHP to iterate over the objects with `foreach`.
+
+use std::{alloc::Layout, os::raw::c_int, ptr};
+
+use crate::{
+    alloc::emalloc,
+    convert::IntoZval,
+    error::Result,
+    exception::PhpException,
+    ffi::{
+        zend_create_internal_iterator_zval, zend_iterator_init, zend_object_iterator_funcs,
+        zend_result, ZEND_RESULT_CODE_FAILURE, ZEND_RESULT_CODE_SUCCESS,
+    },
+    ops::PhpIterable,
+    types::{ZendClassObject, ZendIterator, Zval},
+    zend::{ce, ClassEntry, ExecuteData},
+    zend_fastcall,
+};
+
+/// Iterator over an object of class `T`. Extends `zend_object_iterator`,
+/// which must be the first field.
+#[repr(C)]
+pub(crate) struct ClassIterator<T: PhpIterable> {
+    it: ZendIterator,
+    iter: Option<T::Iter>,
+    current: Option<(Zval, Zval)>,
+}
+
+impl<T: PhpIterable> ClassIterator<T> {
+    const FUNCS: zend_object_iterator_funcs = zend_object_iterator_funcs {
+        dtor: Some(Self::dtor),
+        valid: Some(Self::valid),
+        get_current_data: Some(Self::get_current_data),
+        get_current_key: Some(Self::get_current_key),
+        move_forward: Some(Self::move_forward),
+        rewind: Some(Self::rewind),
+        invalidate_current: None,
+        get_gc: None,
+    };
+
+    /// Handler creating an iterator over `object`, set as the `get_iterator`
+    /// of the class entry.
+    pub(crate) unsafe extern "C" fn get_iterator(
+        _: *mut ClassEntry,
+        object: *mut Zval,
+        by_ref: c_int,
+    ) -> *mut ZendIterator {
+        if by_ref != 0 {
+            let _ = PhpException::new(
+                "An iterator cannot be used with foreach by reference".into(),
+                0,
+                ce::error(),
+            )
+            .throw();
+            return ptr::null_mut();
+        }
+        let Some(object) = object.as_ref() else {
+            return ptr::null_mut();
+        };
+
+        // The memory is freed by PHP when the iterator is released.
+        let iterator = emalloc(Layout::new::<Self>()).cast::<Self>();
+        zend_iterator_init(iterator.cast());
+        // The iterator holds a reference to the object while iterating.
+        ptr::write(&raw mut (*iterator).it.data, object.shallow_clone());
+        let funcs: &'static zend_object_iterator_funcs = &Self::FUNCS;
+        (*iterator).it.funcs = funcs;
+        (*iterator).it.index = 0;
+        ptr::write(&raw mut (*iterator).iter, None);
+        ptr::write(&raw mut (*iterator).current, None);
+
+        iterator.cast()
+    }
+
+    /// Returns the Rust iterator for a `zend_object_iterator` created by
+    /// [`ClassIterator::get_iterator`].
+    unsafe fn from_ptr<'a>(iter: *mut ZendIterator) -> &'a mut Self {
+        &mut *iter.cast::<Self>()
+    }
+
+    /// Moves the iterator to the next element, throwing an exception if the
+    /// element could not be converted into PHP values.
+    fn fetch(&mut self) {
+        let next = self
+            .iter
+            .as_mut()
+            .and_then(Iterator::next)
+            .map(|(key, value)| -> Result<_> {
+                Ok((key.into_zval(false)?, value.into_zval(false)?))
+            });
+        self.current = match next.transpose() {
+            Ok(current) => current,
+            Err(e) => {
+                let _ = PhpException::default(e.to_string()).throw();
+                None
+            }
+        };
+    }
+
+    unsafe extern "C" fn dtor(iter: *mut ZendIterator) {
+        let iterator = iter.cast::<Self>();
+        ptr::drop_in_place(&raw mut (*iterator).iter);
+        ptr::drop_in_place(&raw mut (*iterator).current);
+        ptr::drop_in_place(&raw mut (*iterator).it.data);
+    }
+
+    unsafe extern "C" fn valid(iter: *mut ZendIterator) -> zend_result {
+        if Self::from_ptr(iter).current.is_some() {
+            ZEND_RESULT_CODE_SUCCESS
+        } else {
+            ZEND_RESULT_CODE_FAILURE
+        }
+    }
+
+    unsafe extern "C" fn get_current_data(iter: *mut ZendIterator) -> *mut Zval {
+        Self::from_ptr(iter)
+            .current
+            .as_mut()
+            .map_or(ptr::null_mut(), |(_, value)| value)
+    }
+
+    unsafe extern "C" fn get_current_key(iter: *mut ZendIterator, key: *mut Zval) {
+        let key_value = Self::from_ptr(iter)
+            .current
+            .as_ref()
+            .map_or_else(Zval::new, |(key, _)| key.shallow_clone());
+        // `key` is not initialized.
+        ptr::write(key, key_value);
+    }
+
+    unsafe extern "C" fn move_forward(iter: *mut ZendIterator) {
+        Self::from_ptr(iter).fetch();
+    }
+
+    unsafe extern "C" fn rewind(iter: *mut ZendIterator) {
+        let iterator = Self::from_ptr(iter);
+        iterator.iter = None;
+        iterator.current = None;
+
+        let Some(object) = iterator.it.data.object_mut() else {
+            return;
+        };
+        // Objects of PHP subclasses which did not call the parent constructor
+        // have no elements.
+        let Some(this) =
+            ZendClassObject::<T>::from_zend_obj(object).and_then(|obj| obj.obj.as_ref())
+        else {
+            return;
+        };
+        match this.get_iterator() {
+            Ok(iter) => {
+                iterator.iter = Some(iter);
+                iterator.fetch();
+            }
+            Err(e) => {
+                let _ = e.throw();
+            }
+        }
+    }
+}
+
+zend_fastcall! {
+    /// Implementation of `IteratorAggregate::getIterator()` for classes
+    /// implementing [`PhpIterable`], returning an `InternalIterator` over the
+    /// object.
+    pub(crate) extern fn get_iterator_method(ex: &mut ExecuteData, retval: &mut Zval) {
+        // The function throws an exception on failure.
+        unsafe { zend_create_internal_iterator_zval(retval, &raw mut ex.This) };
+    }
+}
@@ -0,0 +1,161 @@
+//! Hooks run when the extension or a request is started or shut down.
+
+use std::{
+    any::Any,
+    fmt::{Debug, Display},
+    panic::{catch_unwind, AssertUnwindSafe},
+};
+
+use crate::{
+    builders::StartupShutdownFunc,
+    error::{php_error, Error, Result},
+    flags::ErrorType,
+};
+
+/// A closure registered with one of the lifecycle methods of
+/// [`ModuleBuilder`](crate::builders::ModuleBuilder), e.g.
+/// [`on_rinit`](crate::builders::ModuleBuilder::on_rinit).
+pub(crate) struct LifecycleHook(Box<dyn Fn(i32) -> Result<(), String> + Send + Sync>);
+
+impl LifecycleHook {
+    /// Creates a hook from a closure, which is passed the module number.
+    pub(crate) fn new<F, E>(hook: F) -> Self
+    where
+        F: Fn(i32) -> Result<(), E> + Send + Sync + 'static,
+        E: Display,
+    {
+        Self(Box::new(move |module_number| {
+            hook(module_number).map_err(|e| e.to_string())
+        }))
+    }
+
+    /// Runs the hook, converting panics into errors.
+    fn run(&self, module_number: i32) -> Result<(), String> {
+        catch_unwind(AssertUnwindSafe(|| (self.0)(module_number)))
+            .unwrap_or_else(|panic| Err(panic_message(&*panic)))
+    }
+}
+
+impl Debug for LifecycleHook {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LifecycleHook").finish_non_exhaustive()
+    }
+}
+
+/// The hooks of one stage of the lifecycle, e.g. request startup.
+#[derive(Debug)]
+pub(crate) struct LifecycleHooks {
+    /// The function set for the stage on the module entry, which is called
+    /// before the hooks.
+    func: Option<StartupShutdownFunc>,
+    /// The hooks, in registration order.
+    hooks: Vec<LifecycleHook>,
+    /// Whether the stage is a shutdown stage, where all hooks run even if one
+    /// of them fails.
+    shutdown: bool,
+}
+
+impl LifecycleHooks {
+    /// Creates the hooks of a stage.
+    ///
+    /// # Parameters
+    ///
+    /// * `func` - The function set for the stage, called before the hooks.
+    /// * `hooks` - The hooks of the stage.
+    /// * `shutdown` - Whether the stage is a shutdown stage.
+    pub(crate) fn new(
+        func: Option<StartupShutdownFunc>,
+        hooks: Vec<LifecycleHook>,
+        shutdown: bool,
+    ) -> Self {
+        Self {
+            func,
+            hooks,
+            shutdown,
+        }
+    }
+
+    /// Runs the function and hooks of the stage. Failures are reported as PHP
+    /// warnings.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the function or a hook failed or panicked.
+    pub(crate) fn run(&self, ty: i32, module_number: i32) -> Result<()> {
+        let mut result = match self.func {
+            Some(func) if unsafe { func(ty, module_number) } != 0 => Err(Error::LifecycleHook(
+                "Lifecycle function of the module failed".into(),
+            )),
+            _ => Ok(()),
+        };
+        for hook in &self.hooks {
+            if result.is_err() && !self.shutdown {
+                break;
+            }
+            if let Err(e) = hook.run(module_number) {
+                php_error(&ErrorType::CoreWarning, &e);
+                result = result.and(Err(Error::LifecycleHook(e)));
+            }
+        }
+        result
+    }
+
+    /// Returns true if the stage has no hooks.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.hooks.is_empty()
+    }
+
+    /// Returns the function set for the stage.
+    pub(crate) fn func(&self) -> Option<StartupShutdownFunc> {
+        self.func
+    }
+
+    /// Runs the function and hooks of the stage, returning the result expected
+    /// by PHP.
+    pub(crate) fn run_ffi(&self, ty: i32, module_number: i32) -> i32 {
+        i32::from(self.run(ty, module_number).is_err())
+    }
+}
+
+/// Returns the message of a caught panic.
+fn panic_message(panic: &(dyn Any + Send)) -> String {
+    let message = panic
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| panic.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("Box<dyn Any>");
+    format!("Lifecycle hook panicked: {message}")
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use std::sync::{Arc, Mutex};
+
+    use super::*;
+
+    #[test]
+    fn test_hooks_run_in_order() {
+        let calls = Arc::new(Mutex::new(vec![]));
+        let hooks = (0..3)
+            .map(|i| {
+                let calls = calls.clone();
+                LifecycleHook::new(move |module_number| {
+                    calls.lock().unwrap().push((i, module_number));
+                    Ok::<_, String>(())
+                })
+            })
+            .collect();
+        let hooks = LifecycleHooks::new(None, hooks, false);
+
+        assert!(hooks.run(0, 7).is_ok());
+        assert_eq!(*calls.lock().unwrap(), vec![(0, 7), (1, 7), (2, 7)]);
+    }
+
+    #[test]
+    fn test_panic_message() {
+        let panic = catch_unwind(|| panic!("boom")).unwrap_err();
+        assert_eq!(panic_message(&*panic), "Lifecycle hook panicked: boom");
+    }
+}
@@ -8,6 +8,9 @@ mod function;
 mod globals;
 mod handlers;
 mod ini_entry_def;
+mod ini_setting;
+mod iterator;
+mod lifecycle;
 mod linked_list;
 mod module;
 mod streams;
@@ -24,8 +27,14 @@ pub use class::ClassEntry;
 pub use ex::ExecuteData;
 pub use function::Function;
 pub use function::FunctionEntry;
+#[cfg(php_zts)]
+pub(crate) use globals::module_globals_id_ptr;
+#[cfg(not(php_zts))]
+pub(crate) use globals::module_globals_ptr;
+pub(crate) use globals::register_module_globals;
 pub use globals::ExecutorGlobals;
 pub use globals::FileGlobals;
+pub use globals::ModuleGlobals;
 pub use globals::ProcessGlobals;
 pub use globals::SapiGlobals;
 pub use globals::SapiHeader;
@@ -33,8 +42,12 @@ pub use globals::SapiHeaders;
 pub use globals::SapiModule;
 pub use handlers::ZendObjectHandlers;
 pub use ini_entry_def::IniEntryDef;
+pub(crate) use ini_setting::IniSettingEntry;
+pub use ini_setting::{IniSetting, IniValue};
+pub(crate) use iterator::{get_iterator_method, ClassIterator};
+pub(crate) use lifecycle::{LifecycleHook, LifecycleHooks};
 pub use linked_list::ZendLinkedList;
-pub use module::ModuleEntry;
+pub use module::{ModuleDep, ModuleEntry};
 pub use streams::*;
 #[cfg(feature = "embed")]
 pub(crate) use try_catch::panic_wrapper;
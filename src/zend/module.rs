@@ -1,11 +1,14 @@
 //! Builder and objects for creating modules in PHP. A module is the base of a
 //! PHP extension.
 
-use crate::ffi::zend_module_entry;
+use crate::ffi::{_zend_module_dep, zend_module_entry};
 
 /// A Zend module entry, also known as an extension.
 pub type ModuleEntry = zend_module_entry;
 
+/// A dependency of a Zend module on another module.
+pub type ModuleDep = _zend_module_dep;
+
 impl ModuleEntry {
     /// Allocates the module entry on the heap, returning a pointer to the
     /// memory location. The caller is responsible for the memory pointed to.
@@ -0,0 +1,14 @@
+<?php
+
+require(__DIR__ . '/../_utils.php');
+
+// Items which are not added to the module in `build_module` are registered
+// automatically
+assert(function_exists('test_auto_registered_function'));
+assert(test_auto_registered_function('World') === 'Hello, World!');
+
+assert(class_exists(TestAutoRegisteredClass::class));
+assert(interface_exists(TestAutoRegisteredShape::class));
+$obj = new TestAutoRegisteredClass(21);
+assert($obj instanceof TestAutoRegisteredShape);
+assert($obj->double() === 42);
@@ -0,0 +1,51 @@
+//! Items in this module are not added to the module in `build_module`, they
+//! are registered by `ModuleBuilder::auto_register`.
+
+use ext_php_rs::prelude::*;
+
+#[php_function]
+pub fn test_auto_registered_function(name: String) -> String {
+    format!("Hello, {name}!")
+}
+
+// Sorted after the class implementing it, but registered before it.
+#[php_interface]
+pub trait TestAutoRegisteredShape {
+    fn double(&self) -> i64;
+}
+
+#[php_class]
+#[php(implements(
+    ce = PhpInterfaceTestAutoRegisteredShape::ce,
+    stub = "TestAutoRegisteredShape"
+))]
+pub struct TestAutoRegisteredClass {
+    value: i64,
+}
+
+#[php_impl]
+impl TestAutoRegisteredClass {
+    pub fn __construct(value: i64) -> Self {
+        Self { value }
+    }
+
+    pub fn double(&self) -> i64 {
+        TestAutoRegisteredShape::double(self)
+    }
+}
+
+impl TestAutoRegisteredShape for TestAutoRegisteredClass {
+    fn double(&self) -> i64 {
+        self.value * 2
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn auto_register_works() {
+        assert!(crate::integration::test::run_php(
+            "auto_register/auto_register.php"
+        ));
+    }
+}
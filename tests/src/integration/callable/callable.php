@@ -1,3 +1,48 @@
 <?php
 
 assert(test_callable(fn (string $a) => $a, 'test') === 'test');
+
+// Prepared calls
+assert(test_callable_prepared(fn (int $a) => $a * 2, [1, 2, 3]) === [2, 4, 6]);
+assert(test_callable_prepared('strtoupper', ['a', 'b']) === ['A', 'B']);
+
+class CallableMagic
+{
+    public function __call(string $name, array $args): string
+    {
+        return $name . $args[0];
+    }
+}
+
+assert(test_callable_prepared([new CallableMagic(), 'magic'], [1, 2]) === ['magic1', 'magic2']);
+
+// Named arguments
+assert(test_callable_named(fn (string $a, string $b) => $a . $b, 'a', 'b') === 'ab');
+assert(test_callable_named(fn (string $a, string ...$rest) => $a . $rest['b'], 'a', 'b') === 'ab');
+
+// Arguments passed by reference
+$result = test_callable_by_ref(function (int &$value) {
+    $value *= 2;
+    return 'changed';
+}, 21);
+assert($result === ['changed', 42]);
+$result = test_callable_by_ref(fn (int $value) => $value + 1, 21);
+assert($result === [22, 21]);
+
+// Unpacked arrays
+$concat = fn (string $a, string $b = '-', string $c = '-') => $a . $b . $c;
+assert(test_callable_spread($concat, ['a', 'b']) === 'ab-');
+assert(test_callable_spread($concat, ['a', 'c' => 'c']) === 'a-c');
+assert(test_callable_spread('str_pad', ['5', 'length' => 3, 'pad_string' => '0', 'pad_type' => STR_PAD_LEFT]) === '005');
+
+assert(test_callable_positional_after_named());
+
+class CallableTest
+{
+    public function double(int $a): int
+    {
+        return $a * 2;
+    }
+}
+
+assert(test_call_method_named(new CallableTest(), 'double', 4) === 8);
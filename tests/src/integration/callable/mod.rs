@@ -1,12 +1,77 @@
-use ext_php_rs::{prelude::*, types::Zval};
+use ext_php_rs::{
+    prelude::*,
+    types::{CallArgs, ZendHashTable, ZendObject, Zval},
+};
 
 #[php_function]
 pub fn test_callable(call: ZendCallable, a: String) -> Zval {
     call.try_call(vec![&a]).expect("Failed to call function")
 }
 
+#[php_function]
+pub fn test_callable_prepared(call: ZendCallable, values: Vec<Zval>) -> Vec<Zval> {
+    let mut call = call.prepare().expect("Failed to prepare callable");
+    values
+        .iter()
+        .map(|value| call.call(&[value]).expect("Failed to call function"))
+        .collect()
+}
+
+#[php_function]
+pub fn test_callable_named(call: ZendCallable, a: String, b: String) -> Zval {
+    let args = CallArgs::new()
+        .named("b", b)
+        .and_then(|args| args.named("a", a))
+        .expect("Failed to build arguments");
+    call.try_call_with(args).expect("Failed to call function")
+}
+
+#[php_function]
+pub fn test_callable_by_ref(call: ZendCallable, value: i64) -> Vec<Zval> {
+    let mut arg = Zval::new();
+    arg.set_long(value);
+    let args = CallArgs::new()
+        .by_ref(&mut arg)
+        .expect("Failed to build arguments");
+    let result = call.try_call_with(args).expect("Failed to call function");
+    vec![result, arg]
+}
+
+#[php_function]
+pub fn test_callable_spread(call: ZendCallable, args: &ZendHashTable) -> Zval {
+    let args = CallArgs::new()
+        .spread(args)
+        .expect("Failed to build arguments");
+    call.try_call_with(args).expect("Failed to call function")
+}
+
+#[php_function]
+pub fn test_callable_positional_after_named() -> bool {
+    CallArgs::new()
+        .named("a", 1)
+        .and_then(|args| args.arg(2))
+        .is_err()
+}
+
+#[php_function]
+pub fn test_call_method_named(object: &ZendObject, method: &str, a: i64) -> Zval {
+    let args = CallArgs::new()
+        .named("a", a)
+        .expect("Failed to build arguments");
+    object
+        .try_call_method_with(method, args)
+        .expect("Failed to call method")
+}
+
 pub fn build_module(builder: ModuleBuilder) -> ModuleBuilder {
-    builder.function(wrap_function!(test_callable))
+    builder
+        .function(wrap_function!(test_callable))
+        .function(wrap_function!(test_callable_prepared))
+        .function(wrap_function!(test_callable_named))
+        .function(wrap_function!(test_callable_by_ref))
+        .function(wrap_function!(test_callable_spread))
+        .function(wrap_function!(test_callable_positional_after_named))
+        .function(wrap_function!(test_call_method_named))
 }
 
 #[cfg(test)]
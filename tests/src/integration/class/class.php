@@ -43,3 +43,165 @@ assert_exception_thrown(fn() => $arrayAccess[0] = 'foo');
 assert_exception_thrown(fn() => $arrayAccess['foo']);
 assert($arrayAccess[0] === true);
 assert($arrayAccess[1] === false);
+
+// Tests abstract classes
+$abstract = new ReflectionClass(TestAbstractClass::class);
+assert($abstract->isAbstract());
+assert($abstract->getMethod('sides')->isAbstract());
+assert($abstract->getMethod('area')->isAbstract());
+assert(!$abstract->getMethod('describe')->isAbstract());
+assert_exception_thrown(fn() => new TestAbstractClass('shape'));
+
+class TestAbstractSquare extends TestAbstractClass {
+    public function __construct(private float $side) {
+        parent::__construct('square');
+    }
+
+    public function sides(): int {
+        return 4;
+    }
+
+    public function area(float $scale): float {
+        return $this->side ** 2 * $scale;
+    }
+}
+
+$square = new TestAbstractSquare(2.0);
+assert($square instanceof TestAbstractClass);
+assert($square->area(2.0) === 8.0);
+assert($square->describe() === 'A square');
+
+// Tests static properties
+assert(TestStaticProps::$count === 0);
+assert(TestStaticProps::increment() === 1);
+assert(TestStaticProps::$count === 1);
+TestStaticProps::$count = 10;
+assert(TestStaticProps::increment() === 11);
+assert(TestStaticProps::label() === 'counter');
+assert((new ReflectionProperty(TestStaticProps::class, 'label'))->isProtected());
+assert((new ReflectionProperty(TestStaticProps::class, 'count'))->isStatic());
+assert(TestStaticProps::$max_count === 100);
+
+// Tests readonly and typed properties
+if (PHP_VERSION_ID >= 80100) {
+    $readonly = new TestReadonlyProps(5);
+    assert($readonly->id === 5);
+    assert_exception_thrown(fn() => $readonly->id = 6);
+    assert($readonly->increment() === 6);
+    assert($readonly->id === 6);
+    $idProp = new ReflectionProperty(TestReadonlyProps::class, 'id');
+    assert($idProp->isReadOnly());
+    assert((string) $idProp->getType() === 'int');
+    $nameProp = new ReflectionProperty(TestReadonlyProps::class, 'name');
+    assert(!$nameProp->isReadOnly());
+    assert((string) $nameProp->getType() === '?string');
+    $readonly->name = 'test';
+    assert($readonly->name === 'test');
+
+    // A readonly property may be initialized once from the scope of the declaring class
+    $fresh = new TestReadonlyProps(1);
+    $init = Closure::bind(fn() => $this->id = 7, $fresh, TestReadonlyProps::class);
+    $init();
+    assert($fresh->id === 7);
+    assert_exception_thrown($init);
+    assert($fresh->id === 7);
+}
+
+// Tests property defaults
+$defaults = new TestPropertyDefaults();
+assert($defaults->count === 1);
+assert($defaults->name === 'named');
+assert((new ReflectionProperty(TestPropertyDefaults::class, 'count'))->getDefaultValue() === 10);
+assert((new ReflectionProperty(TestPropertyDefaults::class, 'name'))->getDefaultValue() === 'unnamed');
+assert((new ReflectionProperty(TestPropertyDefaults::class, 'timeout'))->getDefaultValue() === 3600);
+
+class TestPropertyDefaultsChild extends TestPropertyDefaults {
+    public function __construct() {}
+}
+
+$child = new TestPropertyDefaultsChild();
+assert($child->count === 10);
+assert($child->name === 'unnamed');
+assert(isset($child->count));
+$child->count = 20;
+assert($child->count === 20);
+
+// Tests compound assignment, indirect modification and unsetting of properties
+$indirect = new TestIndirectProps();
+$indirect->items = [1, 2];
+assert($indirect->sum() === 3);
+$indirect->count++;
+$indirect->count += 5;
+assert($indirect->count === 6);
+assert($indirect->count() === 6);
+$indirect->items[] = 3;
+assert($indirect->items === [1, 2, 3]);
+assert($indirect->sum() === 6);
+$ref = &$indirect->items;
+$ref[] = 4;
+assert($indirect->sum() === 10);
+unset($indirect->items[0]);
+assert($indirect->items === [2, 3, 4]);
+assert($indirect->sum() === 9);
+$indirect->object = new stdClass();
+$indirect->object->count = 1;
+$indirect->object->count++;
+assert($indirect->object->count === 2);
+assert_exception_thrown(function () use ($indirect) {
+    unset($indirect->items);
+});
+assert($indirect->items === [2, 3, 4]);
+
+// Tests cycles through values held by the Rust struct are collected
+gc_collect_cycles();
+$listeners = new TestGcListeners();
+$listeners->listen(function () use ($listeners) {});
+unset($listeners);
+assert(gc_collect_cycles() > 0);
+
+// Tests cloning
+$original = new TestCloneable();
+$original->items = ['a'];
+$copy = clone $original;
+$copy->items[] = 'b';
+assert($original->items === ['a']);
+assert($copy->items === ['a', 'b']);
+assert($original->clones() === 0);
+assert($copy->clones() === 1);
+$original->items[] = 'c';
+$other = clone $original;
+assert($other->items === ['a', 'c']);
+assert_exception_thrown(fn() => clone $arrayAccess);
+
+// Tests count() and array access handlers
+$vector = new TestVector();
+assert(count($vector) === 0);
+$vector[] = 1;
+$vector[] = 2;
+$vector[1] = 3;
+assert(count($vector) === 2);
+assert($vector[0] === 1);
+assert($vector[1] === 3);
+assert(isset($vector[1]));
+assert(!isset($vector[2]));
+assert(($vector[2] ?? 'default') === 'default');
+assert(empty($vector[5]));
+unset($vector[0]);
+assert(count($vector) === 1);
+assert($vector[0] === 3);
+assert_exception_thrown(fn() => $vector[5]);
+assert_exception_thrown(fn() => $vector[] = 'foo');
+
+// Tests iterating with foreach
+$vector[] = 4;
+$iterated = [];
+foreach ($vector as $key => $value) {
+    $iterated[$key] = $value;
+    $vector[] = 5;
+}
+assert($iterated === [3, 4]);
+assert($vector instanceof IteratorAggregate);
+assert(iterator_to_array($vector->getIterator()) === [3, 4, 5, 5]);
+assert_exception_thrown(function () use ($vector) {
+    foreach ($vector as &$value) {}
+});
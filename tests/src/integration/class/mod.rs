@@ -1,6 +1,8 @@
 #![allow(clippy::unused_self)]
 use ext_php_rs::{
+    class::RegisteredClass,
     convert::IntoZval,
+    ops::{PhpArrayAccess, PhpCountable, PhpIterable},
     prelude::*,
     types::{ZendClassObject, Zval},
     zend::ce,
@@ -144,12 +146,262 @@ impl TestClassExtendsImpl {
     }
 }
 
+#[php_class]
+#[php(abstract)]
+pub struct TestAbstractClass {
+    name: String,
+}
+
+#[php_impl]
+impl TestAbstractClass {
+    pub fn __construct(name: String) -> Self {
+        Self { name }
+    }
+
+    pub fn describe(&self) -> String {
+        format!("A {}", self.name)
+    }
+
+    #[php(abstract)]
+    pub fn sides(&self) -> i64 {
+        unimplemented!()
+    }
+
+    #[php(abstract)]
+    pub fn area(&self, scale: f64) -> f64;
+}
+
+#[php_class]
+pub struct TestStaticProps;
+
+#[php_impl]
+#[php(change_static_prop_case = "snake_case")]
+impl TestStaticProps {
+    #[php(static_prop)]
+    const COUNT: i64 = 0;
+
+    #[php(static_prop)]
+    const MAX_COUNT: i64 = 100;
+
+    #[php(static_prop, vis = "protected")]
+    const LABEL: &'static str = "counter";
+
+    pub fn increment() -> PhpResult<i64> {
+        let ce = Self::get_metadata().ce();
+        let count = ce.get_static_property::<i64>("count")? + 1;
+        ce.set_static_property("count", count)?;
+        Ok(count)
+    }
+
+    pub fn label() -> PhpResult<String> {
+        Ok(Self::get_metadata().ce().get_static_property("label")?)
+    }
+}
+
+#[cfg(feature = "readonly")]
+#[php_class]
+pub struct TestReadonlyProps {
+    #[php(prop, readonly)]
+    id: i64,
+    #[php(prop)]
+    name: Option<String>,
+}
+
+#[cfg(feature = "readonly")]
+#[php_impl]
+impl TestReadonlyProps {
+    pub fn __construct(id: i64) -> Self {
+        Self { id, name: None }
+    }
+
+    pub fn increment(&mut self) -> i64 {
+        self.id += 1;
+        self.id
+    }
+}
+
+#[php_class]
+pub struct TestPropertyDefaults {
+    #[php(prop, default = 10)]
+    count: i64,
+    #[php(prop, default = "unnamed")]
+    name: String,
+    #[php(prop, default = 60 * 60, stub = "3600")]
+    timeout: i64,
+}
+
+#[php_impl]
+impl TestPropertyDefaults {
+    pub fn __construct() -> Self {
+        Self {
+            count: 1,
+            name: "named".into(),
+            timeout: 5,
+        }
+    }
+}
+
+#[php_class]
+pub struct TestIndirectProps {
+    #[php(prop)]
+    items: Vec<i64>,
+    #[php(prop)]
+    count: i64,
+    object: Zval,
+}
+
+#[php_impl]
+impl TestIndirectProps {
+    pub fn __construct() -> Self {
+        Self {
+            items: vec![],
+            count: 0,
+            object: Zval::new(),
+        }
+    }
+
+    #[php(getter)]
+    pub fn get_object(&self) -> Zval {
+        self.object.shallow_clone()
+    }
+
+    #[php(setter)]
+    pub fn set_object(&mut self, object: Zval) {
+        self.object = object;
+    }
+
+    pub fn sum(&self) -> i64 {
+        self.items.iter().sum()
+    }
+
+    pub fn count(&self) -> i64 {
+        self.count
+    }
+}
+
+#[php_class]
+#[derive(GcTraverse)]
+pub struct TestGcListeners {
+    listeners: Vec<Zval>,
+}
+
+#[php_impl]
+impl TestGcListeners {
+    pub fn __construct() -> Self {
+        Self { listeners: vec![] }
+    }
+
+    pub fn listen(&mut self, listener: &Zval) {
+        self.listeners.push(listener.shallow_clone());
+    }
+}
+
+#[php_class]
+#[derive(Clone)]
+pub struct TestCloneable {
+    #[php(prop)]
+    items: Vec<String>,
+    clones: i64,
+}
+
+#[php_impl]
+impl TestCloneable {
+    pub fn __construct() -> Self {
+        Self {
+            items: vec![],
+            clones: 0,
+        }
+    }
+
+    pub fn __clone(&mut self) {
+        self.clones += 1;
+    }
+
+    pub fn clones(&self) -> i64 {
+        self.clones
+    }
+}
+
+#[php_class]
+pub struct TestVector {
+    items: Vec<i64>,
+}
+
+#[php_impl]
+impl TestVector {
+    pub fn __construct() -> Self {
+        Self { items: vec![] }
+    }
+}
+
+impl TestVector {
+    fn index(&self, offset: &Zval) -> Option<usize> {
+        let index = usize::try_from(offset.long()?).ok()?;
+        (index < self.items.len()).then_some(index)
+    }
+}
+
+impl PhpCountable for TestVector {
+    fn count(&self) -> PhpResult<i64> {
+        Ok(i64::try_from(self.items.len()).map_err(|_| "Too many items")?)
+    }
+}
+
+impl PhpIterable for TestVector {
+    type Key = usize;
+    type Value = i64;
+    type Iter = std::iter::Enumerate<std::vec::IntoIter<i64>>;
+
+    fn get_iterator(&self) -> PhpResult<Self::Iter> {
+        Ok(self.items.clone().into_iter().enumerate())
+    }
+}
+
+impl PhpArrayAccess for TestVector {
+    fn offset_get(&self, offset: &Zval) -> PhpResult<Zval> {
+        let index = self.index(offset).ok_or("Undefined offset")?;
+        Ok(self.items[index].into_zval(false)?)
+    }
+
+    fn offset_set(&mut self, offset: Option<&Zval>, value: &Zval) -> PhpResult {
+        let value = value.long().ok_or("Expected integer value")?;
+        match offset {
+            Some(offset) => {
+                let index = self.index(offset).ok_or("Undefined offset")?;
+                self.items[index] = value;
+            }
+            None => self.items.push(value),
+        }
+        Ok(())
+    }
+
+    fn offset_exists(&self, offset: &Zval) -> PhpResult<bool> {
+        Ok(self.index(offset).is_some())
+    }
+
+    fn offset_unset(&mut self, offset: &Zval) -> PhpResult {
+        if let Some(index) = self.index(offset) {
+            self.items.remove(index);
+        }
+        Ok(())
+    }
+}
+
 pub fn build_module(builder: ModuleBuilder) -> ModuleBuilder {
+    #[cfg(feature = "readonly")]
+    let builder = builder.class::<TestReadonlyProps>();
     builder
         .class::<TestClass>()
         .class::<TestClassArrayAccess>()
         .class::<TestClassExtends>()
         .class::<TestClassExtendsImpl>()
+        .class::<TestAbstractClass>()
+        .class::<TestStaticProps>()
+        .class::<TestPropertyDefaults>()
+        .class::<TestIndirectProps>()
+        .class::<TestGcListeners>()
+        .class::<TestCloneable>()
+        .class::<TestVector>()
         .function(wrap_function!(test_class))
         .function(wrap_function!(throw_exception))
 }
@@ -0,0 +1,7 @@
+<?php
+
+$dependencies = (new ReflectionExtension('tests'))->getDependencies();
+
+// Declared with `#[php(requires = ["standard"])]` on the module function.
+assert($dependencies['standard'] === 'Required');
+assert($dependencies['json'] === 'Optional');
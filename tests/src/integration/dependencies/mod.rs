@@ -0,0 +1,15 @@
+use ext_php_rs::prelude::*;
+
+pub fn build_module(builder: ModuleBuilder) -> ModuleBuilder {
+    builder.optional("json")
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn dependencies_work() {
+        assert!(crate::integration::test::run_php(
+            "dependencies/dependencies.php"
+        ));
+    }
+}
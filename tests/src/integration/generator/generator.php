@@ -0,0 +1,23 @@
+<?php
+
+require(__DIR__ . '/../_utils.php');
+
+$squares = test_generator_squares(4);
+assert($squares instanceof Iterator);
+assert(iterator_to_array($squares) === [0 => 1, 1 => 4, 2 => 9, 3 => 16]);
+
+// Generators can only be iterated once
+assert_exception_thrown(fn () => iterator_to_array($squares));
+
+// Rewinding before advancing is allowed
+$empty = test_generator_squares(0);
+$empty->rewind();
+assert(!$empty->valid());
+assert($empty->current() === null);
+
+$keys = test_generator_keys();
+$result = [];
+foreach ($keys as $key => $value) {
+    $result[$key] = $value;
+}
+assert($result === ['first' => 1, 'second' => 2]);
@@ -0,0 +1,25 @@
+use ext_php_rs::prelude::*;
+
+#[php_function]
+pub fn test_generator_squares(n: i64) -> Generator {
+    Generator::new((1..=n).map(|i| i * i))
+}
+
+#[php_function]
+pub fn test_generator_keys() -> Generator {
+    Generator::with_keys([("first", 1), ("second", 2)])
+}
+
+pub fn build_module(builder: ModuleBuilder) -> ModuleBuilder {
+    builder
+        .function(wrap_function!(test_generator_squares))
+        .function(wrap_function!(test_generator_keys))
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn generator_works() {
+        assert!(crate::integration::test::run_php("generator/generator.php"));
+    }
+}
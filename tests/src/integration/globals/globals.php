@@ -6,3 +6,10 @@ assert(test_globals_http_cookie() === []);
 assert(!empty(test_globals_http_server()));
 assert(test_globals_http_request() === []);
 assert(test_globals_http_files() === []);
+
+// Module globals
+assert(test_globals_name() === null);
+test_globals_set_name('test');
+assert(test_globals_name() === 'test');
+assert(test_globals_increment() === 1);
+assert(test_globals_increment() === 2);
@@ -1,4 +1,17 @@
-use ext_php_rs::{boxed::ZBox, prelude::*, types::ZendHashTable, zend::ProcessGlobals};
+use ext_php_rs::{
+    boxed::ZBox,
+    prelude::*,
+    types::ZendHashTable,
+    zend::{ModuleGlobals, ProcessGlobals},
+};
+
+#[derive(Default)]
+pub struct TestModuleGlobals {
+    counter: i64,
+    name: Option<String>,
+}
+
+impl ModuleGlobals for TestModuleGlobals {}
 
 #[php_function]
 pub fn test_globals_http_get() -> ZBox<ZendHashTable> {
@@ -33,8 +46,29 @@ pub fn test_globals_http_files() -> ZBox<ZendHashTable> {
     ProcessGlobals::get().http_files_vars().to_owned()
 }
 
+#[php_function]
+pub fn test_globals_increment() -> i64 {
+    let mut globals = TestModuleGlobals::get_mut();
+    globals.counter += 1;
+    globals.counter
+}
+
+#[php_function]
+pub fn test_globals_set_name(name: String) {
+    TestModuleGlobals::get_mut().name = Some(name);
+}
+
+#[php_function]
+pub fn test_globals_name() -> Option<String> {
+    TestModuleGlobals::get().name.clone()
+}
+
 pub fn build_module(builder: ModuleBuilder) -> ModuleBuilder {
     builder
+        .globals::<TestModuleGlobals>()
+        .function(wrap_function!(test_globals_increment))
+        .function(wrap_function!(test_globals_set_name))
+        .function(wrap_function!(test_globals_name))
         .function(wrap_function!(test_globals_http_get))
         .function(wrap_function!(test_globals_http_post))
         .function(wrap_function!(test_globals_http_cookie))
@@ -0,0 +1,14 @@
+<?php
+
+ob_start();
+phpinfo(INFO_MODULES);
+$info = ob_get_clean();
+
+assert(str_contains($info, "tests support => enabled\n"));
+assert(str_contains($info, "Cells => one => two\n"));
+assert(str_contains($info, "Captured => captured value\n"));
+assert(str_contains($info, "Settings"));
+assert(str_contains($info, "tests.ini_limit => 1K => 1K\n"));
+assert(str_contains($info, "After settings =>  \n"));
+assert(strpos($info, 'Settings') < strpos($info, 'tests.ini_limit'));
+assert(strpos($info, 'tests.ini_limit') < strpos($info, 'After settings'));
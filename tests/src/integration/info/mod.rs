@@ -0,0 +1,22 @@
+use ext_php_rs::{builders::InfoTable, prelude::*};
+
+pub fn build_module(builder: ModuleBuilder) -> ModuleBuilder {
+    let captured = String::from("captured value");
+    builder.info_table(move || {
+        InfoTable::new()
+            .header(["tests support", "enabled"])
+            .row(["Cells", "one", "two"])
+            .row(["Captured", captured.as_str()])
+            .colspan_header(3, "Settings")
+            .ini_entries()
+            .row(["After settings", ""])
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn info_works() {
+        assert!(crate::integration::test::run_php("info/info.php"));
+    }
+}
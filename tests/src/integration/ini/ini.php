@@ -0,0 +1,35 @@
+<?php
+
+// Default values
+assert(ini_get('tests.ini_enabled') === 'yes');
+assert(test_ini_enabled() === true);
+assert(test_ini_limit() === 1024);
+assert(test_ini_timeout() === 1.5);
+assert(test_ini_name() === 'default');
+
+// Changed values are parsed
+assert(ini_set('tests.ini_enabled', 'off') === 'yes');
+assert(test_ini_enabled() === false);
+assert(ini_set('tests.ini_limit', '2K') === '1K');
+assert(test_ini_limit() === 2048);
+
+// Invalid values are rejected
+assert(ini_set('tests.ini_limit', '-1') === false);
+assert(ini_set('tests.ini_limit', 'abc') === false);
+assert(test_ini_limit() === 2048);
+assert(ini_get('tests.ini_limit') === '2K');
+
+// System settings cannot be changed at runtime
+assert(ini_set('tests.ini_name', 'changed') === false);
+assert(test_ini_name() === 'default');
+
+// Restored values are parsed
+ini_restore('tests.ini_limit');
+assert(test_ini_limit() === 1024);
+
+// Settings are shown by phpinfo()
+ob_start();
+phpinfo(INFO_MODULES);
+$info = ob_get_clean();
+assert(str_contains($info, "tests.ini_limit => 1K => 1K\n"));
+assert(str_contains($info, "tests.ini_name => default => default\n"));
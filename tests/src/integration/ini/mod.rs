@@ -0,0 +1,52 @@
+use std::time::Duration;
+
+use ext_php_rs::{flags::IniEntryPermission, prelude::*, zend::IniSetting};
+
+static TEST_INI_ENABLED: IniSetting<bool> =
+    IniSetting::new("tests.ini_enabled", "yes", IniEntryPermission::All);
+static TEST_INI_LIMIT: IniSetting<i64> =
+    IniSetting::new("tests.ini_limit", "1K", IniEntryPermission::All).on_modify(|v| *v > 0);
+static TEST_INI_TIMEOUT: IniSetting<Duration> =
+    IniSetting::new("tests.ini_timeout", "1.5", IniEntryPermission::All);
+static TEST_INI_NAME: IniSetting<String> =
+    IniSetting::new("tests.ini_name", "default", IniEntryPermission::System);
+
+#[php_function]
+pub fn test_ini_enabled() -> bool {
+    TEST_INI_ENABLED.get()
+}
+
+#[php_function]
+pub fn test_ini_limit() -> i64 {
+    TEST_INI_LIMIT.get()
+}
+
+#[php_function]
+pub fn test_ini_timeout() -> f64 {
+    TEST_INI_TIMEOUT.get().as_secs_f64()
+}
+
+#[php_function]
+pub fn test_ini_name() -> String {
+    TEST_INI_NAME.get()
+}
+
+pub fn build_module(builder: ModuleBuilder) -> ModuleBuilder {
+    builder
+        .ini_setting(&TEST_INI_ENABLED)
+        .ini_setting(&TEST_INI_LIMIT)
+        .ini_setting(&TEST_INI_TIMEOUT)
+        .ini_setting(&TEST_INI_NAME)
+        .function(wrap_function!(test_ini_enabled))
+        .function(wrap_function!(test_ini_limit))
+        .function(wrap_function!(test_ini_timeout))
+        .function(wrap_function!(test_ini_name))
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn ini_works() {
+        assert!(crate::integration::test::run_php("ini/ini.php"));
+    }
+}
@@ -0,0 +1,26 @@
+<?php
+
+require(__DIR__ . '/../_utils.php');
+
+assert(interface_exists('Shape'));
+assert(!class_exists('Shape'));
+
+$interface = new ReflectionClass('Shape');
+assert($interface->isInterface());
+assert($interface->implementsInterface(Countable::class));
+assert($interface->getConstant('UNIT') === 'cm');
+assert(Shape::UNIT === 'cm');
+assert($interface->getMethod('area')->isAbstract());
+assert($interface->getMethod('describe_shape')->isAbstract());
+assert($interface->getMethod('unit')->isStatic());
+
+assert_exception_thrown(fn () => new ReflectionMethod('Shape', 'describe'));
+
+$square = new TestInterfaceSquare(2.0);
+assert($square instanceof Shape);
+assert($square instanceof Countable);
+assert($square->area() === 4.0);
+assert($square->describe_shape('A') === 'A square');
+assert(TestInterfaceSquare::unit() === 'cm');
+assert(count($square) === 4);
+assert(TestInterfaceSquare::UNIT === 'cm');
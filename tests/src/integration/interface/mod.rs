@@ -0,0 +1,75 @@
+#![allow(clippy::unused_self)]
+use ext_php_rs::{prelude::*, zend::ce};
+
+/// Doc comment
+/// Goes here
+#[php_interface]
+#[php(extends(ce = ce::countable, stub = "\\Countable"))]
+pub trait Shape {
+    const UNIT: &'static str = "cm";
+
+    fn area(&self) -> f64;
+
+    #[php(name = "describe_shape")]
+    fn describe(&self, prefix: String) -> String;
+
+    fn unit() -> String;
+}
+
+#[php_class]
+#[php(implements(ce = PhpInterfaceShape::ce, stub = "Shape"))]
+pub struct TestInterfaceSquare {
+    side: f64,
+}
+
+impl Shape for TestInterfaceSquare {
+    fn area(&self) -> f64 {
+        self.side * self.side
+    }
+
+    fn describe(&self, prefix: String) -> String {
+        format!("{prefix} square")
+    }
+
+    fn unit() -> String {
+        Self::UNIT.to_string()
+    }
+}
+
+#[php_impl]
+impl TestInterfaceSquare {
+    pub fn __construct(side: f64) -> Self {
+        Self { side }
+    }
+
+    pub fn area(&self) -> f64 {
+        Shape::area(self)
+    }
+
+    #[php(name = "describe_shape")]
+    pub fn describe_shape(&self, prefix: String) -> String {
+        Shape::describe(self, prefix)
+    }
+
+    pub fn unit() -> String {
+        <Self as Shape>::unit()
+    }
+
+    pub fn count(&self) -> i64 {
+        4
+    }
+}
+
+pub fn build_module(builder: ModuleBuilder) -> ModuleBuilder {
+    builder
+        .interface::<PhpInterfaceShape>()
+        .class::<TestInterfaceSquare>()
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn interface_works() {
+        assert!(crate::integration::test::run_php("interface/interface.php"));
+    }
+}
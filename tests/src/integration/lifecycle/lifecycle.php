@@ -0,0 +1,4 @@
+<?php
+
+assert(test_lifecycle_calls() === ['minit first', 'minit second', 'rinit']);
+assert(test_lifecycle_requests() === 1);
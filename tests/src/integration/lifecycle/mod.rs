@@ -0,0 +1,49 @@
+use std::sync::{
+    atomic::{AtomicI64, Ordering},
+    Mutex,
+};
+
+use ext_php_rs::prelude::*;
+
+static HOOK_CALLS: Mutex<Vec<&'static str>> = Mutex::new(Vec::new());
+static REQUESTS: AtomicI64 = AtomicI64::new(0);
+
+fn record(hook: &'static str) -> Result<(), String> {
+    HOOK_CALLS.lock().map_err(|e| e.to_string())?.push(hook);
+    Ok(())
+}
+
+#[php_function]
+pub fn test_lifecycle_calls() -> Vec<&'static str> {
+    HOOK_CALLS
+        .lock()
+        .map(|calls| calls.clone())
+        .unwrap_or_default()
+}
+
+#[php_function]
+pub fn test_lifecycle_requests() -> i64 {
+    REQUESTS.load(Ordering::SeqCst)
+}
+
+pub fn build_module(builder: ModuleBuilder) -> ModuleBuilder {
+    builder
+        .function(wrap_function!(test_lifecycle_calls))
+        .function(wrap_function!(test_lifecycle_requests))
+        .on_minit(|_| record("minit first"))
+        .on_minit(|_| record("minit second"))
+        .on_rinit(|_| {
+            REQUESTS.fetch_add(1, Ordering::SeqCst);
+            record("rinit")
+        })
+        .on_rshutdown(|_| record("rshutdown"))
+        .on_mshutdown(|_| record("mshutdown"))
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn lifecycle_works() {
+        assert!(crate::integration::test::run_php("lifecycle/lifecycle.php"));
+    }
+}
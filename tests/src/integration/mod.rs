@@ -1,22 +1,35 @@
 pub mod array;
+#[cfg(feature = "inventory")]
+pub mod auto_register;
 pub mod binary;
 pub mod bool;
 pub mod callable;
 pub mod class;
 pub mod closure;
 pub mod defaults;
+pub mod dependencies;
 #[cfg(feature = "enum")]
 pub mod enum_;
 pub mod exception;
+pub mod generator;
 pub mod globals;
+pub mod info;
+pub mod ini;
+pub mod interface;
 pub mod iterator;
+pub mod lifecycle;
 pub mod magic_method;
+pub mod named_args;
 pub mod nullable;
 pub mod number;
 pub mod object;
+pub mod operators;
+pub mod resource;
+pub mod serde;
 pub mod string;
 pub mod types;
 pub mod variadic_args;
+pub mod zval_convert;
 
 #[cfg(test)]
 mod test {
@@ -36,6 +49,14 @@ mod test {
             {
                 command.arg("--features=enum");
             }
+            #[cfg(feature = "readonly")]
+            {
+                command.arg("--features=readonly");
+            }
+            #[cfg(feature = "inventory")]
+            {
+                command.arg("--features=inventory");
+            }
             assert!(command
                 .output()
                 .expect("failed to build extension")
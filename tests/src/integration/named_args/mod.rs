@@ -0,0 +1,46 @@
+use std::collections::HashMap;
+
+use ext_php_rs::{prelude::*, types::Zval};
+
+#[php_function]
+pub fn test_named_args(a: i64, b: Option<i64>, c: Option<i64>) -> String {
+    format!("{a}-{b:?}-{c:?}")
+}
+
+#[php_function]
+pub fn test_named_rest(
+    prefix: String,
+    #[php(named_rest)] options: HashMap<String, Zval>,
+) -> String {
+    let mut keys = options.into_keys().collect::<Vec<_>>();
+    keys.sort();
+    format!("{prefix}{}", keys.join(","))
+}
+
+#[php_function]
+pub fn test_named_rest_typed(#[php(named_rest)] options: HashMap<String, i64>) -> i64 {
+    options.values().sum()
+}
+
+#[php_function]
+pub fn test_named_variadic(values: &[&Zval]) -> usize {
+    values.len()
+}
+
+pub fn build_module(builder: ModuleBuilder) -> ModuleBuilder {
+    builder
+        .function(wrap_function!(test_named_args))
+        .function(wrap_function!(test_named_rest))
+        .function(wrap_function!(test_named_rest_typed))
+        .function(wrap_function!(test_named_variadic))
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn named_args_works() {
+        assert!(crate::integration::test::run_php(
+            "named_args/named_args.php"
+        ));
+    }
+}
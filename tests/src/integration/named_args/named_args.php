@@ -0,0 +1,27 @@
+<?php
+
+require __DIR__ . "/../_utils.php";
+
+// Named arguments are mapped onto the parameters
+assert(test_named_args(1, 2, 3) === '1-Some(2)-Some(3)');
+assert(test_named_args(c: 3, a: 1) === '1-None-Some(3)');
+assert(test_named_args(1, c: 3) === '1-None-Some(3)');
+assert_exception_thrown(fn () => test_named_args(1, d: 4));
+assert_exception_thrown(fn () => test_named_args(b: 2));
+
+// Unknown named arguments are collected by the named rest argument
+assert(test_named_rest('keys:') === 'keys:');
+assert(test_named_rest('keys:', timeout: 5, debug: true) === 'keys:debug,timeout');
+assert(test_named_rest(prefix: 'keys:', retry: null) === 'keys:retry');
+assert_exception_thrown(fn () => test_named_rest('keys:', 'extra'));
+assert(test_named_rest_typed(a: 1, b: 2, c: 3) === 6);
+try {
+    test_named_rest_typed(a: 1, b: 'x');
+    assert(false, 'Expected a TypeError');
+} catch (TypeError $e) {
+    assert(str_contains($e->getMessage(), '$b'));
+}
+
+// Variadic functions without a named rest argument reject named arguments
+assert(test_named_variadic(1, 2) === 2);
+assert_exception_thrown(fn () => test_named_variadic(1, unknown: 2));
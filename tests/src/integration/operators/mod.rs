@@ -0,0 +1,83 @@
+use std::cmp::Ordering;
+
+use ext_php_rs::{
+    convert::IntoZval,
+    flags::DataType,
+    ops::{Operator, PhpCast, PhpCompare, PhpOperators},
+    prelude::*,
+    types::Zval,
+};
+
+#[php_class]
+pub struct TestMoney {
+    cents: i64,
+}
+
+#[php_impl]
+impl TestMoney {
+    pub fn __construct(cents: i64) -> Self {
+        Self { cents }
+    }
+
+    pub fn cents(&self) -> i64 {
+        self.cents
+    }
+}
+
+/// Returns the amount of cents of a money object or an integer.
+fn cents(zv: &Zval) -> Option<i64> {
+    zv.extract::<&TestMoney>()
+        .map(|money| money.cents)
+        .or_else(|| zv.long())
+}
+
+impl PhpCompare for TestMoney {
+    fn compare(&self, other: &Zval) -> PhpResult<Option<Ordering>> {
+        Ok(cents(other).map(|other| self.cents.cmp(&other)))
+    }
+}
+
+impl PhpCast for TestMoney {
+    fn cast(&self, ty: DataType) -> PhpResult<Option<Zval>> {
+        Ok(match ty {
+            DataType::String => {
+                Some(format!("{}.{:02}", self.cents / 100, self.cents % 100).into_zval(false)?)
+            }
+            DataType::Long => Some(self.cents.into_zval(false)?),
+            _ => None,
+        })
+    }
+}
+
+impl PhpOperators for TestMoney {
+    fn operation(op: Operator, lhs: &Zval, rhs: &Zval) -> PhpResult<Option<Zval>> {
+        let (Some(lhs), Some(rhs)) = (cents(lhs), cents(rhs)) else {
+            return Ok(None);
+        };
+        let cents = match op {
+            Operator::Add => lhs + rhs,
+            Operator::Sub => lhs - rhs,
+            Operator::Mul => lhs * rhs,
+            Operator::Div => {
+                if rhs == 0 {
+                    return Err("Division by zero".into());
+                }
+                lhs / rhs
+            }
+            _ => return Ok(None),
+        };
+        Ok(Some(TestMoney { cents }.into_zval(false)?))
+    }
+}
+
+pub fn build_module(builder: ModuleBuilder) -> ModuleBuilder {
+    builder.class::<TestMoney>()
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn operators_works() {
+        assert!(crate::integration::test::run_php("operators/operators.php"));
+    }
+}
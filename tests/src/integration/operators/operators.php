@@ -0,0 +1,46 @@
+<?php
+
+require(__DIR__ . '/../_utils.php');
+
+$a = new TestMoney(150);
+$b = new TestMoney(250);
+
+// Comparison
+assert($a < $b);
+assert($b > $a);
+assert($a == new TestMoney(150));
+assert($a != $b);
+assert(($a <=> $b) === -1);
+assert($a == 150);
+assert(150 == $a);
+assert(100 < $a);
+// Values the class does not compare fall back to PHP, which casts the object
+assert($a == '1.50');
+assert('2.50' == $b);
+
+// Casts
+assert((string) $a === '1.50');
+assert("Total: $b" === 'Total: 2.50');
+assert((int) $a === 150);
+assert((bool) $a === true);
+
+// Arithmetic
+$sum = $a + $b;
+assert($sum instanceof TestMoney);
+assert($sum->cents() === 400);
+assert(($b - $a)->cents() === 100);
+assert(($a * 2)->cents() === 300);
+assert(($b / 5)->cents() === 50);
+assert((1000 - $a)->cents() === 850);
+
+$total = $a;
+$total += $b;
+assert($total->cents() === 400);
+assert($a->cents() === 150);
+
+$counter = new TestMoney(1);
+$counter++;
+assert($counter->cents() === 2);
+
+assert_exception_thrown(fn() => $a / 0);
+assert_exception_thrown(fn() => $a % $b);
@@ -0,0 +1,72 @@
+use ext_php_rs::{
+    prelude::*,
+    types::{RegisteredResource, Resource, ResourceType, Zval},
+};
+
+pub struct TestCounter {
+    count: i64,
+}
+
+static TEST_COUNTER: ResourceType<TestCounter> = ResourceType::new("test counter");
+
+impl RegisteredResource for TestCounter {
+    fn resource_type() -> &'static ResourceType<Self> {
+        &TEST_COUNTER
+    }
+}
+
+#[php_function]
+pub fn test_resource_new(count: i64) -> Resource<TestCounter> {
+    Resource::new(TestCounter { count })
+}
+
+#[php_function]
+pub fn test_resource_increment(counter: Resource<TestCounter>) -> Option<i64> {
+    let mut counter = counter.get_mut()?;
+    counter.count += 1;
+    Some(counter.count)
+}
+
+#[php_function]
+pub fn test_resource_count(counter: &Zval) -> Option<i64> {
+    counter
+        .resource_as::<TestCounter>()
+        .map(|counter| counter.count)
+}
+
+#[php_function]
+pub fn test_resource_close(counter: Resource<TestCounter>) {
+    let mut counter = counter;
+    counter.close();
+}
+
+#[php_function]
+pub fn test_resource_close_borrowed(counter: Resource<TestCounter>) -> Option<i64> {
+    let mut other = counter.clone();
+    let value = counter.get()?;
+    // The value cannot be mutably borrowed through another reference while it
+    // is borrowed, and stays alive when the resource is closed.
+    if other.get_mut().is_some() {
+        return None;
+    }
+    other.close();
+    Some(value.count)
+}
+
+pub fn build_module(builder: ModuleBuilder) -> ModuleBuilder {
+    builder
+        .resource::<TestCounter>()
+        .function(wrap_function!(test_resource_new))
+        .function(wrap_function!(test_resource_increment))
+        .function(wrap_function!(test_resource_count))
+        .function(wrap_function!(test_resource_close))
+        .function(wrap_function!(test_resource_close_borrowed))
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn resource_works() {
+        assert!(crate::integration::test::run_php("resource/resource.php"));
+    }
+}
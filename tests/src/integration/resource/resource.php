@@ -0,0 +1,26 @@
+<?php
+
+require(__DIR__ . '/../_utils.php');
+
+$counter = test_resource_new(5);
+assert(is_resource($counter));
+assert(get_resource_type($counter) === 'test counter');
+assert(test_resource_increment($counter) === 6);
+assert(test_resource_increment($counter) === 7);
+assert(test_resource_count($counter) === 7);
+
+// Other resources and values are not counters
+assert(test_resource_count(fopen('php://memory', 'r')) === null);
+assert(test_resource_count(7) === null);
+assert_exception_thrown(fn () => test_resource_increment(fopen('php://memory', 'r')));
+
+// Closed resources can no longer be used
+test_resource_close($counter);
+assert(get_resource_type($counter) === 'Unknown');
+assert(test_resource_count($counter) === null);
+assert_exception_thrown(fn () => test_resource_increment($counter));
+
+// Values borrowed from Rust outlive closing the resource
+$counter = test_resource_new(3);
+assert(test_resource_close_borrowed($counter) === 3);
+assert(get_resource_type($counter) === 'Unknown');
@@ -0,0 +1,106 @@
+use std::collections::BTreeMap;
+
+use ext_php_rs::{
+    prelude::*,
+    serde::{from_zval, to_zval, Serializer},
+    types::Zval,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+pub struct Order {
+    id: u32,
+    note: Option<String>,
+    items: Vec<Item>,
+    status: Status,
+    tags: BTreeMap<String, i64>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct Item {
+    id: u32,
+    quantity: u8,
+}
+
+#[derive(Serialize, Deserialize)]
+pub enum Status {
+    Pending,
+    Shipped { tracking: String },
+    Cancelled(String),
+}
+
+#[php_function]
+pub fn test_serde_round_trip(order: &Zval) -> PhpResult<Zval> {
+    let order: Order = from_zval(order)?;
+    Ok(to_zval(&order)?)
+}
+
+#[php_function]
+pub fn test_serde_object(order: &Zval) -> PhpResult<Zval> {
+    let order: Order = from_zval(order)?;
+    Ok(order.serialize(Serializer::new().structs_as_objects())?)
+}
+
+#[php_function]
+pub fn test_serde_error(order: &Zval) -> String {
+    match from_zval::<Order>(order) {
+        Ok(_) => String::new(),
+        Err(e) => e.path(),
+    }
+}
+
+#[php_function]
+pub fn test_serde_bytes(bytes: &Zval) -> PhpResult<Zval> {
+    let bytes: serde_bytes_like::Bytes = from_zval(bytes)?;
+    Ok(to_zval(&bytes)?)
+}
+
+/// Minimal stand-in for `serde_bytes`, (de)serializing a byte buffer through
+/// the bytes methods of serde.
+mod serde_bytes_like {
+    use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+    pub struct Bytes(Vec<u8>);
+
+    impl Serialize for Bytes {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_bytes(&self.0)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Bytes {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            struct Visitor;
+
+            impl de::Visitor<'_> for Visitor {
+                type Value = Bytes;
+
+                fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                    f.write_str("bytes")
+                }
+
+                fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Bytes, E> {
+                    Ok(Bytes(v.to_vec()))
+                }
+            }
+
+            deserializer.deserialize_bytes(Visitor)
+        }
+    }
+}
+
+pub fn build_module(builder: ModuleBuilder) -> ModuleBuilder {
+    builder
+        .function(wrap_function!(test_serde_round_trip))
+        .function(wrap_function!(test_serde_object))
+        .function(wrap_function!(test_serde_error))
+        .function(wrap_function!(test_serde_bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn serde_works() {
+        assert!(crate::integration::test::run_php("serde/serde.php"));
+    }
+}
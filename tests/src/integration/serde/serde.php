@@ -0,0 +1,41 @@
+<?php
+
+require __DIR__ . "/../_utils.php";
+
+$order = [
+    'id' => 7,
+    'note' => null,
+    'items' => [['id' => 1, 'quantity' => 2], ['id' => 2, 'quantity' => 1]],
+    'status' => 'Pending',
+    'tags' => ['a' => 1, 'b' => 2],
+];
+
+// Structs round-trip through associative arrays
+assert(test_serde_round_trip($order) === $order);
+
+// Enum variants with values are arrays keyed by the variant
+$shipped = array_merge($order, ['status' => ['Shipped' => ['tracking' => 'XY1']]]);
+assert(test_serde_round_trip($shipped) === $shipped);
+$cancelled = array_merge($order, ['status' => ['Cancelled' => 'out of stock']]);
+assert(test_serde_round_trip($cancelled) === $cancelled);
+
+// Objects can be deserialized, and structs serialized into stdClass
+$object = test_serde_object((object) $order);
+assert($object instanceof stdClass);
+assert($object->id === 7);
+assert($object->items[1] instanceof stdClass);
+assert($object->items[1]->quantity === 1);
+assert($object->tags === ['a' => 1, 'b' => 2]);
+
+// Errors carry the path of the offending value
+$invalid = $order;
+$invalid['items'][1]['id'] = 'two';
+assert(test_serde_error($invalid) === '$.items[1].id');
+$invalid = $order;
+$invalid['items'][0]['quantity'] = 300;
+assert(test_serde_error($invalid) === '$.items[0].quantity');
+assert(test_serde_error(array_merge($order, ['status' => 'Lost'])) === '$.status');
+assert_exception_thrown(fn () => test_serde_round_trip($invalid));
+
+// Bytes map onto binary strings
+assert(test_serde_bytes("\x00\xff") === "\x00\xff");
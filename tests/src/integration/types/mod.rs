@@ -1,3 +1,71 @@
+use ext_php_rs::{prelude::*, types::Zval};
+
+#[derive(ZvalConvert)]
+pub enum IntOrString {
+    Int(i64),
+    String(String),
+}
+
+#[derive(ZvalConvert)]
+pub enum IntOrAny {
+    Int(i64),
+    Any(Zval),
+}
+
+#[php_function]
+pub fn test_union(value: IntOrString) -> IntOrString {
+    value
+}
+
+#[php_function]
+pub fn test_union_mixed(value: IntOrAny) -> IntOrAny {
+    value
+}
+
+#[php_function]
+#[php(types(value = "int|string|null"), returns = "?float")]
+pub fn test_union_override(value: &Zval) -> Option<f64> {
+    value.long().map(|value| value as f64)
+}
+
+#[php_function]
+#[php(types(value = "Countable&Traversable"))]
+pub fn test_intersection(value: &Zval) -> bool {
+    value.is_object()
+}
+
+#[php_function]
+#[php(returns = "never")]
+pub fn test_never() -> PhpResult<()> {
+    Err("test_never() never returns".into())
+}
+
+#[php_class]
+pub struct TestTypes;
+
+#[php_impl]
+impl TestTypes {
+    #[php(returns = "static")]
+    pub fn create() -> Self {
+        Self
+    }
+
+    #[php(types(other = "self"))]
+    pub fn same(&self, other: &Zval) -> bool {
+        other.is_object()
+    }
+}
+
+pub fn build_module(builder: ModuleBuilder) -> ModuleBuilder {
+    builder
+        .function(wrap_function!(test_union))
+        .function(wrap_function!(test_union_mixed))
+        .function(wrap_function!(test_union_override))
+        .function(wrap_function!(test_intersection))
+        .function(wrap_function!(test_never))
+        .class::<TestTypes>()
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
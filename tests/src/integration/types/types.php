@@ -1,6 +1,8 @@
 <?php
 
-const TYPES = [
+require(__DIR__ . '/../_utils.php');
+
+$types = [
     'test_str' => [['string'], 'string'],
     'test_string' => [['string'], 'string'],
     'test_bool' => [['bool'], 'bool'],
@@ -15,21 +17,31 @@ const TYPES = [
     'test_object' => [['object'], 'object'],
     'test_closure' => [[], 'RustClosure'],
     'test_closure_once' => [['string'], 'RustClosure'],
-    'test_callable' => [['callable', 'string'], 'mixed']
+    'test_callable' => [['callable', 'string'], 'mixed'],
+    'test_union' => [['string|int'], 'string|int'],
+    'test_union_mixed' => [['mixed'], 'mixed'],
+    'test_union_override' => [['string|int|null'], '?float'],
 ];
 
+if (PHP_VERSION_ID >= 80100) {
+    $types['test_never'] = [[], 'never'];
+}
+if (PHP_VERSION_ID >= 80300) {
+    $types['test_intersection'] = [['Countable&Traversable'], 'bool'];
+}
+
 function toStr(ReflectionNamedType|ReflectionUnionType|ReflectionIntersectionType|null $v): string {
     if ($v === null) {
         return '<null>';
     }
     return match (true) {
         $v instanceof ReflectionNamedType => $v->allowsNull() && $v->getName() !== 'mixed' ? '?'.$v->getName() : $v->getName(),
-        $v instanceof ReflectionUnionType => $v->getName(),
-        $v instanceof ReflectionIntersectionType => $v->getName(),
+        $v instanceof ReflectionUnionType => (string) $v,
+        $v instanceof ReflectionIntersectionType => (string) $v,
     };
 }
 
-foreach (TYPES as $func => [$args, $return]) {
+foreach ($types as $func => [$args, $return]) {
     $f = new ReflectionFunction($func);
     $tReturn = toStr($f->getReturnType());
     assert($tReturn === $return, "Wrong return type of $func, expected $return, got $tReturn");
@@ -38,3 +50,18 @@ foreach (TYPES as $func => [$args, $return]) {
         assert($tParam === $args[$idx], "Wrong arg type $idx of $func, expected {$args[$idx]}, got $tParam");
     }
 }
+
+$create = new ReflectionMethod(TestTypes::class, 'create');
+assert(toStr($create->getReturnType()) === 'static');
+assert(TestTypes::create() instanceof TestTypes);
+
+$same = new ReflectionMethod(TestTypes::class, 'same');
+assert(toStr($same->getParameters()[0]->getType()) === 'self');
+
+assert(test_union(5) === 5);
+assert(test_union('hello') === 'hello');
+assert(test_union_mixed(5) === 5);
+assert(test_union_mixed('hello') === 'hello');
+assert(test_union_override(2) === 2.0);
+assert(test_union_override(null) === null);
+assert_exception_thrown(fn () => test_never());
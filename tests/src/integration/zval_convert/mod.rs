@@ -0,0 +1,65 @@
+use ext_php_rs::prelude::*;
+
+#[derive(ZvalConvert)]
+pub struct Retry {
+    attempts: i64,
+    #[php(name = "backoffMs", default = 100)]
+    backoff_ms: i64,
+}
+
+#[derive(ZvalConvert)]
+#[php(array, change_case = "camelCase")]
+pub struct Config {
+    host_name: String,
+    #[php(default)]
+    port: i64,
+    #[php(name = "label")]
+    tag: Option<String>,
+    #[php(skip)]
+    connections: Vec<String>,
+    #[php(flatten)]
+    retry: Retry,
+}
+
+#[derive(ZvalConvert)]
+#[php(class = "TestZvalConvertPoint")]
+pub struct Point {
+    x: i64,
+    y: i64,
+}
+
+#[php_function]
+pub fn test_zval_convert_array(config: Config) -> Config {
+    assert!(config.connections.is_empty());
+    config
+}
+
+#[php_function]
+pub fn test_zval_convert_object(retry: Retry) -> Retry {
+    retry
+}
+
+#[php_function]
+pub fn test_zval_convert_class(point: Point) -> Point {
+    Point {
+        x: point.y,
+        y: point.x,
+    }
+}
+
+pub fn build_module(builder: ModuleBuilder) -> ModuleBuilder {
+    builder
+        .function(wrap_function!(test_zval_convert_array))
+        .function(wrap_function!(test_zval_convert_object))
+        .function(wrap_function!(test_zval_convert_class))
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn zval_convert_works() {
+        assert!(crate::integration::test::run_php(
+            "zval_convert/zval_convert.php"
+        ));
+    }
+}
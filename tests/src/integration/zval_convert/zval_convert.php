@@ -0,0 +1,51 @@
+<?php
+
+require __DIR__ . "/../_utils.php";
+
+// Structs can be converted to and from associative arrays, with renamed,
+// defaulted, skipped and flattened fields
+$config = test_zval_convert_array(['hostName' => 'localhost', 'label' => null, 'attempts' => 3]);
+assert($config === [
+    'hostName' => 'localhost',
+    'port' => 0,
+    'label' => null,
+    'attempts' => 3,
+    'backoffMs' => 100,
+]);
+assert(!array_key_exists('connections', $config));
+assert(test_zval_convert_array($config) === $config);
+assert_exception_thrown(fn () => test_zval_convert_array(['port' => 80]));
+assert_exception_thrown(fn () => test_zval_convert_array((object) ['hostName' => 'localhost', 'label' => null, 'attempts' => 3]));
+
+// Objects are converted into stdClass by default
+$retry = new stdClass;
+$retry->attempts = 2;
+$retry = test_zval_convert_object($retry);
+assert($retry instanceof stdClass);
+assert($retry->attempts === 2);
+assert($retry->backoffMs === 100);
+
+// Structs can be converted into a specific class
+class TestZvalConvertPoint
+{
+    public int $x;
+    public int $y;
+
+    public function __construct()
+    {
+        throw new Exception('The constructor is not called');
+    }
+}
+
+$point = (new ReflectionClass(TestZvalConvertPoint::class))->newInstanceWithoutConstructor();
+$point->x = 1;
+$point->y = 2;
+$swapped = test_zval_convert_class($point);
+assert($swapped instanceof TestZvalConvertPoint);
+assert($swapped->x === 2 && $swapped->y === 1);
+assert_exception_thrown(fn () => test_zval_convert_class((object) ['x' => 1, 'y' => 2]));
+
+$f = new ReflectionFunction('test_zval_convert_class');
+assert((string) $f->getReturnType() === 'TestZvalConvertPoint');
+assert((string) $f->getParameters()[0]->getType() === 'TestZvalConvertPoint');
+assert((string) (new ReflectionFunction('test_zval_convert_array'))->getReturnType() === 'array');
@@ -10,6 +10,7 @@ use ext_php_rs::prelude::*;
 mod integration;
 
 #[php_module]
+#[php(requires = ["standard"])]
 pub fn build_module(module: ModuleBuilder) -> ModuleBuilder {
     let mut module = integration::array::build_module(module);
     module = integration::binary::build_module(module);
@@ -18,19 +19,31 @@ pub fn build_module(module: ModuleBuilder) -> ModuleBuilder {
     module = integration::class::build_module(module);
     module = integration::closure::build_module(module);
     module = integration::defaults::build_module(module);
+    module = integration::dependencies::build_module(module);
     #[cfg(feature = "enum")]
     {
         module = integration::enum_::build_module(module);
     }
     module = integration::exception::build_module(module);
+    module = integration::generator::build_module(module);
     module = integration::globals::build_module(module);
+    module = integration::info::build_module(module);
+    module = integration::ini::build_module(module);
+    module = integration::interface::build_module(module);
     module = integration::iterator::build_module(module);
+    module = integration::lifecycle::build_module(module);
     module = integration::magic_method::build_module(module);
+    module = integration::named_args::build_module(module);
     module = integration::nullable::build_module(module);
     module = integration::number::build_module(module);
     module = integration::object::build_module(module);
+    module = integration::operators::build_module(module);
+    module = integration::resource::build_module(module);
+    module = integration::serde::build_module(module);
     module = integration::string::build_module(module);
+    module = integration::types::build_module(module);
     module = integration::variadic_args::build_module(module);
+    module = integration::zval_convert::build_module(module);
 
     module
 }
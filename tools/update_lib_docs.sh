@@ -48,8 +48,10 @@ update_docs "constant"
 update_docs "extern"
 update_docs "function"
 update_docs "impl"
+update_docs "interface"
 update_docs "module"
 update_docs "zval_convert"
+update_docs "gc_traverse"
 update_docs "enum"
 
 # Format to remove trailing whitespace